@@ -495,6 +495,20 @@ class TestTtl(unittest.TestCase):
         decoded = dx_decode(encoded, as_string=True, check_ttl=False)
         self.assertEqual(decoded, "Data")
 
+    def test_ttl_info_requires_padding(self):
+        """测试 TTL 信息与解码同样要求规范的填充"""
+        encoded = next(
+            e
+            for e in (dx_encode_with_ttl("x" * n, 3600) for n in range(1, 4))
+            if e.endswith("=")
+        )
+        unpadded = encoded.rstrip("=")
+        self.assertIsNotNone(get_ttl_info(encoded))
+        with self.assertRaises(DxEncodingError):
+            dx_decode(unpadded)
+        with self.assertRaises(DxEncodingError):
+            get_ttl_info(unpadded)
+
     def test_is_expired_function(self):
         """测试 is_expired 函数"""
         encoded = dx_encode_with_ttl("Data", 86400)  # 1天
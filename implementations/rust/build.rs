@@ -0,0 +1,29 @@
+//! 构建脚本：启用 `ffi` feature 时用 cbindgen 在 `OUT_DIR` 中生成 `dxcode.h`
+//!
+//! 仓库中的 `include/dxcode.h` 用 `cbindgen --config cbindgen.toml --output include/dxcode.h`
+//! 更新，`cargo test --features ffi` 会检查两者一致。
+
+fn main() {
+    #[cfg(feature = "ffi")]
+    generate_header();
+}
+
+#[cfg(feature = "ffi")]
+fn generate_header() {
+    use std::env;
+    use std::path::PathBuf;
+
+    println!("cargo:rerun-if-changed=src/ffi.rs");
+    println!("cargo:rerun-if-changed=cbindgen.toml");
+
+    let crate_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    let config = cbindgen::Config::from_file(crate_dir.join("cbindgen.toml"))
+        .expect("failed to read cbindgen.toml");
+    cbindgen::Builder::new()
+        .with_config(config)
+        .with_src(crate_dir.join("src/ffi.rs"))
+        .generate()
+        .expect("failed to generate dxcode.h")
+        .write_to_file(out_dir.join("dxcode.h"));
+}
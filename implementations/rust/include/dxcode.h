@@ -0,0 +1,317 @@
+/**
+ * DX Encoding - 带有 `dx` 前缀的自定义编码算法
+ *
+ * C 接口 - 由 cbindgen 根据 src/ffi.rs 生成，请勿手动修改
+ *
+ * 作者: Dogxi
+ * 许可证: MIT
+ */
+
+#ifndef DXCODE_H
+#define DXCODE_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * 成功
+ */
+#define DX_OK 0
+
+/**
+ * 无效输入（空指针或不是 UTF-8 的字符串）
+ */
+#define DX_ERROR_INVALID_INPUT -1
+
+/**
+ * 缺少前缀
+ */
+#define DX_ERROR_INVALID_PREFIX -2
+
+/**
+ * 长度不正确
+ */
+#define DX_ERROR_INVALID_LENGTH -3
+
+/**
+ * 包含非法字符
+ */
+#define DX_ERROR_INVALID_CHARACTER -4
+
+/**
+ * 缓冲区太小
+ */
+#define DX_ERROR_BUFFER_TOO_SMALL -5
+
+/**
+ * 内存分配失败
+ */
+#define DX_ERROR_MEMORY -6
+
+/**
+ * 头部无效
+ */
+#define DX_ERROR_INVALID_HEADER -7
+
+/**
+ * flags 无效
+ */
+#define DX_ERROR_INVALID_FLAGS -8
+
+/**
+ * 校验和不匹配
+ */
+#define DX_ERROR_CHECKSUM_MISMATCH -9
+
+/**
+ * 压缩/解压缩失败
+ */
+#define DX_ERROR_COMPRESSION -10
+
+/**
+ * TTL 已过期
+ */
+#define DX_ERROR_TTL_EXPIRED -11
+
+/**
+ * 不是有效的 UTF-8
+ */
+#define DX_ERROR_INVALID_UTF8 -12
+
+/**
+ * 字符集或前缀无效
+ */
+#define DX_ERROR_INVALID_ALPHABET -13
+
+/**
+ * 内容类型不符
+ */
+#define DX_ERROR_INVALID_CONTENT_TYPE -14
+
+/**
+ * 序列化/反序列化失败
+ */
+#define DX_ERROR_SERIALIZATION -15
+
+/**
+ * 令牌过大
+ */
+#define DX_ERROR_TOKEN_TOO_LARGE -16
+
+/**
+ * 待转换的输入无效
+ */
+#define DX_ERROR_INVALID_SOURCE -17
+
+/**
+ * 过期时间无效
+ */
+#define DX_ERROR_INVALID_EXPIRY -18
+
+/**
+ * Cookie 名称或属性无效
+ */
+#define DX_ERROR_INVALID_COOKIE -19
+
+/**
+ * 头部信息
+ */
+typedef struct dx_header_t {
+  /**
+   * flags 字节
+   */
+  uint8_t flags;
+  /**
+   * 存储的 CRC16 校验和
+   */
+  uint16_t checksum;
+  /**
+   * 是否压缩
+   */
+  bool compressed;
+  /**
+   * 压缩前的大小，未压缩时为 0
+   */
+  uint16_t original_size;
+  /**
+   * 是否带 TTL
+   */
+  bool has_ttl;
+  /**
+   * 创建时间（Unix 时间戳，秒），不带 TTL 时为 0
+   */
+  uint64_t created_at;
+  /**
+   * 有效期（秒），不带 TTL 或永不过期时为 0
+   */
+  uint32_t ttl_seconds;
+  /**
+   * 是否标记了内容类型
+   */
+  bool has_content_type;
+  /**
+   * 内容类型
+   */
+  uint8_t content_type;
+  /**
+   * 负载字节数
+   */
+  size_t payload_len;
+} dx_header_t;
+
+/**
+ * TTL 信息
+ */
+typedef struct dx_ttl_info_t {
+  /**
+   * 是否带 TTL，为 `false` 时其他字段均为 0
+   */
+  bool has_ttl;
+  /**
+   * 创建时间（Unix 时间戳，秒）
+   */
+  uint64_t created_at;
+  /**
+   * 有效期（秒），0 表示永不过期
+   */
+  uint32_t ttl_seconds;
+  /**
+   * 过期时间（Unix 时间戳，秒），永不过期时为 0
+   */
+  uint64_t expires_at;
+  /**
+   * 是否已过期
+   */
+  bool is_expired;
+} dx_ttl_info_t;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * 编码字节数据，`*out` 为新分配的 NUL 结尾字符串，需要用 `dx_free` 释放
+ *
+ * # Safety
+ *
+ * `input` 指向至少 `input_len` 个可读字节（`input_len` 为 0 时可以为空），
+ * `out` 为有效的可写指针。
+ */
+int dx_encode(const uint8_t *input, size_t input_len, char **out);
+
+/**
+ * 使用 TTL 编码字节数据，`ttl_seconds` 为 0 时不带 TTL
+ *
+ * # Safety
+ *
+ * 同 [`dx_encode`]。
+ */
+int dx_encode_with_ttl(const uint8_t *input, size_t input_len, uint32_t ttl_seconds, char **out);
+
+/**
+ * 编码字节数据到调用方的缓冲区（包含 NUL 结尾）
+ *
+ * `out_len` 返回编码后的字符数（不含 NUL），可以为空指针。
+ *
+ * # Safety
+ *
+ * `input` 同 [`dx_encode`]；`buf` 指向至少 `buf_size` 个可写字节。
+ */
+int dx_encode_into(const uint8_t *input,
+                   size_t input_len,
+                   char *buf,
+                   size_t buf_size,
+                   size_t *out_len);
+
+/**
+ * 解码（验证校验和，检查 TTL），`*out` 为新分配的字节数组，需要用 `dx_free` 释放
+ *
+ * 解码结果为空时 `*out` 仍为有效指针，`*out_len` 为 0。
+ *
+ * # Safety
+ *
+ * `encoded` 为 NUL 结尾的字符串，`out`、`out_len` 为有效的可写指针。
+ */
+int dx_decode(const char *encoded,
+              uint8_t **out,
+              size_t *out_len);
+
+/**
+ * 解码到调用方的缓冲区
+ *
+ * `out_len` 返回解码后的字节数；缓冲区不足时返回 `DX_ERROR_BUFFER_TOO_SMALL`，
+ * `out_len` 为所需的大小。
+ *
+ * # Safety
+ *
+ * `encoded` 为 NUL 结尾的字符串；`buf` 指向至少 `buf_size` 个可写字节。
+ */
+int dx_decode_into(const char *encoded, uint8_t *buf, size_t buf_size, size_t *out_len);
+
+/**
+ * 释放 `dx_encode`/`dx_decode` 分配的内存，空指针时不做任何操作
+ *
+ * # Safety
+ *
+ * `ptr` 必须来自本库的分配函数，且只能释放一次。
+ */
+void dx_free(void *ptr);
+
+/**
+ * 解析头部信息（不验证校验和）
+ *
+ * # Safety
+ *
+ * `encoded` 为 NUL 结尾的字符串，`header` 为有效的可写指针。
+ */
+int dx_inspect(const char *encoded, struct dx_header_t *header);
+
+/**
+ * 验证校验和，`*is_valid` 为是否匹配；格式错误时返回错误码
+ *
+ * # Safety
+ *
+ * `encoded` 为 NUL 结尾的字符串，`is_valid` 为有效的可写指针。
+ */
+int dx_verify(const char *encoded, bool *is_valid);
+
+/**
+ * 检查字符串是否为 DX 编码（仅检查前缀、长度和字符）
+ *
+ * # Safety
+ *
+ * `s` 为空指针或 NUL 结尾的字符串。
+ */
+bool dx_is_encoded(const char *s);
+
+/**
+ * 获取 TTL 信息，不带 TTL 时 `info->has_ttl` 为 `false`
+ *
+ * # Safety
+ *
+ * `encoded` 为 NUL 结尾的字符串，`info` 为有效的可写指针。
+ */
+int dx_get_ttl_info(const char *encoded, struct dx_ttl_info_t *info);
+
+/**
+ * 计算 CRC16-CCITT 校验和
+ *
+ * # Safety
+ *
+ * `data` 指向至少 `len` 个可读字节（`len` 为 0 时可以为空）。
+ */
+uint16_t dx_crc16(const uint8_t *data, size_t len);
+
+/**
+ * 错误码的英文描述（静态字符串，无需释放）
+ */
+const char *dx_error_string(int code);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* DXCODE_H */
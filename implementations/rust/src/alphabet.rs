@@ -0,0 +1,213 @@
+//! 字符集定义
+//!
+//! `Alphabet` 保存 64 个编码字符及其反向查找表，构造时验证字符集的合法性。
+
+use std::fmt;
+
+use crate::locale::{Locale, Localized};
+use crate::{CHARSET, PADDING};
+
+/// 反向查找表中的无效标记
+const INVALID: u8 = 0xFF;
+
+/// 字符集构造错误
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AlphabetError {
+    /// 字符数不是 64
+    InvalidLength(usize),
+    /// 包含非 ASCII 可见字符（空白、控制字符或多字节字符）
+    NonPrintable(u8),
+    /// 字符重复
+    DuplicateSymbol(char),
+    /// 与填充字符冲突
+    ReservedSymbol(char),
+    /// 前缀为空、包含非 ASCII 可见字符或填充字符
+    InvalidPrefix,
+    /// 前缀全部由字符集中的字符组成
+    PrefixInAlphabet,
+}
+
+impl AlphabetError {
+    /// 按指定语言格式化错误信息，`Display` 始终输出英文
+    pub fn localized(&self, locale: Locale) -> Localized<'_, AlphabetError> {
+        Localized {
+            error: self,
+            locale,
+        }
+    }
+}
+
+impl fmt::Display for AlphabetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.localized(Locale::En), f)
+    }
+}
+
+impl fmt::Display for Localized<'_, AlphabetError> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.error, self.locale) {
+            (AlphabetError::InvalidLength(len), Locale::En) => {
+                write!(f, "alphabet must contain 64 symbols, found {}", len)
+            }
+            (AlphabetError::InvalidLength(len), Locale::ZhCn) => {
+                write!(f, "字符集必须包含 64 个字符，实际为 {}", len)
+            }
+            (AlphabetError::NonPrintable(b), Locale::En) => {
+                write!(f, "alphabet contains non-printable byte 0x{:02X}", b)
+            }
+            (AlphabetError::NonPrintable(b), Locale::ZhCn) => {
+                write!(f, "字符集包含非 ASCII 可见字符 0x{:02X}", b)
+            }
+            (AlphabetError::DuplicateSymbol(c), Locale::En) => {
+                write!(f, "alphabet contains duplicate symbol '{}'", c)
+            }
+            (AlphabetError::DuplicateSymbol(c), Locale::ZhCn) => {
+                write!(f, "字符集包含重复字符 '{}'", c)
+            }
+            (AlphabetError::ReservedSymbol(c), Locale::En) => {
+                write!(f, "alphabet must not contain the padding symbol '{}'", c)
+            }
+            (AlphabetError::ReservedSymbol(c), Locale::ZhCn) => {
+                write!(f, "字符集不能包含填充字符 '{}'", c)
+            }
+            (AlphabetError::InvalidPrefix, Locale::En) => write!(
+                f,
+                "prefix must be non-empty printable ASCII without the padding symbol"
+            ),
+            (AlphabetError::InvalidPrefix, Locale::ZhCn) => {
+                write!(f, "前缀必须是非空的 ASCII 可见字符且不含填充字符")
+            }
+            (AlphabetError::PrefixInAlphabet, Locale::En) => write!(
+                f,
+                "prefix must contain at least one symbol outside the alphabet"
+            ),
+            (AlphabetError::PrefixInAlphabet, Locale::ZhCn) => {
+                write!(f, "前缀必须至少包含一个字符集以外的字符")
+            }
+        }
+    }
+}
+
+impl std::error::Error for AlphabetError {}
+
+/// 64 字符编码字符集
+///
+/// 只能通过 [`Alphabet::new`] 或预设常量构造，保证 64 个字符均为互不相同的
+/// ASCII 可见字符，且不包含填充字符 `=`。
+///
+/// # 示例
+///
+/// ```
+/// use dxcode::Alphabet;
+///
+/// assert_eq!(Alphabet::STANDARD.as_str(), dxcode::CHARSET);
+/// assert!(Alphabet::new("abc").is_err());
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Alphabet {
+    symbols: [u8; 64],
+    decode: [u8; 256],
+}
+
+impl Alphabet {
+    /// 标准 DX 字符集
+    pub const STANDARD: Alphabet = Alphabet::from_const(CHARSET);
+
+    /// 去除易混淆字符的 URL 安全字符集
+    ///
+    /// 去掉 `O`、`I` 和 `l`，使 `0`/`O` 与 `1`/`I`/`l` 两组字符各只保留一个数字。
+    /// RFC 3986 的非保留字符只有 66 个，去掉三个后不足 64 个，因此补充了 `*`：
+    /// 它可以原样出现在 URL 路径和查询参数中，`encodeURIComponent`、
+    /// `URLSearchParams` 和 Java 的 `URLEncoder` 都不会转义它。`S`/`5`、`Z`/`2`
+    /// 需要再补充三个保留字符，因此保留。
+    pub const NO_CONFUSABLES: Alphabet =
+        Alphabet::from_const("DXdx0123456789ABCEFGHJKLMNPQRSTUVWYZabcefghijkmnopqrstuvwyz-_.~*");
+
+    /// RFC 4648 "URL and Filename safe" 字符集
+    pub const FILENAME_SAFE: Alphabet =
+        Alphabet::from_const("ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_");
+
+    /// 从 64 个字符构造字符集
+    ///
+    /// # 参数
+    ///
+    /// * `symbols` - 字符集，必须是 64 个互不相同的 ASCII 可见字符，且不包含 `=`
+    ///
+    /// # 返回值
+    ///
+    /// 验证通过的字符集，否则返回 [`AlphabetError`]
+    pub const fn new(symbols: &str) -> Result<Alphabet, AlphabetError> {
+        let bytes = symbols.as_bytes();
+        if bytes.len() != 64 {
+            return Err(AlphabetError::InvalidLength(bytes.len()));
+        }
+
+        let mut table = [0u8; 64];
+        let mut decode = [INVALID; 256];
+        let mut i = 0;
+        while i < 64 {
+            let b = bytes[i];
+            if !b.is_ascii_graphic() {
+                return Err(AlphabetError::NonPrintable(b));
+            }
+            if b == PADDING as u8 {
+                return Err(AlphabetError::ReservedSymbol(b as char));
+            }
+            if decode[b as usize] != INVALID {
+                return Err(AlphabetError::DuplicateSymbol(b as char));
+            }
+            table[i] = b;
+            decode[b as usize] = i as u8;
+            i += 1;
+        }
+
+        Ok(Alphabet {
+            symbols: table,
+            decode,
+        })
+    }
+
+    /// 预设常量使用的构造函数，非法字符集在编译期报错
+    const fn from_const(symbols: &str) -> Alphabet {
+        match Alphabet::new(symbols) {
+            Ok(alphabet) => alphabet,
+            Err(_) => panic!("invalid built-in alphabet"),
+        }
+    }
+
+    /// 字符集字符串
+    pub fn as_str(&self) -> &str {
+        // 构造时已保证全部为 ASCII
+        std::str::from_utf8(&self.symbols).expect("alphabet is ASCII")
+    }
+
+    /// 6 位值对应的字符
+    pub(crate) fn symbol(&self, value: u8) -> u8 {
+        self.symbols[(value & 0x3F) as usize]
+    }
+
+    /// 字符对应的 6 位值
+    pub(crate) fn value(&self, symbol: u8) -> Option<u8> {
+        match self.decode[symbol as usize] {
+            INVALID => None,
+            v => Some(v),
+        }
+    }
+
+    /// 是否包含该字符
+    pub fn contains(&self, symbol: u8) -> bool {
+        self.decode[symbol as usize] != INVALID
+    }
+}
+
+impl fmt::Debug for Alphabet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Alphabet").field(&self.as_str()).finish()
+    }
+}
+
+impl Default for Alphabet {
+    fn default() -> Self {
+        Alphabet::STANDARD
+    }
+}
@@ -0,0 +1,384 @@
+//! axum/tower 集成（需要启用 `axum` feature）
+//!
+//! - [`Dx<T>`]：解码 `application/x-dxcode` 请求体并反序列化（见 [`from_dx_str`]），
+//!   也可以作为响应返回
+//! - [`DxBytes`]：不经过 serde，直接提取或返回原始字节
+//! - [`DxLayer`]：在中间件中透明地解码请求体、编码响应体
+//!
+//! 解码失败时按错误类别返回状态码（见 [`status_code`]）：TTL 过期返回
+//! `410 Gone`，校验和不匹配返回 `401 Unauthorized`，其他格式错误返回
+//! `400 Bad Request`。注意 CRC16 只能发现意外损坏，不能代替密码学签名。
+//!
+//! # 示例
+//!
+//! ```
+//! use axum::{routing::post, Router};
+//! use dxcode::axum::Dx;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Order {
+//!     id: u64,
+//! }
+//!
+//! async fn create(Dx(order): Dx<Order>) -> Dx<Order> {
+//!     Dx(order)
+//! }
+//!
+//! let app: Router = Router::new().route("/orders", post(create));
+//! ```
+//!
+//! [`from_dx_str`]: crate::from_dx_str
+
+use std::convert::Infallible;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use ::axum::body::{self, Body, Bytes};
+use ::axum::extract::{FromRequest, Request};
+use ::axum::http::header::{ACCEPT, CONTENT_LENGTH, CONTENT_TYPE};
+use ::axum::http::{HeaderMap, HeaderValue, StatusCode};
+use ::axum::response::{IntoResponse, Response};
+use ::serde::de::DeserializeOwned;
+use ::serde::Serialize;
+use http_body_util::LengthLimitError;
+use tower_layer::Layer;
+use tower_service::Service;
+
+use crate::{from_dx_str, to_dx_string, DxError, Engine, ErrorKind, STANDARD};
+
+/// DX 编码内容的 MIME 类型
+pub const CONTENT_TYPE_DX: &str = "application/x-dxcode";
+
+/// 默认的请求体大小上限（字节）
+pub const DEFAULT_BODY_LIMIT: usize = 2 * 1024 * 1024;
+
+/// DX 错误对应的 HTTP 状态码
+///
+/// | 错误 | 状态码 |
+/// | --- | --- |
+/// | `TtlExpired` | 410 Gone |
+/// | `ChecksumMismatch` | 401 Unauthorized |
+/// | `TokenTooLarge` | 431 Request Header Fields Too Large |
+/// | 其他 | 400 Bad Request |
+pub fn status_code(error: &DxError) -> StatusCode {
+    match error.kind() {
+        ErrorKind::TtlExpired => StatusCode::GONE,
+        ErrorKind::ChecksumMismatch => StatusCode::UNAUTHORIZED,
+        ErrorKind::TokenTooLarge => StatusCode::REQUEST_HEADER_FIELDS_TOO_LARGE,
+        _ => StatusCode::BAD_REQUEST,
+    }
+}
+
+/// 提取或返回 DX 编码的 serde 数据
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Dx<T>(pub T);
+
+/// 提取或返回 DX 编码的原始字节
+#[derive(Debug, Clone, Default)]
+pub struct DxBytes(pub Vec<u8>);
+
+/// 提取失败的原因
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum DxRejection {
+    /// 请求的 `Content-Type` 不是 `application/x-dxcode`（415）
+    UnsupportedContentType,
+    /// 请求体超过大小上限（413）
+    PayloadTooLarge,
+    /// 读取请求体失败，或请求体不是 UTF-8（400）
+    InvalidBody,
+    /// 解码失败，状态码见 [`status_code`]
+    Dx(DxError),
+}
+
+impl DxRejection {
+    /// 对应的 HTTP 状态码
+    pub fn status(&self) -> StatusCode {
+        match self {
+            DxRejection::UnsupportedContentType => StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            DxRejection::PayloadTooLarge => StatusCode::PAYLOAD_TOO_LARGE,
+            DxRejection::InvalidBody => StatusCode::BAD_REQUEST,
+            DxRejection::Dx(e) => status_code(e),
+        }
+    }
+}
+
+impl From<DxError> for DxRejection {
+    fn from(e: DxError) -> Self {
+        DxRejection::Dx(e)
+    }
+}
+
+impl IntoResponse for DxRejection {
+    fn into_response(self) -> Response {
+        let message = match &self {
+            DxRejection::UnsupportedContentType => {
+                format!("expected Content-Type: {}", CONTENT_TYPE_DX)
+            }
+            DxRejection::PayloadTooLarge => "request body too large".to_string(),
+            DxRejection::InvalidBody => "failed to read request body".to_string(),
+            DxRejection::Dx(e) => e.to_string(),
+        };
+        (self.status(), message).into_response()
+    }
+}
+
+/// 是否为 DX 内容类型（忽略参数和大小写）
+fn is_dx_content_type(headers: &HeaderMap) -> bool {
+    headers
+        .get(CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.split(';').next())
+        .is_some_and(|mime| mime.trim().eq_ignore_ascii_case(CONTENT_TYPE_DX))
+}
+
+/// 客户端是否接受 DX 编码的响应
+fn accepts_dx(headers: &HeaderMap) -> bool {
+    headers
+        .get_all(ACCEPT)
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .flat_map(|v| v.split(','))
+        .filter_map(|v| v.split(';').next())
+        .any(|mime| mime.trim().eq_ignore_ascii_case(CONTENT_TYPE_DX))
+}
+
+/// 读取并检查请求体（Content-Type 和大小）
+async fn read_dx_body(
+    headers: &HeaderMap,
+    body: Body,
+    limit: usize,
+) -> Result<String, DxRejection> {
+    if !is_dx_content_type(headers) {
+        return Err(DxRejection::UnsupportedContentType);
+    }
+    let declared = headers
+        .get(CONTENT_LENGTH)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.parse::<usize>().ok());
+    if declared.is_some_and(|len| len > limit) {
+        return Err(DxRejection::PayloadTooLarge);
+    }
+
+    // 没有 Content-Length（分块传输）时在读取过程中超出上限
+    let bytes =
+        body::to_bytes(body, limit)
+            .await
+            .map_err(|e| match std::error::Error::source(&e) {
+                Some(source) if source.is::<LengthLimitError>() => DxRejection::PayloadTooLarge,
+                _ => DxRejection::InvalidBody,
+            })?;
+    String::from_utf8(bytes.to_vec()).map_err(|_| DxRejection::InvalidBody)
+}
+
+impl<S, T> FromRequest<S> for Dx<T>
+where
+    S: Send + Sync,
+    T: DeserializeOwned,
+{
+    type Rejection = DxRejection;
+
+    async fn from_request(req: Request, _state: &S) -> Result<Self, Self::Rejection> {
+        let (parts, body) = req.into_parts();
+        let encoded = read_dx_body(&parts.headers, body, DEFAULT_BODY_LIMIT).await?;
+        Ok(Dx(from_dx_str(encoded.trim(), &STANDARD)?))
+    }
+}
+
+impl<S> FromRequest<S> for DxBytes
+where
+    S: Send + Sync,
+{
+    type Rejection = DxRejection;
+
+    async fn from_request(req: Request, _state: &S) -> Result<Self, Self::Rejection> {
+        let (parts, body) = req.into_parts();
+        let encoded = read_dx_body(&parts.headers, body, DEFAULT_BODY_LIMIT).await?;
+        Ok(DxBytes(STANDARD.decode(encoded.trim())?))
+    }
+}
+
+/// DX 编码的响应
+fn dx_response(encoded: String) -> Response {
+    (
+        [(CONTENT_TYPE, HeaderValue::from_static(CONTENT_TYPE_DX))],
+        encoded,
+    )
+        .into_response()
+}
+
+impl<T: Serialize> IntoResponse for Dx<T> {
+    fn into_response(self) -> Response {
+        match to_dx_string(&self.0, &STANDARD) {
+            Ok(encoded) => dx_response(encoded),
+            Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        }
+    }
+}
+
+impl IntoResponse for DxBytes {
+    fn into_response(self) -> Response {
+        dx_response(STANDARD.encode(&self.0))
+    }
+}
+
+/// 透明地解码请求体、编码响应体的中间件
+///
+/// - 请求 `Content-Type` 为 `application/x-dxcode` 时，解码请求体（检查 TTL 和校验和），
+///   并将 `Content-Type` 替换为 [`with_content_type`](DxLayer::with_content_type) 设置的类型
+/// - 请求使用 DX 编码或 `Accept` 包含 `application/x-dxcode` 时，将成功的响应体编码为 DX
+///
+/// # 示例
+///
+/// ```
+/// use axum::{http::HeaderValue, routing::post, Router};
+/// use dxcode::axum::DxLayer;
+///
+/// async fn echo(body: String) -> String {
+///     body
+/// }
+///
+/// let app: Router = Router::new()
+///     .route("/echo", post(echo))
+///     .layer(DxLayer::new().with_content_type(HeaderValue::from_static("text/plain")));
+/// ```
+#[derive(Debug, Clone)]
+pub struct DxLayer {
+    engine: Engine,
+    content_type: HeaderValue,
+    limit: usize,
+}
+
+impl DxLayer {
+    /// 使用标准引擎，解码后的请求体类型为 `application/octet-stream`
+    pub fn new() -> DxLayer {
+        DxLayer {
+            engine: STANDARD,
+            content_type: HeaderValue::from_static("application/octet-stream"),
+            limit: DEFAULT_BODY_LIMIT,
+        }
+    }
+
+    /// 使用指定引擎
+    pub fn with_engine(mut self, engine: Engine) -> DxLayer {
+        self.engine = engine;
+        self
+    }
+
+    /// 解码后请求体的 `Content-Type`（例如 `application/json`）
+    pub fn with_content_type(mut self, content_type: HeaderValue) -> DxLayer {
+        self.content_type = content_type;
+        self
+    }
+
+    /// 请求体和响应体的大小上限（字节）
+    pub fn with_body_limit(mut self, limit: usize) -> DxLayer {
+        self.limit = limit;
+        self
+    }
+}
+
+impl Default for DxLayer {
+    fn default() -> Self {
+        DxLayer::new()
+    }
+}
+
+impl<S> Layer<S> for DxLayer {
+    type Service = DxService<S>;
+
+    fn layer(&self, inner: S) -> DxService<S> {
+        DxService {
+            inner,
+            layer: self.clone(),
+        }
+    }
+}
+
+/// [`DxLayer`] 生成的服务
+#[derive(Debug, Clone)]
+pub struct DxService<S> {
+    inner: S,
+    layer: DxLayer,
+}
+
+type BoxFuture<T> = Pin<Box<dyn Future<Output = T> + Send>>;
+
+impl<S> Service<Request> for DxService<S>
+where
+    S: Service<Request, Response = Response, Error = Infallible> + Clone + Send + 'static,
+    S::Future: Send,
+{
+    type Response = Response;
+    type Error = Infallible;
+    type Future = BoxFuture<Result<Response, Infallible>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Infallible>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: Request) -> Self::Future {
+        // 使用已经 poll_ready 的实例，把克隆留给下一次调用
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+        let layer = self.layer.clone();
+
+        Box::pin(async move {
+            let encode_response = is_dx_content_type(req.headers()) || accepts_dx(req.headers());
+
+            let req = if is_dx_content_type(req.headers()) {
+                match decode_request(req, &layer).await {
+                    Ok(req) => req,
+                    Err(rejection) => return Ok(rejection.into_response()),
+                }
+            } else {
+                req
+            };
+
+            let response = inner.call(req).await?;
+            if !encode_response || !response.status().is_success() {
+                return Ok(response);
+            }
+            Ok(encode_body(response, &layer).await)
+        })
+    }
+}
+
+/// 解码请求体并替换 `Content-Type`
+async fn decode_request(req: Request, layer: &DxLayer) -> Result<Request, DxRejection> {
+    let (mut parts, body) = req.into_parts();
+    let encoded = read_dx_body(&parts.headers, body, layer.limit).await?;
+    let decoded = layer.engine.decode(encoded.trim())?;
+
+    parts
+        .headers
+        .insert(CONTENT_TYPE, layer.content_type.clone());
+    parts
+        .headers
+        .insert(CONTENT_LENGTH, HeaderValue::from(decoded.len()));
+    Ok(Request::from_parts(parts, Body::from(decoded)))
+}
+
+/// 将响应体编码为 DX
+async fn encode_body(response: Response, layer: &DxLayer) -> Response {
+    if is_dx_content_type(response.headers()) {
+        return response;
+    }
+
+    let (mut parts, body) = response.into_parts();
+    let bytes: Bytes = match body::to_bytes(body, layer.limit).await {
+        Ok(bytes) => bytes,
+        Err(_) => return StatusCode::INTERNAL_SERVER_ERROR.into_response(),
+    };
+    let encoded = layer.engine.encode(&bytes);
+
+    parts
+        .headers
+        .insert(CONTENT_TYPE, HeaderValue::from_static(CONTENT_TYPE_DX));
+    parts
+        .headers
+        .insert(CONTENT_LENGTH, HeaderValue::from(encoded.len()));
+    Response::from_parts(parts, Body::from(encoded))
+}
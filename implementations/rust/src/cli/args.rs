@@ -0,0 +1,657 @@
+//! 声明式参数解析
+//!
+//! 每个子命令在 [`COMMANDS`] 中声明名称、别名、选项和位置参数数量，[`parse`] 按声明
+//! 解析参数，[`help`] 生成本地化的子命令帮助。
+//!
+//! - 选项可以出现在位置参数之前或之后，`--` 之后的参数都按位置参数处理
+//! - 带值的选项支持 `--ttl 60` 和 `--ttl=60` 两种写法
+//! - 未声明的选项、缺少值或多余的位置参数返回 [`ArgError`]
+
+use super::messages::{Messages, Msg};
+
+/// 选项声明
+#[derive(Debug)]
+pub struct Opt {
+    /// 长选项名（不含 `--`）
+    pub long: &'static str,
+    /// 短选项（含 `-`，如 `-t`）
+    pub short: Option<&'static str>,
+    /// 值的占位符，`None` 表示开关选项
+    pub value: Option<Msg>,
+    /// 帮助说明
+    pub help: Msg,
+}
+
+/// 子命令声明
+#[derive(Debug)]
+pub struct Command {
+    /// 名称
+    pub name: &'static str,
+    /// 别名
+    pub aliases: &'static [&'static str],
+    /// 帮助说明
+    pub about: Msg,
+    /// 位置参数的用法说明
+    pub usage: Msg,
+    /// 选项
+    pub options: &'static [Opt],
+    /// 位置参数的最大数量，`None` 表示不限
+    pub max_args: Option<usize>,
+}
+
+const HELP: Opt = Opt {
+    long: "help",
+    short: Some("-h"),
+    value: None,
+    help: Msg::OptHelp,
+};
+
+const NO_COMPRESS: Opt = Opt {
+    long: "no-compress",
+    short: Some("-nc"),
+    value: None,
+    help: Msg::OptNoCompress,
+};
+
+const IGNORE_TTL: Opt = Opt {
+    long: "ignore-ttl",
+    short: Some("-it"),
+    value: None,
+    help: Msg::OptIgnoreTtl,
+};
+
+const INPUT: Opt = Opt {
+    long: "input",
+    short: Some("-i"),
+    value: Some(Msg::ValueFile),
+    help: Msg::OptInput,
+};
+
+const OUTPUT: Opt = Opt {
+    long: "output",
+    short: Some("-o"),
+    value: Some(Msg::ValueFile),
+    help: Msg::OptOutput,
+};
+
+const NO_PADDING: Opt = Opt {
+    long: "no-padding",
+    short: Some("-np"),
+    value: None,
+    help: Msg::OptNoPadding,
+};
+
+const WRAP: Opt = Opt {
+    long: "wrap",
+    short: Some("-w"),
+    value: Some(Msg::ValueColumns),
+    help: Msg::OptWrap,
+};
+
+const CRLF: Opt = Opt {
+    long: "crlf",
+    short: None,
+    value: None,
+    help: Msg::OptCrlf,
+};
+
+const JSON: Opt = Opt {
+    long: "json",
+    short: None,
+    value: None,
+    help: Msg::OptJson,
+};
+
+const FORMAT: Opt = Opt {
+    long: "format",
+    short: None,
+    value: Some(Msg::ValueFormat),
+    help: Msg::OptFormat,
+};
+
+const LINES: Opt = Opt {
+    long: "lines",
+    short: Some("-l"),
+    value: None,
+    help: Msg::OptLines,
+};
+
+const NULL: Opt = Opt {
+    long: "null",
+    short: Some("-0"),
+    value: None,
+    help: Msg::OptNull,
+};
+
+const SKIP_ERRORS: Opt = Opt {
+    long: "skip-errors",
+    short: None,
+    value: None,
+    help: Msg::OptSkipErrors,
+};
+
+const FAIL_FAST: Opt = Opt {
+    long: "fail-fast",
+    short: None,
+    value: None,
+    help: Msg::OptFailFast,
+};
+
+const JOBS: Opt = Opt {
+    long: "jobs",
+    short: Some("-j"),
+    value: Some(Msg::ValueCount),
+    help: Msg::OptJobs,
+};
+
+const UTC: Opt = Opt {
+    long: "utc",
+    short: None,
+    value: None,
+    help: Msg::OptUtc,
+};
+
+const PATH: Opt = Opt {
+    long: "path",
+    short: Some("-p"),
+    value: Some(Msg::ValueSelector),
+    help: Msg::OptPath,
+};
+
+/// 所有子命令
+pub const COMMANDS: &[Command] = &[
+    Command {
+        name: "encode",
+        aliases: &["e", "-e"],
+        about: Msg::CmdEncode,
+        usage: Msg::UsageText,
+        options: &[
+            NO_COMPRESS,
+            NO_PADDING,
+            Opt {
+                long: "ttl",
+                short: Some("-t"),
+                value: Some(Msg::ValueDuration),
+                help: Msg::OptTtl,
+            },
+            Opt {
+                long: "expires",
+                short: None,
+                value: Some(Msg::ValueTime),
+                help: Msg::OptExpires,
+            },
+            WRAP,
+            CRLF,
+            INPUT,
+            OUTPUT,
+            Opt {
+                long: "text",
+                short: None,
+                value: None,
+                help: Msg::OptTextEncode,
+            },
+            LINES,
+            NULL,
+            SKIP_ERRORS,
+            FAIL_FAST,
+            JOBS,
+            JSON,
+            FORMAT,
+        ],
+        max_args: None,
+    },
+    Command {
+        name: "decode",
+        aliases: &["d", "-d"],
+        about: Msg::CmdDecode,
+        usage: Msg::UsageEncoded,
+        options: &[
+            IGNORE_TTL,
+            INPUT,
+            OUTPUT,
+            Opt {
+                long: "text",
+                short: None,
+                value: None,
+                help: Msg::OptTextDecode,
+            },
+            Opt {
+                long: "force",
+                short: None,
+                value: None,
+                help: Msg::OptForce,
+            },
+            LINES,
+            NULL,
+            SKIP_ERRORS,
+            FAIL_FAST,
+            JOBS,
+            JSON,
+            FORMAT,
+        ],
+        max_args: Some(1),
+    },
+    Command {
+        name: "check",
+        aliases: &["c", "-c"],
+        about: Msg::CmdCheck,
+        usage: Msg::UsageString,
+        options: &[INPUT, JSON, FORMAT],
+        max_args: Some(1),
+    },
+    Command {
+        name: "verify",
+        aliases: &["v"],
+        about: Msg::CmdVerify,
+        usage: Msg::UsageEncoded,
+        options: &[INPUT, JSON, FORMAT],
+        max_args: Some(1),
+    },
+    Command {
+        name: "ttl",
+        aliases: &["expire", "expiry"],
+        about: Msg::CmdTtl,
+        usage: Msg::UsageEncoded,
+        options: &[
+            UTC,
+            Opt {
+                long: "extend",
+                short: None,
+                value: Some(Msg::ValueDuration),
+                help: Msg::OptExtend,
+            },
+            Opt {
+                long: "expires",
+                short: None,
+                value: Some(Msg::ValueTime),
+                help: Msg::OptExpires,
+            },
+            Opt {
+                long: "remove-ttl",
+                short: None,
+                value: None,
+                help: Msg::OptRemoveTtl,
+            },
+            IGNORE_TTL,
+            INPUT,
+            OUTPUT,
+            JSON,
+            FORMAT,
+        ],
+        max_args: Some(1),
+    },
+    Command {
+        name: "expired",
+        aliases: &[],
+        about: Msg::CmdExpired,
+        usage: Msg::UsageEncoded,
+        options: &[INPUT, JSON, FORMAT],
+        max_args: Some(1),
+    },
+    Command {
+        name: "inspect",
+        aliases: &[],
+        about: Msg::CmdInspect,
+        usage: Msg::UsageEncoded,
+        options: &[
+            Opt {
+                long: "raw",
+                short: None,
+                value: None,
+                help: Msg::OptRaw,
+            },
+            UTC,
+            INPUT,
+            JSON,
+            FORMAT,
+        ],
+        max_args: Some(1),
+    },
+    Command {
+        name: "json",
+        aliases: &[],
+        about: Msg::CmdJson,
+        usage: Msg::UsageDocument,
+        options: &[PATH, NO_COMPRESS, IGNORE_TTL, OUTPUT, JSON, FORMAT],
+        max_args: Some(2),
+    },
+    Command {
+        name: "toml",
+        aliases: &[],
+        about: Msg::CmdToml,
+        usage: Msg::UsageDocument,
+        options: &[PATH, NO_COMPRESS, IGNORE_TTL, OUTPUT, JSON, FORMAT],
+        max_args: Some(2),
+    },
+    Command {
+        name: "csv",
+        aliases: &[],
+        about: Msg::CmdCsv,
+        usage: Msg::UsageDocument,
+        options: &[
+            Opt {
+                long: "column",
+                short: Some("-c"),
+                value: Some(Msg::ValueColumn),
+                help: Msg::OptColumn,
+            },
+            Opt {
+                long: "delimiter",
+                short: Some("-d"),
+                value: Some(Msg::ValueChar),
+                help: Msg::OptDelimiter,
+            },
+            Opt {
+                long: "no-header",
+                short: None,
+                value: None,
+                help: Msg::OptNoHeader,
+            },
+            NO_COMPRESS,
+            IGNORE_TTL,
+            OUTPUT,
+            JSON,
+            FORMAT,
+        ],
+        max_args: Some(2),
+    },
+    Command {
+        name: "scan",
+        aliases: &[],
+        about: Msg::CmdScan,
+        usage: Msg::UsagePaths,
+        options: &[
+            Opt {
+                long: "expired-only",
+                short: None,
+                value: None,
+                help: Msg::OptExpiredOnly,
+            },
+            Opt {
+                long: "decode",
+                short: Some("-d"),
+                value: None,
+                help: Msg::OptDecode,
+            },
+            UTC,
+            JSON,
+            FORMAT,
+        ],
+        max_args: None,
+    },
+    Command {
+        name: "convert",
+        aliases: &[],
+        about: Msg::CmdConvert,
+        usage: Msg::UsageString,
+        options: &[
+            Opt {
+                long: "from",
+                short: Some("-f"),
+                value: Some(Msg::ValueEncoding),
+                help: Msg::OptFrom,
+            },
+            Opt {
+                long: "to",
+                short: Some("-t"),
+                value: Some(Msg::ValueEncoding),
+                help: Msg::OptTo,
+            },
+            Opt {
+                long: "keep-header",
+                short: None,
+                value: None,
+                help: Msg::OptKeepHeader,
+            },
+            NO_COMPRESS,
+            NO_PADDING,
+            WRAP,
+            CRLF,
+            IGNORE_TTL,
+            INPUT,
+            OUTPUT,
+            JSON,
+            FORMAT,
+        ],
+        max_args: Some(1),
+    },
+    Command {
+        name: "info",
+        aliases: &["-i", "--info"],
+        about: Msg::CmdInfo,
+        usage: Msg::UsageNone,
+        options: &[JSON, FORMAT],
+        max_args: Some(0),
+    },
+    Command {
+        name: "vectors",
+        aliases: &[],
+        about: Msg::CmdVectors,
+        usage: Msg::UsageNone,
+        options: &[JSON, FORMAT],
+        max_args: Some(0),
+    },
+];
+
+/// 按名称或别名查找子命令
+pub fn find(name: &str) -> Option<&'static Command> {
+    COMMANDS
+        .iter()
+        .find(|c| c.name == name || c.aliases.contains(&name))
+}
+
+/// 解析错误
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ArgError {
+    /// 请求显示帮助（`-h` / `--help`）
+    Help,
+    /// 未声明的选项
+    UnknownOption(String),
+    /// 选项缺少值
+    MissingValue(String),
+    /// 开关选项带了值（`--crlf=1`）
+    UnexpectedValue(String),
+    /// 多余的位置参数
+    UnexpectedArgument(String),
+}
+
+impl ArgError {
+    /// 本地化的错误信息
+    pub fn message(&self, m: &Messages, cmd: &Command) -> String {
+        match self {
+            ArgError::Help => String::new(),
+            ArgError::UnknownOption(opt) => m.format(Msg::UnknownOption, &[opt, &cmd.name]),
+            ArgError::MissingValue(opt) => m.format(Msg::MissingValue, &[opt]),
+            ArgError::UnexpectedValue(opt) => m.format(Msg::UnexpectedValue, &[opt]),
+            ArgError::UnexpectedArgument(arg) => m.format(Msg::UnexpectedArgument, &[arg]),
+        }
+    }
+}
+
+/// 解析结果
+#[derive(Debug, Default)]
+pub struct Matches {
+    /// 出现过的选项（长选项名）及其值，按出现顺序排列
+    options: Vec<(&'static str, Option<String>)>,
+    /// 位置参数
+    pub args: Vec<String>,
+}
+
+impl Matches {
+    /// 开关选项是否出现
+    pub fn flag(&self, long: &str) -> bool {
+        self.options.iter().any(|(name, _)| *name == long)
+    }
+
+    /// 选项的值，出现多次时取最后一次
+    pub fn value(&self, long: &str) -> Option<&str> {
+        self.options
+            .iter()
+            .rev()
+            .find(|(name, _)| *name == long)
+            .and_then(|(_, value)| value.as_deref())
+    }
+
+    /// 选项的所有值，按出现顺序排列
+    pub fn values(&self, long: &str) -> Vec<&str> {
+        self.options
+            .iter()
+            .filter(|(name, _)| *name == long)
+            .filter_map(|(_, value)| value.as_deref())
+            .collect()
+    }
+}
+
+/// 按子命令的声明解析参数（不含子命令名）
+pub fn parse(cmd: &Command, args: &[String]) -> Result<Matches, ArgError> {
+    let mut matches = Matches::default();
+    let mut iter = args.iter();
+
+    while let Some(arg) = iter.next() {
+        if arg == "--" {
+            matches.args.extend(iter.by_ref().cloned());
+            break;
+        }
+        // 单独的 `-` 以及不以 `-` 开头的参数为位置参数
+        if !arg.starts_with('-') || arg == "-" {
+            matches.args.push(arg.clone());
+            continue;
+        }
+
+        let (name, inline) = match arg.split_once('=') {
+            Some((name, value)) if name.starts_with("--") => (name, Some(value.to_string())),
+            _ => (arg.as_str(), None),
+        };
+        let opt = std::iter::once(&HELP)
+            .chain(cmd.options)
+            .find(|o| name.strip_prefix("--") == Some(o.long) || o.short == Some(name))
+            .ok_or_else(|| ArgError::UnknownOption(name.to_string()))?;
+
+        if opt.long == HELP.long {
+            return Err(ArgError::Help);
+        }
+        let value = match (opt.value, inline) {
+            (None, None) => None,
+            (None, Some(_)) => return Err(ArgError::UnexpectedValue(name.to_string())),
+            (Some(_), Some(value)) => Some(value),
+            (Some(_), None) => Some(
+                iter.next()
+                    .cloned()
+                    .ok_or_else(|| ArgError::MissingValue(name.to_string()))?,
+            ),
+        };
+        matches.options.push((opt.long, value));
+    }
+
+    if let Some(max) = cmd.max_args {
+        if let Some(extra) = matches.args.get(max) {
+            return Err(ArgError::UnexpectedArgument(extra.clone()));
+        }
+    }
+    Ok(matches)
+}
+
+/// 子命令的帮助信息
+pub fn help(cmd: &Command, m: &Messages) -> String {
+    let mut out = m.format(Msg::UsageLine, &[&cmd.name, &m.get(cmd.usage)]);
+    out.push_str("\n\n");
+    out.push_str(m.get(cmd.about));
+    out.push_str("\n\n");
+    out.push_str(m.get(Msg::OptionsTitle));
+
+    let rows: Vec<(String, &str)> = cmd
+        .options
+        .iter()
+        .chain(std::iter::once(&HELP))
+        .map(|o| {
+            let mut spec = match o.short {
+                Some(short) => format!("{}, --{}", short, o.long),
+                None => format!("--{}", o.long),
+            };
+            if let Some(value) = o.value {
+                spec.push(' ');
+                spec.push_str(m.get(value));
+            }
+            (spec, m.get(o.help))
+        })
+        .collect();
+    let width = rows
+        .iter()
+        .map(|(spec, _)| spec.chars().count())
+        .max()
+        .unwrap_or(0);
+    for (spec, text) in rows {
+        let pad = width - spec.chars().count();
+        out.push_str(&format!("\n  {}{}  {}", spec, " ".repeat(pad), text));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dxcode::Locale;
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_options_anywhere_and_double_dash() {
+        let encode = find("encode").unwrap();
+        let matches = parse(encode, &args(&["hello", "--ttl", "60", "world", "-nc"])).unwrap();
+        assert_eq!(matches.args, ["hello", "world"]);
+        assert_eq!(matches.value("ttl"), Some("60"));
+        assert!(matches.flag("no-compress"));
+        assert!(!matches.flag("no-padding"));
+
+        let matches = parse(encode, &args(&["--ttl=5", "--", "--no-compress", "-"])).unwrap();
+        assert_eq!(matches.value("ttl"), Some("5"));
+        assert_eq!(matches.args, ["--no-compress", "-"]);
+
+        let json = find("json").unwrap();
+        let matches = parse(
+            json,
+            &args(&["decode", "-p", "$.a", "--path=$.b", "f.json"]),
+        )
+        .unwrap();
+        assert_eq!(matches.args, ["decode", "f.json"]);
+        assert_eq!(matches.values("path"), ["$.a", "$.b"]);
+    }
+
+    #[test]
+    fn test_errors() {
+        let encode = find("e").unwrap();
+        let decode = find("-d").unwrap();
+        assert_eq!(
+            parse(encode, &args(&["--bogus"])).unwrap_err(),
+            ArgError::UnknownOption("--bogus".into())
+        );
+        assert_eq!(
+            parse(encode, &args(&["--ttl"])).unwrap_err(),
+            ArgError::MissingValue("--ttl".into())
+        );
+        assert_eq!(
+            parse(encode, &args(&["--crlf=yes"])).unwrap_err(),
+            ArgError::UnexpectedValue("--crlf".into())
+        );
+        assert_eq!(
+            parse(decode, &args(&["dxA", "dxB"])).unwrap_err(),
+            ArgError::UnexpectedArgument("dxB".into())
+        );
+        assert_eq!(
+            parse(decode, &args(&["dxA", "--help"])).unwrap_err(),
+            ArgError::Help
+        );
+        assert!(find("bogus").is_none());
+    }
+
+    #[test]
+    fn test_help_lists_options() {
+        let m = Messages::new(Locale::En);
+        let text = help(find("decode").unwrap(), &m);
+        assert!(text.starts_with("Usage: dxc decode [options] [encoded]"));
+        assert!(text.contains("-it, --ignore-ttl"));
+        assert!(text.contains("-h, --help"));
+    }
+}
@@ -0,0 +1,133 @@
+//! 批处理模式（`--lines` / `-0`）
+//!
+//! 输入按分隔符切分为独立的记录，分批交给多个线程处理，结果按输入顺序输出。
+
+use std::ops::ControlFlow;
+use std::thread;
+
+/// 每个线程每批处理的记录数
+const RECORDS_PER_JOB: usize = 256;
+
+/// 处理失败的记录
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Failure {
+    /// 错误类别（与 JSON 错误的 `kind` 一致）
+    pub kind: String,
+    /// 本地化的错误信息
+    pub message: String,
+    /// 对应的退出码
+    pub exit_code: i32,
+}
+
+/// 失败记录的处理方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorPolicy {
+    /// 报告错误，输出空记录占位以保持行对齐，最后以第一个错误的退出码退出（默认）
+    Report,
+    /// 报告错误并跳过失败的记录，以 0 退出
+    Skip,
+    /// 遇到第一个失败的记录时停止
+    FailFast,
+}
+
+/// 按分隔符切分记录
+///
+/// 结尾的分隔符不产生空记录；按行切分时去掉行尾的 `\r`。
+pub fn records(data: &[u8], delimiter: u8) -> Vec<&[u8]> {
+    if data.is_empty() {
+        return Vec::new();
+    }
+    let data = data.strip_suffix(&[delimiter]).unwrap_or(data);
+    data.split(|&b| b == delimiter)
+        .map(|r| match delimiter {
+            b'\n' => r.strip_suffix(b"\r").unwrap_or(r),
+            _ => r,
+        })
+        .collect()
+}
+
+/// 用 `jobs` 个线程处理记录，按输入顺序把 `(序号, 结果)` 交给 `emit`
+///
+/// `emit` 返回 [`ControlFlow::Break`] 时停止处理后续记录。
+pub fn process<T, F, E>(records: &[&[u8]], jobs: usize, f: F, mut emit: E)
+where
+    T: Send,
+    F: Fn(&[u8]) -> T + Sync,
+    E: FnMut(usize, T) -> ControlFlow<()>,
+{
+    let jobs = jobs.max(1);
+    let batch = jobs * RECORDS_PER_JOB;
+    for (n, chunk) in records.chunks(batch).enumerate() {
+        let results: Vec<T> = if jobs == 1 || chunk.len() <= RECORDS_PER_JOB {
+            chunk.iter().map(|r| f(r)).collect()
+        } else {
+            let per_job = chunk.len().div_ceil(jobs);
+            thread::scope(|s| {
+                let handles: Vec<_> = chunk
+                    .chunks(per_job)
+                    .map(|part| s.spawn(|| part.iter().map(|r| f(r)).collect::<Vec<T>>()))
+                    .collect();
+                handles
+                    .into_iter()
+                    .flat_map(|h| h.join().expect("batch worker panicked"))
+                    .collect()
+            })
+        };
+        for (i, result) in results.into_iter().enumerate() {
+            if emit(n * batch + i, result).is_break() {
+                return;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_records() {
+        assert_eq!(
+            records(b"a\nb\r\n\nc\n", b'\n'),
+            [&b"a"[..], b"b", b"", b"c"]
+        );
+        assert_eq!(records(b"a\r\0b", 0), [&b"a\r"[..], b"b"]);
+        assert!(records(b"", b'\n').is_empty());
+        assert_eq!(records(b"\n", b'\n'), [&b""[..]]);
+    }
+
+    #[test]
+    fn test_process_preserves_order_and_stops() {
+        let data: Vec<Vec<u8>> = (0..5000).map(|i: u32| i.to_string().into_bytes()).collect();
+        let records: Vec<&[u8]> = data.iter().map(Vec::as_slice).collect();
+
+        let mut seen = Vec::new();
+        process(
+            &records,
+            4,
+            |r| r.to_vec(),
+            |i, r| {
+                assert_eq!(r, records[i]);
+                seen.push(i);
+                ControlFlow::Continue(())
+            },
+        );
+        assert_eq!(seen, (0..5000).collect::<Vec<_>>());
+
+        let mut count = 0;
+        process(
+            &records,
+            4,
+            |r| r.len(),
+            |i, _| {
+                count += 1;
+                if i == 1500 {
+                    ControlFlow::Break(())
+                } else {
+                    ControlFlow::Continue(())
+                }
+            },
+        );
+        assert_eq!(count, 1501);
+    }
+}
@@ -0,0 +1,554 @@
+//! 结构化文档中字段的编解码（`dxc json` / `dxc toml` / `dxc csv`）
+//!
+//! 只改写选中的字段，文档的其余部分保持原样：TOML 保留注释和格式，
+//! CSV 未选中的字段按原始文本输出，JSON 只替换选中字符串的字面量。
+
+use std::borrow::Cow;
+use std::ops::Range;
+
+use dxcode::{DxError, Engine, FieldOp, Segment, Selector, TransformError};
+use serde::de::IgnoredAny;
+use toml_edit::{DocumentMut, Formatted, Item, Table};
+
+/// 文档处理错误
+#[derive(Debug)]
+pub enum DocError {
+    /// 文档无法解析
+    Parse(String),
+    /// CSV 的带引号字段没有结束（起始行号）
+    Unterminated(usize),
+    /// 找不到 `--column` 指定的列
+    ColumnNotFound(String),
+    /// 选择器无效或字段编解码失败
+    Transform(TransformError),
+    /// CSV 字段编解码失败
+    CsvField {
+        line: usize,
+        column: String,
+        error: DxError,
+    },
+}
+
+impl From<TransformError> for DocError {
+    fn from(e: TransformError) -> DocError {
+        DocError::Transform(e)
+    }
+}
+
+/// 改写 JSON 文档中选中的字符串，其余文本（数字写法、转义、空白）保持原样
+pub fn json(
+    input: &str,
+    selectors: &[&str],
+    op: FieldOp,
+    engine: &Engine,
+) -> Result<String, DocError> {
+    serde_json::from_str::<IgnoredAny>(input).map_err(|e| DocError::Parse(e.to_string()))?;
+    let mut out = input.to_string();
+    for selector in selectors {
+        let selector = Selector::parse(selector)?;
+        let root = Scanner {
+            input: &out,
+            pos: 0,
+        }
+        .value();
+        let mut targets = Vec::new();
+        select_json(&root, selector.segments(), "$".to_string(), &mut targets);
+
+        // 先计算所有新值，全部成功后再写回
+        let mut spans = targets
+            .into_iter()
+            .map(|(path, node)| {
+                let JsonNode::String(span) = node else {
+                    return Err(not_a_string(&path));
+                };
+                let value: String =
+                    serde_json::from_str(&out[span.clone()]).expect("scanned a JSON string");
+                let new = op
+                    .apply(engine, &value)
+                    .map_err(|error| TransformError::Field { path, error })?;
+                Ok((
+                    span.clone(),
+                    serde_json::to_string(&new).expect("strings serialize"),
+                ))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        spans.sort_by_key(|(span, _)| span.start);
+        for (span, new) in spans.into_iter().rev() {
+            out.replace_range(span, &new);
+        }
+    }
+    Ok(out)
+}
+
+/// JSON 值在原文中的结构，字符串记录字面量（含引号）的字节范围
+enum JsonNode {
+    String(Range<usize>),
+    Array(Vec<JsonNode>),
+    Object(Vec<(String, JsonNode)>),
+    /// 数字、布尔值或 `null`
+    Other,
+}
+
+/// 扫描已通过 serde_json 验证的 JSON 文本
+struct Scanner<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl Scanner<'_> {
+    fn skip_whitespace(&mut self) {
+        let rest = &self.input[self.pos..];
+        self.pos += rest.len() - rest.trim_start_matches([' ', '\t', '\n', '\r']).len();
+    }
+
+    fn peek(&mut self) -> u8 {
+        self.skip_whitespace();
+        self.input.as_bytes()[self.pos]
+    }
+
+    fn value(&mut self) -> JsonNode {
+        match self.peek() {
+            b'"' => JsonNode::String(self.string()),
+            b'[' => {
+                self.pos += 1;
+                let mut items = Vec::new();
+                while self.peek() != b']' {
+                    items.push(self.value());
+                    if self.peek() == b',' {
+                        self.pos += 1;
+                    }
+                }
+                self.pos += 1;
+                JsonNode::Array(items)
+            }
+            b'{' => {
+                self.pos += 1;
+                let mut fields = Vec::new();
+                while self.peek() != b'}' {
+                    let key = self.string();
+                    let key = serde_json::from_str(&self.input[key]).expect("scanned a JSON key");
+                    self.peek();
+                    self.pos += 1; // ':'
+                    fields.push((key, self.value()));
+                    if self.peek() == b',' {
+                        self.pos += 1;
+                    }
+                }
+                self.pos += 1;
+                JsonNode::Object(fields)
+            }
+            _ => {
+                let rest = &self.input[self.pos..];
+                self.pos += rest
+                    .find([',', ']', '}', ' ', '\t', '\n', '\r'])
+                    .unwrap_or(rest.len());
+                JsonNode::Other
+            }
+        }
+    }
+
+    /// 字符串字面量的范围，调用时 `pos` 指向开头的引号
+    fn string(&mut self) -> Range<usize> {
+        let bytes = self.input.as_bytes();
+        let start = self.pos;
+        let mut i = start + 1;
+        while bytes[i] != b'"' {
+            i += if bytes[i] == b'\\' { 2 } else { 1 };
+        }
+        self.pos = i + 1;
+        start..self.pos
+    }
+}
+
+/// 收集选中的值及其具体路径
+fn select_json<'a>(
+    node: &'a JsonNode,
+    segments: &[Segment],
+    path: String,
+    out: &mut Vec<(String, &'a JsonNode)>,
+) {
+    let Some((segment, rest)) = segments.split_first() else {
+        out.push((path, node));
+        return;
+    };
+    match node {
+        JsonNode::Object(fields) => {
+            for (key, value) in fields {
+                let key = Segment::Key(key.clone());
+                if matches!(segment, Segment::Wildcard) || *segment == key {
+                    select_json(value, rest, format!("{}{}", path, key), out);
+                }
+            }
+        }
+        JsonNode::Array(items) => {
+            for (i, item) in items.iter().enumerate() {
+                if matches!(segment, Segment::Wildcard) || *segment == Segment::Index(i) {
+                    select_json(item, rest, format!("{}{}", path, Segment::Index(i)), out);
+                }
+            }
+        }
+        JsonNode::String(_) | JsonNode::Other => {}
+    }
+}
+
+/// 改写 TOML 文档，保留注释、空白和键的顺序
+pub fn toml(
+    input: &str,
+    selectors: &[&str],
+    op: FieldOp,
+    engine: &Engine,
+) -> Result<String, DocError> {
+    let mut doc: DocumentMut = input
+        .parse()
+        .map_err(|e: toml_edit::TomlError| DocError::Parse(e.message().to_string()))?;
+    for selector in selectors {
+        let selector = Selector::parse(selector)?;
+        let mut apply = |path: &str, value: &str| {
+            op.apply(engine, value)
+                .map_err(|error| TransformError::Field {
+                    path: path.to_string(),
+                    error,
+                })
+        };
+        walk_item(doc.as_item_mut(), selector.segments(), "$", &mut apply)?;
+    }
+    Ok(doc.to_string())
+}
+
+type Apply<'a> = dyn FnMut(&str, &str) -> Result<String, TransformError> + 'a;
+
+fn walk_item(
+    item: &mut Item,
+    segments: &[Segment],
+    path: &str,
+    apply: &mut Apply<'_>,
+) -> Result<(), TransformError> {
+    match item {
+        Item::None => Ok(()),
+        Item::Value(value) => walk_value(value, segments, path, apply),
+        Item::Table(table) => walk_table(table, segments, path, apply),
+        Item::ArrayOfTables(tables) => {
+            let Some((segment, rest)) = segments.split_first() else {
+                return Err(not_a_string(path));
+            };
+            for (i, table) in tables.iter_mut().enumerate() {
+                if matches!(segment, Segment::Wildcard) || *segment == Segment::Index(i) {
+                    let path = format!("{}{}", path, Segment::Index(i));
+                    walk_table(table, rest, &path, apply)?;
+                }
+            }
+            Ok(())
+        }
+    }
+}
+
+fn walk_table(
+    table: &mut Table,
+    segments: &[Segment],
+    path: &str,
+    apply: &mut Apply<'_>,
+) -> Result<(), TransformError> {
+    let Some((segment, rest)) = segments.split_first() else {
+        return Err(not_a_string(path));
+    };
+    for (key, item) in table.iter_mut() {
+        let key = Segment::Key(key.get().to_string());
+        if matches!(segment, Segment::Wildcard) || *segment == key {
+            walk_item(item, rest, &format!("{}{}", path, key), apply)?;
+        }
+    }
+    Ok(())
+}
+
+fn walk_value(
+    value: &mut toml_edit::Value,
+    segments: &[Segment],
+    path: &str,
+    apply: &mut Apply<'_>,
+) -> Result<(), TransformError> {
+    let Some((segment, rest)) = segments.split_first() else {
+        let toml_edit::Value::String(s) = value else {
+            return Err(not_a_string(path));
+        };
+        // 保留值前后的空白和注释
+        let decor = s.decor().clone();
+        *s = Formatted::new(apply(path, s.value())?);
+        *s.decor_mut() = decor;
+        return Ok(());
+    };
+    match value {
+        toml_edit::Value::Array(items) => {
+            for (i, item) in items.iter_mut().enumerate() {
+                if matches!(segment, Segment::Wildcard) || *segment == Segment::Index(i) {
+                    let path = format!("{}{}", path, Segment::Index(i));
+                    walk_value(item, rest, &path, apply)?;
+                }
+            }
+        }
+        toml_edit::Value::InlineTable(table) => {
+            for (key, item) in table.iter_mut() {
+                let key = Segment::Key(key.get().to_string());
+                if matches!(segment, Segment::Wildcard) || *segment == key {
+                    walk_value(item, rest, &format!("{}{}", path, key), apply)?;
+                }
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+fn not_a_string(path: &str) -> TransformError {
+    TransformError::NotAString {
+        path: path.to_string(),
+    }
+}
+
+/// 一行 CSV 记录：各字段的原始文本和行尾
+struct Row<'a> {
+    fields: Vec<&'a str>,
+    line: usize,
+    end: &'a str,
+}
+
+/// 按 RFC 4180 切分记录，字段保留原始文本（含引号）
+fn rows(input: &str, delimiter: u8) -> Result<Vec<Row<'_>>, DocError> {
+    let bytes = input.as_bytes();
+    let mut rows = Vec::new();
+    let mut fields = Vec::new();
+    let (mut start, mut line, mut row_line) = (0, 1, 1);
+    let mut in_quotes = false;
+    let mut i = 0;
+    while i < bytes.len() {
+        let b = bytes[i];
+        if in_quotes {
+            match b {
+                b'"' if bytes.get(i + 1) == Some(&b'"') => i += 1,
+                b'"' => in_quotes = false,
+                b'\n' => line += 1,
+                _ => {}
+            }
+        } else if b == b'"' && i == start {
+            in_quotes = true;
+        } else if b == delimiter {
+            fields.push(&input[start..i]);
+            start = i + 1;
+        } else if b == b'\n' {
+            let end = if i > start && bytes[i - 1] == b'\r' {
+                i - 1
+            } else {
+                i
+            };
+            fields.push(&input[start..end]);
+            rows.push(Row {
+                fields: std::mem::take(&mut fields),
+                line: row_line,
+                end: &input[end..=i],
+            });
+            line += 1;
+            row_line = line;
+            start = i + 1;
+        }
+        i += 1;
+    }
+    if in_quotes {
+        return Err(DocError::Unterminated(row_line));
+    }
+    if start < bytes.len() || !fields.is_empty() {
+        fields.push(&input[start..]);
+        rows.push(Row {
+            fields,
+            line: row_line,
+            end: "",
+        });
+    }
+    Ok(rows)
+}
+
+/// 字段的值（去掉引号并还原 `""`）
+fn unquote(raw: &str) -> Cow<'_, str> {
+    match raw.strip_prefix('"').and_then(|r| r.strip_suffix('"')) {
+        Some(inner) => Cow::Owned(inner.replace("\"\"", "\"")),
+        None => Cow::Borrowed(raw),
+    }
+}
+
+/// 必要时给字段加上引号
+fn quote(value: &str, delimiter: u8) -> String {
+    if value.contains(['"', '\n', '\r', delimiter as char]) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// 改写 CSV 文件中选中的列，空字段保持不变
+///
+/// `columns` 先按表头的列名匹配，再按从 1 开始的列号匹配。
+pub fn csv(
+    input: &str,
+    columns: &[&str],
+    delimiter: u8,
+    header: bool,
+    op: FieldOp,
+    engine: &Engine,
+) -> Result<String, DocError> {
+    let rows = rows(input, delimiter)?;
+    let names: Vec<Cow<'_, str>> = match (header, rows.first()) {
+        (true, Some(row)) => row.fields.iter().map(|f| unquote(f)).collect(),
+        _ => Vec::new(),
+    };
+    let selected = columns
+        .iter()
+        .map(|column| {
+            names
+                .iter()
+                .position(|name| name == column)
+                .or_else(|| {
+                    column
+                        .parse::<usize>()
+                        .ok()
+                        .filter(|&n| n > 0)
+                        .map(|n| n - 1)
+                })
+                .ok_or_else(|| DocError::ColumnNotFound(column.to_string()))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut out = String::with_capacity(input.len());
+    for (n, row) in rows.iter().enumerate() {
+        for (i, raw) in row.fields.iter().enumerate() {
+            if i > 0 {
+                out.push(delimiter as char);
+            }
+            let value = unquote(raw);
+            if (header && n == 0) || value.is_empty() || !selected.contains(&i) {
+                out.push_str(raw);
+                continue;
+            }
+            let new = op
+                .apply(engine, &value)
+                .map_err(|error| DocError::CsvField {
+                    line: row.line,
+                    column: names
+                        .get(i)
+                        .map_or_else(|| (i + 1).to_string(), |c| c.to_string()),
+                    error,
+                })?;
+            out.push_str(&quote(&new, delimiter));
+        }
+        out.push_str(row.end);
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dxcode::STANDARD;
+
+    const ENCODE: FieldOp = FieldOp::Encode {
+        allow_compression: true,
+    };
+    const DECODE: FieldOp = FieldOp::Decode { check_ttl: true };
+
+    #[test]
+    fn test_json_keeps_order_and_indent() {
+        let input =
+            "{\n    \"z\": 1,\n    \"token\": \"secret\",\n    \"a\": [\n        1.5\n    ]\n}\n";
+        let encoded = json(input, &["$.token"], ENCODE, &STANDARD).unwrap();
+        let lines: Vec<&str> = encoded.lines().collect();
+        assert_eq!(lines[1], "    \"z\": 1,");
+        assert!(lines[2].starts_with("    \"token\": \"dx"));
+        assert_eq!(
+            json(&encoded, &["$.token"], DECODE, &STANDARD).unwrap(),
+            input
+        );
+
+        let compact = json("{\"b\":\"x\",\"a\":1}", &["$.b"], ENCODE, &STANDARD).unwrap();
+        assert!(compact.starts_with("{\"b\":\"dx") && compact.ends_with(",\"a\":1}"));
+    }
+
+    #[test]
+    fn test_json_keeps_unselected_text() {
+        let input = concat!(
+            "{\"id\": 123456789012345678901234567890, \"n\": 1.50, \"e\": 1e3,\n",
+            " \"s\": \"caf\\u00e9 \\\"q\\\"\", \"arr\": [1, 2, 3],\n",
+            " \"k\\u0065y\": {\"token\": \"secret\"}, \"token\": \"x\"}"
+        );
+        let encoded = json(input, &["$.key.token"], ENCODE, &STANDARD).unwrap();
+        let token = STANDARD.encode(b"secret");
+        assert_eq!(
+            encoded,
+            input.replace("\"secret\"", &format!("\"{}\"", token))
+        );
+        assert_eq!(
+            json(&encoded, &["$.key.token"], DECODE, &STANDARD).unwrap(),
+            input
+        );
+
+        let err = json(input, &["$.arr"], ENCODE, &STANDARD).unwrap_err();
+        assert!(matches!(
+            err,
+            DocError::Transform(TransformError::NotAString { ref path }) if path == "$.arr"
+        ));
+        assert!(matches!(
+            json("{\"a\": ", &["$.a"], ENCODE, &STANDARD),
+            Err(DocError::Parse(_))
+        ));
+    }
+
+    #[test]
+    fn test_toml_keeps_comments() {
+        let input = "# users\n[[users]]\nname = \"a\" # name\ntoken = \"secret\" # keep\n\n[db]\nurl = \"x\"\n";
+        let encoded = toml(input, &["$.users[*].token"], ENCODE, &STANDARD).unwrap();
+        assert!(encoded.contains("token = \"dx"));
+        assert!(encoded.contains("\" # keep\n"));
+        assert_eq!(
+            toml(&encoded, &["$.users[0].token"], DECODE, &STANDARD).unwrap(),
+            input
+        );
+
+        let err = toml(input, &["$.db"], ENCODE, &STANDARD).unwrap_err();
+        assert!(matches!(
+            err,
+            DocError::Transform(TransformError::NotAString { ref path }) if path == "$.db"
+        ));
+    }
+
+    #[test]
+    fn test_csv_rows() {
+        let parsed = rows("a,\"b,\"\"c\"\"\"\r\n\"x\ny\",2\n\n", b',').unwrap();
+        assert_eq!(parsed.len(), 3);
+        assert_eq!(parsed[0].fields, ["a", "\"b,\"\"c\"\"\""]);
+        assert_eq!(parsed[0].end, "\r\n");
+        assert_eq!(parsed[1].line, 2);
+        assert_eq!(unquote(parsed[1].fields[0]), "x\ny");
+        assert_eq!(parsed[2].fields, [""]);
+        assert!(matches!(
+            rows("a,\"b\n", b','),
+            Err(DocError::Unterminated(1))
+        ));
+    }
+
+    #[test]
+    fn test_csv_selected_columns() {
+        let input = "id,secret,note\n1,hunter2,\"a, b\"\n2,,x\n";
+        let encoded = csv(input, &["secret"], b',', true, ENCODE, &STANDARD).unwrap();
+        let lines: Vec<&str> = encoded.lines().collect();
+        assert_eq!(lines[0], "id,secret,note");
+        assert!(lines[1].starts_with("1,dx") && lines[1].ends_with(",\"a, b\""));
+        assert_eq!(lines[2], "2,,x");
+        assert_eq!(
+            csv(&encoded, &["2"], b',', true, DECODE, &STANDARD).unwrap(),
+            input
+        );
+
+        assert!(matches!(
+            csv(input, &["missing"], b',', true, ENCODE, &STANDARD),
+            Err(DocError::ColumnNotFound(_))
+        ));
+        let err = csv(input, &["note"], b',', true, DECODE, &STANDARD).unwrap_err();
+        assert!(matches!(err, DocError::CsvField { line: 2, ref column, .. } if column == "note"));
+    }
+}
@@ -0,0 +1,29 @@
+//! 退出码
+//!
+//! | 退出码 | 含义                               |
+//! | ------ | ---------------------------------- |
+//! | 0      | 成功                               |
+//! | 1      | 输入无效（格式错误、不是 DX 编码） |
+//! | 2      | 校验和不匹配                       |
+//! | 3      | TTL 已过期                         |
+//! | 64     | 用法错误（未知命令或选项、缺少参数） |
+
+use dxcode::{DxError, ErrorKind};
+
+/// 输入无效
+pub const INVALID: i32 = 1;
+/// 校验和不匹配
+pub const CHECKSUM: i32 = 2;
+/// TTL 已过期
+pub const EXPIRED: i32 = 3;
+/// 用法错误（与 BSD `EX_USAGE` 一致）
+pub const USAGE: i32 = 64;
+
+/// 库错误对应的退出码
+pub fn for_error(e: &DxError) -> i32 {
+    match e.kind() {
+        ErrorKind::ChecksumMismatch => CHECKSUM,
+        ErrorKind::TtlExpired => EXPIRED,
+        _ => INVALID,
+    }
+}
@@ -0,0 +1,187 @@
+//! `dxc inspect` 的输出
+
+use std::fmt::Write;
+use std::time::Duration;
+
+use dxcode::{format_duration, Inspection};
+use serde_json::{json, Value};
+
+use super::messages::{Messages, Msg};
+use super::output;
+use super::time::{iso8601, rfc3339};
+
+/// 十六进制转储，每行 16 字节：偏移、十六进制和可打印字符
+pub fn hexdump(data: &[u8]) -> String {
+    let mut out = String::new();
+    for (i, chunk) in data.chunks(16).enumerate() {
+        let _ = write!(out, "{:08x} ", i * 16);
+        for j in 0..16 {
+            if j == 8 {
+                out.push(' ');
+            }
+            match chunk.get(j) {
+                Some(b) => {
+                    let _ = write!(out, " {:02x}", b);
+                }
+                None => out.push_str("   "),
+            }
+        }
+        out.push_str("  |");
+        out.extend(chunk.iter().map(|&b| {
+            if b.is_ascii_graphic() || b == b' ' {
+                b as char
+            } else {
+                '.'
+            }
+        }));
+        out.push_str("|\n");
+    }
+    out
+}
+
+/// 压缩率（存储的数据部分 / 原始数据），仅压缩数据有意义
+fn ratio(inspection: &Inspection) -> Option<f64> {
+    let actual = inspection.data.as_ref()?.len();
+    (inspection.declared_size.is_some() && actual > 0)
+        .then(|| inspection.payload().len() as f64 / actual as f64)
+}
+
+fn crc(value: Option<u16>) -> Option<String> {
+    value.map(|c| format!("0x{:04X}", c))
+}
+
+/// 本地化的文本输出
+pub fn text(m: &Messages, prefix: &str, inspection: &Inspection) -> String {
+    let mut lines = vec![m.get(Msg::InspectTitle).to_string(), String::new()];
+    if let Some(e) = &inspection.error {
+        lines.push(m.format(Msg::InspectError, &[&m.dx_message(e)]));
+        lines.push(String::new());
+    }
+    lines.push(m.format(Msg::InspectPrefix, &[&prefix]));
+    lines.push(m.format(Msg::InspectFrame, &[&inspection.frame.len()]));
+
+    if let Some(flags) = inspection.flags {
+        let mut names: Vec<String> = inspection
+            .flag_names()
+            .iter()
+            .map(|n| n.to_string())
+            .collect();
+        if inspection.unknown_flags() != 0 {
+            let unknown = format!("{:02X}", inspection.unknown_flags());
+            names.push(m.format(Msg::InspectUnknownFlags, &[&unknown]));
+        }
+        let names = if names.is_empty() {
+            m.get(Msg::InspectFlagsNone).to_string()
+        } else {
+            names.join(", ")
+        };
+        let flags = format!("{:02X}", flags);
+        lines.push(m.format(Msg::InspectFlags, &[&flags, &names]));
+    }
+
+    if let Some(stored) = crc(inspection.stored_checksum) {
+        let computed = crc(inspection.computed_checksum).unwrap_or_else(|| "?".into());
+        let status = match inspection.checksum_matches() {
+            Some(true) => m.get(Msg::InspectCrcMatch),
+            Some(false) => m.get(Msg::InspectCrcMismatch),
+            None => "",
+        };
+        lines.push(
+            m.format(Msg::InspectCrc, &[&stored, &computed, &status])
+                .trim_end()
+                .to_string(),
+        );
+    }
+
+    if let (Some(created_at), Some(ttl_seconds)) = (inspection.created_at, inspection.ttl_seconds) {
+        lines.push(m.format(Msg::TtlCreated, &[&rfc3339(created_at), &created_at]));
+        match inspection.expires_at() {
+            Some(expires) => {
+                let lifetime = format_duration(Duration::from_secs(ttl_seconds.into()));
+                lines.push(m.format(Msg::TtlLifetime, &[&lifetime, &ttl_seconds]));
+                lines.push(m.format(Msg::TtlExpires, &[&rfc3339(expires), &expires]));
+            }
+            None => lines.push(m.get(Msg::TtlLifetimeNever).to_string()),
+        }
+    }
+    if let Some(content_type) = inspection.content_type {
+        let content_type = format!("{:02X}", content_type);
+        lines.push(m.format(Msg::InspectContentType, &[&content_type]));
+    }
+
+    let actual = inspection.data.as_ref().map(Vec::len);
+    match (inspection.declared_size, actual) {
+        (Some(declared), actual) => {
+            let actual = actual.map_or_else(|| "?".to_string(), |a| a.to_string());
+            lines.push(m.format(Msg::InspectDeclaredSize, &[&declared, &actual]));
+        }
+        (None, Some(actual)) => lines.push(m.format(Msg::InspectSize, &[&actual])),
+        (None, None) => {}
+    }
+    if let (Some(ratio), Some(actual)) = (ratio(inspection), actual) {
+        let percent = format!("{:.1}%", ratio * 100.0);
+        let stored = inspection.payload().len();
+        lines.push(m.format(Msg::InspectRatio, &[&percent, &stored, &actual]));
+    }
+
+    lines.push(String::new());
+    lines.push(m.format(
+        Msg::InspectPayload,
+        &[&inspection.payload().len(), &inspection.payload_offset],
+    ));
+    let mut out = lines.join("\n");
+    out.push('\n');
+    out.push_str(&hexdump(inspection.payload()));
+    out
+}
+
+/// 结构化输出
+pub fn value(m: &Messages, prefix: &str, inspection: &Inspection) -> Value {
+    let expires_at = inspection.expires_at();
+    json!({
+        "prefix": prefix,
+        "frame_len": inspection.frame.len(),
+        "flags": inspection.flags,
+        "flag_names": inspection.flag_names(),
+        "unknown_flags": inspection.unknown_flags(),
+        "crc16": crc(inspection.stored_checksum),
+        "computed_crc16": crc(inspection.computed_checksum),
+        "checksum_ok": inspection.checksum_matches(),
+        "created_at": inspection.created_at,
+        "created_at_iso": inspection.created_at.map(iso8601),
+        "ttl_seconds": inspection.ttl_seconds,
+        "expires_at": expires_at,
+        "expires_at_iso": expires_at.map(iso8601),
+        "content_type": inspection.content_type,
+        "declared_size": inspection.declared_size,
+        "actual_size": inspection.data.as_ref().map(Vec::len),
+        "ratio": ratio(inspection),
+        "payload_offset": inspection.payload_offset,
+        "payload_len": inspection.payload().len(),
+        "payload_hex": output::hex(inspection.payload()),
+        "error": inspection.error.as_ref().map(|e| json!({
+            "kind": format!("{:?}", e.kind()),
+            "message": m.dx_message(e),
+        })),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hexdump() {
+        let dump = hexdump(b"Hello, Dogxi!\x00\x01\xFFtail");
+        let mut lines = dump.lines();
+        assert_eq!(
+            lines.next(),
+            Some("00000000  48 65 6c 6c 6f 2c 20 44  6f 67 78 69 21 00 01 ff  |Hello, Dogxi!...|")
+        );
+        assert_eq!(
+            lines.next(),
+            Some("00000010  74 61 69 6c                                       |tail|")
+        );
+        assert_eq!(hexdump(b""), "");
+    }
+}
@@ -0,0 +1,82 @@
+//! 二进制安全的输入输出
+//!
+//! 输入和输出都按原始字节处理，不做任何编码转换或去除空白。
+
+use std::fs;
+use std::io::{self, Read, Write};
+
+/// 读取管道输入的原始字节，标准输入为终端时返回 `None`
+pub fn read_stdin() -> io::Result<Option<Vec<u8>>> {
+    if atty::is(atty::Stream::Stdin) {
+        return Ok(None);
+    }
+    let mut data = Vec::new();
+    io::stdin().lock().read_to_end(&mut data)?;
+    Ok(Some(data))
+}
+
+/// 读取文件的原始字节，`-` 表示标准输入
+pub fn read_path(path: &str) -> io::Result<Vec<u8>> {
+    if path == "-" {
+        let mut data = Vec::new();
+        io::stdin().lock().read_to_end(&mut data)?;
+        return Ok(data);
+    }
+    fs::read(path)
+}
+
+/// 写入文件，`-` 表示标准输出
+pub fn write_path(path: &str, data: &[u8]) -> io::Result<()> {
+    if path == "-" {
+        return write_stdout(data);
+    }
+    fs::write(path, data)
+}
+
+/// 打开带缓冲的输出，`-` 表示标准输出
+pub fn create(path: &str) -> io::Result<Box<dyn Write>> {
+    if path == "-" {
+        return Ok(Box::new(io::BufWriter::new(io::stdout().lock())));
+    }
+    Ok(Box::new(io::BufWriter::new(fs::File::create(path)?)))
+}
+
+/// 原样写到标准输出
+pub fn write_stdout(data: &[u8]) -> io::Result<()> {
+    let mut stdout = io::stdout().lock();
+    stdout.write_all(data)?;
+    stdout.flush()
+}
+
+/// 标准输出是否为终端
+pub fn stdout_is_terminal() -> bool {
+    atty::is(atty::Stream::Stdout)
+}
+
+/// 是否为不适合直接显示在终端上的二进制数据
+///
+/// 不是有效的 UTF-8，或包含除制表符和换行以外的控制字符（包括 ESC，
+/// 避免向终端写入转义序列）。
+pub fn is_binary(data: &[u8]) -> bool {
+    match std::str::from_utf8(data) {
+        Ok(s) => s
+            .chars()
+            .any(|c| c.is_control() && !matches!(c, '\t' | '\n' | '\r')),
+        Err(_) => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_binary() {
+        assert!(!is_binary(b""));
+        assert!(!is_binary("你好，Dogxi！\n\tok\r\n".as_bytes()));
+        assert!(is_binary(b"\x89PNG\r\n\x1a\n"));
+        assert!(is_binary(b"a\0b"));
+        assert!(is_binary(b"\x1b[2J"));
+        assert!(is_binary(&[0xFF, 0xFE]));
+    }
+}
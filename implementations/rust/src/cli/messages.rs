@@ -0,0 +1,795 @@
+//! 命令行消息目录（en / zh-CN）
+//!
+//! 每条消息在 [`Msg::text`] 中同时给出英文和中文版本，带参数的消息使用 `{}`
+//! 占位符，由 [`Messages::format`] 按顺序填充。
+
+use std::fmt::Display;
+
+use dxcode::{DurationError, DxError, Locale, TransformError};
+
+/// 消息键
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Msg {
+    Help,
+    ErrorPrefix,
+    LangRequiresValue,
+    UnsupportedLang,
+
+    InfoBanner,
+    InfoName,
+    InfoVersion,
+    InfoAuthor,
+    InfoPrefix,
+    InfoMagic,
+    InfoPadding,
+    InfoChecksum,
+    InfoCompression,
+    InfoThreshold,
+    InfoCharsetLength,
+    InfoCharset,
+
+    Compressed,
+    DataCompressed,
+    DataUncompressed,
+    TtlNever,
+    TtlSeconds,
+    TtlExpired,
+    TtlNotExpired,
+    TtlValid,
+
+    CheckValid,
+    CheckInvalid,
+    ChecksumOk,
+    ChecksumFailed,
+    StoredCrc,
+    ComputedCrc,
+    MaybeTampered,
+
+    TtlInfoTitle,
+    TtlCreated,
+    TtlLifetime,
+    TtlLifetimeNever,
+    TtlExpires,
+    TtlRemaining,
+    TtlStatusValid,
+    TtlStatusPermanent,
+    TtlStatusExpired,
+    NoTtl,
+    NeverExpires,
+
+    InspectTitle,
+    InspectError,
+    InspectPrefix,
+    InspectFrame,
+    InspectFlags,
+    InspectFlagsNone,
+    InspectUnknownFlags,
+    InspectCrc,
+    InspectCrcMatch,
+    InspectCrcMismatch,
+    InspectContentType,
+    InspectSize,
+    InspectDeclaredSize,
+    InspectRatio,
+    InspectPayload,
+
+    Expired,
+    NotExpired,
+
+    CmdEncode,
+    CmdDecode,
+    CmdCheck,
+    CmdVerify,
+    CmdTtl,
+    CmdExpired,
+    CmdInfo,
+    CmdVectors,
+    CmdInspect,
+    CmdJson,
+    CmdToml,
+    CmdCsv,
+    CmdScan,
+    CmdConvert,
+    OptHelp,
+    OptNoCompress,
+    OptNoPadding,
+    OptTtl,
+    OptExpires,
+    OptUtc,
+    OptWrap,
+    OptCrlf,
+    OptIgnoreTtl,
+    OptInput,
+    OptOutput,
+    OptTextEncode,
+    OptTextDecode,
+    OptForce,
+    OptRaw,
+    OptPath,
+    OptColumn,
+    OptDelimiter,
+    OptNoHeader,
+    OptExpiredOnly,
+    OptDecode,
+    OptFrom,
+    OptTo,
+    OptKeepHeader,
+    OptExtend,
+    OptRemoveTtl,
+    OptLines,
+    OptNull,
+    OptSkipErrors,
+    OptFailFast,
+    OptJobs,
+    OptJson,
+    OptFormat,
+    UsageLine,
+    UsageText,
+    UsageEncoded,
+    UsageString,
+    UsageNone,
+    UsageDocument,
+    UsagePaths,
+    OptionsTitle,
+    ValueDuration,
+    ValueTime,
+    ValueColumns,
+    ValueFile,
+    ValueFormat,
+    ValueCount,
+    ValueSelector,
+    ValueColumn,
+    ValueChar,
+    ValueEncoding,
+
+    UnknownCommand,
+    UnknownOption,
+    MissingValue,
+    UnexpectedValue,
+    UnexpectedArgument,
+    UsageHint,
+    HelpHint,
+    WrapRequiresWidth,
+    InvalidTtl,
+    TtlTooLong,
+    InvalidExpires,
+    ExpiresInPast,
+    TtlConflict,
+    ReissueConflict,
+    MissingEncodeInput,
+    MissingDecodeInput,
+    MissingCheckInput,
+    MissingVerifyInput,
+    MissingTtlInput,
+    MissingInspectInput,
+    InputConflict,
+    ReadFailed,
+    WriteFailed,
+    InputNotUtf8,
+    BinaryToTerminal,
+    InvalidFormat,
+    JobsMustBePositive,
+    PolicyConflict,
+    WrapInBatch,
+    BatchLine,
+    BatchRecord,
+    BatchSummary,
+    MissingAction,
+    UnknownAction,
+    MissingPath,
+    MissingColumn,
+    InvalidDelimiter,
+    DocumentInvalid,
+    CsvUnterminated,
+    ColumnNotFound,
+    CsvField,
+    MissingScanInput,
+    ScanHit,
+    ScanDecoded,
+    ScanNoTtl,
+    ScanNeverExpires,
+    ScanExpires,
+    ScanExpired,
+    ScanSummary,
+    MissingConvertInput,
+    MissingEncoding,
+    UnknownEncoding,
+    KeepHeaderWithoutDx,
+}
+
+impl Msg {
+    /// 指定语言的消息模板
+    pub fn text(self, locale: Locale) -> &'static str {
+        let (en, zh_cn) = match self {
+            Msg::Help => (HELP_EN, HELP_ZH_CN),
+            Msg::ErrorPrefix => ("error: {}", "错误: {}"),
+            Msg::LangRequiresValue => (
+                "--lang requires a language (en or zh-CN)",
+                "--lang 需要指定语言（en 或 zh-CN）",
+            ),
+            Msg::UnsupportedLang => (
+                "unsupported language '{}' (expected en or zh-CN)",
+                "不支持的语言 '{}'（可选 en 或 zh-CN）",
+            ),
+
+            Msg::InfoBanner => (
+                "║             dxcode info                    ║",
+                "║             dxcode 信息                    ║",
+            ),
+            Msg::InfoName => ("Name:           {}", "名称:       {}"),
+            Msg::InfoVersion => ("Version:        {}", "版本:       {}"),
+            Msg::InfoAuthor => ("Author:         {}", "作者:       {}"),
+            Msg::InfoPrefix => ("Prefix:         {}", "前缀:       {}"),
+            Msg::InfoMagic => ("Magic:          {} ('{}')", "魔数:       {} ('{}')"),
+            Msg::InfoPadding => ("Padding:        {}", "填充:       {}"),
+            Msg::InfoChecksum => ("Checksum:       {}", "校验和:     {}"),
+            Msg::InfoCompression => ("Compression:    {}", "压缩算法:   {}"),
+            Msg::InfoThreshold => ("Threshold:      {} bytes", "压缩阈值:   {} 字节"),
+            Msg::InfoCharsetLength => ("Charset length: {}", "字符集长度: {}"),
+            Msg::InfoCharset => ("Charset:", "字符集:"),
+
+            Msg::Compressed => ("📦 compressed", "📦 已压缩"),
+            Msg::DataCompressed => ("📦 data is compressed", "📦 数据已压缩"),
+            Msg::DataUncompressed => ("📄 data is not compressed", "📄 数据未压缩"),
+            Msg::TtlNever => ("⏰ TTL: never expires", "⏰ TTL: 永不过期"),
+            Msg::TtlSeconds => ("⏰ TTL: {} seconds", "⏰ TTL: {} 秒"),
+            Msg::TtlExpired => ("⏰ TTL: expired", "⏰ TTL: 已过期"),
+            Msg::TtlNotExpired => ("⏰ TTL: {} seconds (not expired)", "⏰ TTL: {} 秒 (未过期)"),
+            Msg::TtlValid => ("⏰ TTL: {} seconds (valid)", "⏰ TTL: {} 秒 (有效)"),
+
+            Msg::CheckValid => ("✅ valid DX encoding", "✅ 是有效的 DX 编码"),
+            Msg::CheckInvalid => ("❌ not a valid DX encoding", "❌ 不是有效的 DX 编码"),
+            Msg::ChecksumOk => ("✅ checksum verified", "✅ 校验和验证通过"),
+            Msg::ChecksumFailed => ("❌ checksum verification failed", "❌ 校验和验证失败"),
+            Msg::StoredCrc => ("stored CRC16:   0x{}", "存储的 CRC16: 0x{}"),
+            Msg::ComputedCrc => ("computed CRC16: 0x{}", "计算的 CRC16: 0x{}"),
+            Msg::MaybeTampered => (
+                "the data may have been tampered with or corrupted",
+                "数据可能已被篡改或损坏",
+            ),
+
+            Msg::TtlInfoTitle => ("⏰ TTL info", "⏰ TTL 信息"),
+            Msg::TtlCreated => ("Created:    {} ({})", "创建时间:   {} ({})"),
+            Msg::TtlLifetime => ("Lifetime:   {} ({} seconds)", "有效期:     {}（{} 秒）"),
+            Msg::TtlLifetimeNever => ("Lifetime:   never expires", "有效期:     永不过期"),
+            Msg::TtlExpires => ("Expires:    {} ({})", "过期时间:   {} ({})"),
+            Msg::TtlRemaining => ("Remaining:  {}", "剩余时间:   {}"),
+            Msg::TtlStatusValid => ("Status:     ✅ valid", "状态:       ✅ 有效"),
+            Msg::TtlStatusPermanent => ("Status:     ✅ valid forever", "状态:       ✅ 永久有效"),
+            Msg::TtlStatusExpired => ("Status:     ❌ expired", "状态:       ❌ 已过期"),
+            Msg::NoTtl => ("ℹ️  this encoding has no TTL", "ℹ️  此编码不包含 TTL 信息"),
+            Msg::NeverExpires => ("the data never expires", "数据永不过期"),
+
+            Msg::InspectTitle => ("🔍 DX structure", "🔍 DX 结构"),
+            Msg::InspectError => ("⚠️  {}", "⚠️  {}"),
+            Msg::InspectPrefix => ("Prefix:     {}", "前缀:       {}"),
+            Msg::InspectFrame => ("Frame:      {} bytes", "帧长度:     {} 字节"),
+            Msg::InspectFlags => ("Flags:      0x{} ({})", "标志:       0x{} ({})"),
+            Msg::InspectFlagsNone => ("none", "无"),
+            Msg::InspectUnknownFlags => ("unknown 0x{}", "未定义 0x{}"),
+            Msg::InspectCrc => (
+                "CRC16:      stored {}, computed {} {}",
+                "CRC16:      存储 {}，计算 {} {}",
+            ),
+            Msg::InspectCrcMatch => ("✅", "✅"),
+            Msg::InspectCrcMismatch => ("❌ mismatch", "❌ 不一致"),
+            Msg::InspectContentType => ("Content:    0x{}", "内容类型:   0x{}"),
+            Msg::InspectSize => ("Size:       {} bytes", "原始大小:   {} 字节"),
+            Msg::InspectDeclaredSize => (
+                "Size:       {} bytes declared, {} bytes actual",
+                "原始大小:   声明 {} 字节，实际 {} 字节",
+            ),
+            Msg::InspectRatio => (
+                "Ratio:      {} ({} / {} bytes)",
+                "压缩率:     {}（{} / {} 字节）",
+            ),
+            Msg::InspectPayload => (
+                "Payload ({} bytes at offset {}):",
+                "数据部分（{} 字节，偏移 {}）:",
+            ),
+
+            Msg::Expired => ("❌ expired", "❌ 已过期"),
+            Msg::NotExpired => ("✅ not expired", "✅ 未过期"),
+
+            Msg::CmdEncode => (
+                "Encode text (reads stdin when no text is given).",
+                "编码文本（未提供文本时从标准输入读取）。",
+            ),
+            Msg::CmdDecode => ("Decode a DX string.", "解码 DX 字符串。"),
+            Msg::CmdCheck => (
+                "Check whether a string is a valid DX encoding.",
+                "检查字符串是否为有效的 DX 编码。",
+            ),
+            Msg::CmdVerify => (
+                "Verify the checksum of a DX string.",
+                "验证 DX 字符串的校验和。",
+            ),
+            Msg::CmdTtl => (
+                "Show the TTL info of a DX string, or reissue it with a new TTL.",
+                "查看 DX 字符串的 TTL 信息，或以新的 TTL 重新签发。",
+            ),
+            Msg::CmdExpired => (
+                "Check whether a DX string has expired (exit code 3 when expired).",
+                "检查 DX 字符串是否已过期（过期时退出码为 3）。",
+            ),
+            Msg::CmdInfo => ("Show encoding info.", "显示编码信息。"),
+            Msg::CmdVectors => (
+                "Print the conformance test vectors as JSON.",
+                "以 JSON 输出一致性测试向量。",
+            ),
+            Msg::CmdInspect => (
+                "Show the header fields and a hexdump of the payload of a DX string.",
+                "显示 DX 字符串的头部字段和数据部分的十六进制转储。",
+            ),
+            Msg::CmdJson => (
+                "Encode or decode the fields selected by --path in a JSON document.",
+                "编码或解码 JSON 文档中 --path 选中的字段。",
+            ),
+            Msg::CmdToml => (
+                "Encode or decode the fields selected by --path in a TOML document.",
+                "编码或解码 TOML 文档中 --path 选中的字段。",
+            ),
+            Msg::CmdCsv => (
+                "Encode or decode the columns selected by --column in a CSV file.",
+                "编码或解码 CSV 文件中 --column 选中的列。",
+            ),
+            Msg::CmdScan => (
+                "Find valid DX strings in files and directories (reads stdin when no path is given).",
+                "在文件和目录中查找有效的 DX 字符串（未提供路径时从标准输入读取）。",
+            ),
+            Msg::CmdConvert => (
+                "Convert between dx, base64, base64url, hex and base32.",
+                "在 dx、base64、base64url、hex 和 base32 之间转换。",
+            ),
+            Msg::OptHelp => ("show this help", "显示帮助信息"),
+            Msg::OptNoCompress => ("disable compression", "禁用压缩"),
+            Msg::OptNoPadding => ("omit = padding", "不输出 = 填充"),
+            Msg::OptTtl => (
+                "expire after a duration (e.g. 3600, 90m, 1h30m, 7d)",
+                "设置有效期（如 3600、90m、1h30m、7d）",
+            ),
+            Msg::OptExpires => (
+                "expire at an RFC 3339 time (e.g. 2026-12-31T00:00:00Z)",
+                "在指定的 RFC 3339 时间过期（如 2026-12-31T00:00:00Z）",
+            ),
+            Msg::OptUtc => ("show times in UTC instead of local time", "以 UTC 而非本地时间显示"),
+            Msg::OptWrap => (
+                "wrap lines at the given width (e.g. 64, 76)",
+                "按列换行（如 64、76）",
+            ),
+            Msg::OptCrlf => ("use CRLF when wrapping", "换行时使用 CRLF"),
+            Msg::OptIgnoreTtl => ("do not check TTL expiry", "不检查 TTL 是否过期"),
+            Msg::OptInput => (
+                "read input from a file ('-' for stdin)",
+                "从文件读取输入（'-' 为标准输入）",
+            ),
+            Msg::OptOutput => (
+                "write output to a file ('-' for stdout)",
+                "输出到文件（'-' 为标准输出）",
+            ),
+            Msg::OptTextEncode => (
+                "require the input to be UTF-8 text",
+                "要求输入为 UTF-8 文本",
+            ),
+            Msg::OptTextDecode => (
+                "require the decoded data to be UTF-8 text",
+                "要求解码结果为 UTF-8 文本",
+            ),
+            Msg::OptForce => (
+                "write binary data to a terminal anyway",
+                "允许向终端输出二进制数据",
+            ),
+            Msg::OptRaw => (
+                "show the structure even if the flags or checksum are invalid",
+                "flags 或校验和无效时仍然显示结构",
+            ),
+            Msg::OptPath => (
+                "field selector such as $.users[*].token (repeatable)",
+                "字段选择器，如 $.users[*].token（可重复）",
+            ),
+            Msg::OptColumn => (
+                "column name, or 1-based index (repeatable)",
+                "列名或从 1 开始的列号（可重复）",
+            ),
+            Msg::OptDelimiter => ("field delimiter (default: ,)", "字段分隔符（默认为 ,）"),
+            Msg::OptNoHeader => (
+                "the first row is data, not a header",
+                "第一行是数据而不是表头",
+            ),
+            Msg::OptExpiredOnly => ("only report expired strings", "只报告已过期的字符串"),
+            Msg::OptDecode => ("show the decoded data", "显示解码后的数据"),
+            Msg::OptFrom => ("encoding of the input", "输入的编码"),
+            Msg::OptTo => ("encoding of the output", "输出的编码"),
+            Msg::OptKeepHeader => (
+                "convert the whole frame, keeping the TTL header",
+                "转换完整的帧，保留 TTL 等头部",
+            ),
+            Msg::OptExtend => (
+                "reissue with the expiry extended by a duration (e.g. 1h)",
+                "重新签发，将过期时间延长指定时长（如 1h）",
+            ),
+            Msg::OptRemoveTtl => ("reissue without a TTL", "重新签发，移除 TTL"),
+            Msg::OptLines => (
+                "treat each input line as a separate record",
+                "把每一行作为独立的记录处理",
+            ),
+            Msg::OptNull => (
+                "like --lines, but records are separated by NUL",
+                "同 --lines，但记录以 NUL 分隔",
+            ),
+            Msg::OptSkipErrors => (
+                "report failed records, leave them out and exit 0",
+                "报告并跳过失败的记录，以 0 退出",
+            ),
+            Msg::OptFailFast => (
+                "stop at the first failed record",
+                "遇到第一条失败的记录时停止",
+            ),
+            Msg::OptJobs => (
+                "number of worker threads (default: CPU count)",
+                "工作线程数（默认为 CPU 数）",
+            ),
+            Msg::OptJson => ("same as --format json", "等同于 --format json"),
+            Msg::OptFormat => (
+                "output format: text, json or tsv",
+                "输出格式：text、json 或 tsv",
+            ),
+            Msg::UsageLine => ("Usage: dxc {} {}", "用法: dxc {} {}"),
+            Msg::UsageText => ("[options] [text]...", "[选项] [文本]..."),
+            Msg::UsageEncoded => ("[options] [encoded]", "[选项] [编码]"),
+            Msg::UsageString => ("[options] [string]", "[选项] [字符串]"),
+            Msg::UsageNone => ("[options]", "[选项]"),
+            Msg::UsageDocument => (
+                "<encode|decode> [options] [file]",
+                "<encode|decode> [选项] [文件]",
+            ),
+            Msg::UsagePaths => ("[options] [path]...", "[选项] [路径]..."),
+            Msg::OptionsTitle => ("Options:", "选项:"),
+            Msg::ValueDuration => ("<duration>", "<时长>"),
+            Msg::ValueTime => ("<time>", "<时间>"),
+            Msg::ValueColumns => ("<cols>", "<列>"),
+            Msg::ValueFile => ("<file>", "<文件>"),
+            Msg::ValueFormat => ("<format>", "<格式>"),
+            Msg::ValueCount => ("<n>", "<数量>"),
+            Msg::ValueSelector => ("<path>", "<路径>"),
+            Msg::ValueColumn => ("<column>", "<列>"),
+            Msg::ValueChar => ("<char>", "<字符>"),
+            Msg::ValueEncoding => ("<encoding>", "<编码>"),
+
+            Msg::UnknownCommand => ("unknown command '{}'", "未知命令 '{}'"),
+            Msg::UnknownOption => ("unknown option '{}' for '{}'", "'{}' 不是 '{}' 的有效选项"),
+            Msg::MissingValue => ("option '{}' requires a value", "选项 '{}' 需要指定值"),
+            Msg::UnexpectedValue => ("option '{}' does not take a value", "选项 '{}' 不接受值"),
+            Msg::UnexpectedArgument => ("unexpected argument '{}'", "多余的参数 '{}'"),
+            Msg::UsageHint => (
+                "run 'dxc {} --help' for usage",
+                "运行 'dxc {} --help' 查看用法",
+            ),
+            Msg::HelpHint => (
+                "run 'dxc help' for a list of commands",
+                "运行 'dxc help' 查看可用命令",
+            ),
+            Msg::WrapRequiresWidth => ("--wrap requires a column count", "--wrap 需要指定列数"),
+            Msg::InvalidTtl => ("invalid TTL '{}': {}", "无效的 TTL '{}'：{}"),
+            Msg::TtlTooLong => (
+                "TTL must not exceed {} seconds",
+                "TTL 不能超过 {} 秒",
+            ),
+            Msg::InvalidExpires => (
+                "invalid time '{}' (expected RFC 3339, e.g. 2026-12-31T00:00:00Z)",
+                "无效的时间 '{}'（应为 RFC 3339 格式，如 2026-12-31T00:00:00Z）",
+            ),
+            Msg::ExpiresInPast => ("--expires must be in the future", "--expires 必须晚于当前时间"),
+            Msg::TtlConflict => (
+                "--ttl and --expires cannot be used together",
+                "--ttl 和 --expires 不能同时使用",
+            ),
+            Msg::ReissueConflict => (
+                "--extend, --expires and --remove-ttl cannot be used together",
+                "--extend、--expires 和 --remove-ttl 不能同时使用",
+            ),
+            Msg::MissingEncodeInput => ("please provide text to encode", "请提供要编码的文本"),
+            Msg::MissingDecodeInput => (
+                "please provide a DX string to decode",
+                "请提供要解码的 DX 字符串",
+            ),
+            Msg::MissingCheckInput => ("please provide a string to check", "请提供要检查的字符串"),
+            Msg::MissingVerifyInput => (
+                "please provide a DX string to verify",
+                "请提供要验证的 DX 字符串",
+            ),
+            Msg::MissingTtlInput => (
+                "please provide a DX string to inspect",
+                "请提供要检查 TTL 的 DX 字符串",
+            ),
+            Msg::MissingInspectInput => (
+                "please provide a DX string to inspect",
+                "请提供要查看结构的 DX 字符串",
+            ),
+            Msg::InputConflict => (
+                "--input cannot be combined with positional input",
+                "--input 不能与位置参数同时使用",
+            ),
+            Msg::ReadFailed => ("cannot read '{}': {}", "无法读取 '{}': {}"),
+            Msg::WriteFailed => ("cannot write '{}': {}", "无法写入 '{}': {}"),
+            Msg::InputNotUtf8 => ("input is not valid UTF-8 text", "输入不是有效的 UTF-8 文本"),
+            Msg::BinaryToTerminal => (
+                "refusing to write binary data to a terminal (use -o <file> or --force)",
+                "拒绝向终端输出二进制数据（使用 -o <文件> 或 --force）",
+            ),
+            Msg::JobsMustBePositive => (
+                "--jobs requires a positive integer",
+                "--jobs 需要指定正整数",
+            ),
+            Msg::PolicyConflict => (
+                "--skip-errors and --fail-fast cannot be used together",
+                "--skip-errors 与 --fail-fast 不能同时使用",
+            ),
+            Msg::WrapInBatch => (
+                "--wrap and --crlf cannot be used with --lines or -0",
+                "--wrap 和 --crlf 不能与 --lines 或 -0 同时使用",
+            ),
+            Msg::BatchLine => ("line {}: {}", "第 {} 行: {}"),
+            Msg::BatchRecord => ("record {}: {}", "第 {} 条记录: {}"),
+            Msg::BatchSummary => (
+                "{} records processed, {} failed",
+                "共处理 {} 条记录，{} 条失败",
+            ),
+            Msg::MissingAction => (
+                "please specify 'encode' or 'decode'",
+                "请指定 'encode' 或 'decode'",
+            ),
+            Msg::UnknownAction => (
+                "unknown action '{}' (expected encode or decode)",
+                "未知操作 '{}'（可选 encode 或 decode）",
+            ),
+            Msg::MissingPath => ("please select fields with --path", "请用 --path 选择字段"),
+            Msg::MissingColumn => (
+                "please select columns with --column",
+                "请用 --column 选择列",
+            ),
+            Msg::InvalidDelimiter => (
+                "--delimiter requires a single ASCII character",
+                "--delimiter 需要指定单个 ASCII 字符",
+            ),
+            Msg::DocumentInvalid => ("cannot parse {} document: {}", "无法解析 {} 文档: {}"),
+            Msg::CsvUnterminated => (
+                "unterminated quoted field starting on line {}",
+                "第 {} 行开始的带引号字段没有结束",
+            ),
+            Msg::ColumnNotFound => ("column '{}' not found", "找不到列 '{}'"),
+            Msg::CsvField => ("line {}, column '{}': {}", "第 {} 行，列 '{}': {}"),
+            Msg::MissingScanInput => (
+                "please provide files or directories to scan",
+                "请提供要扫描的文件或目录",
+            ),
+            Msg::ScanHit => ("{}: {} ({} bytes, {})", "{}: {}（{} 字节，{}）"),
+            Msg::ScanDecoded => ("    = {}", "    = {}"),
+            Msg::ScanNoTtl => ("no TTL", "无 TTL"),
+            Msg::ScanNeverExpires => ("never expires", "永不过期"),
+            Msg::ScanExpires => ("expires {}", "{} 过期"),
+            Msg::ScanExpired => ("expired {}", "已于 {} 过期"),
+            Msg::ScanSummary => (
+                "{} of {} files contain {} DX strings",
+                "{} / {} 个文件包含 {} 个 DX 字符串",
+            ),
+            Msg::MissingConvertInput => (
+                "please provide a string to convert",
+                "请提供要转换的字符串",
+            ),
+            Msg::MissingEncoding => (
+                "please specify both --from and --to",
+                "请同时指定 --from 和 --to",
+            ),
+            Msg::UnknownEncoding => (
+                "unsupported encoding '{}' (expected dx, base64, base64url, hex or base32)",
+                "不支持的编码 '{}'（可选 dx、base64、base64url、hex、base32）",
+            ),
+            Msg::KeepHeaderWithoutDx => (
+                "--keep-header requires dx on one side of the conversion",
+                "--keep-header 要求转换的一侧是 dx",
+            ),
+            Msg::InvalidFormat => (
+                "unsupported format '{}' (expected text, json or tsv)",
+                "不支持的输出格式 '{}'（可选 text、json、tsv）",
+            ),
+        };
+
+        match locale {
+            Locale::En => en,
+            Locale::ZhCn => zh_cn,
+        }
+    }
+}
+
+/// 当前语言的消息目录
+#[derive(Debug, Clone, Copy)]
+pub struct Messages {
+    locale: Locale,
+}
+
+impl Messages {
+    pub fn new(locale: Locale) -> Messages {
+        Messages { locale }
+    }
+
+    /// 不带参数的消息
+    pub fn get(&self, msg: Msg) -> &'static str {
+        msg.text(self.locale)
+    }
+
+    /// 按顺序将参数填入消息模板中的 `{}` 占位符
+    pub fn format(&self, msg: Msg, args: &[&dyn Display]) -> String {
+        let mut parts = self.get(msg).split("{}");
+        let mut result = parts.next().unwrap_or_default().to_string();
+        let mut args = args.iter();
+        for part in parts {
+            if let Some(arg) = args.next() {
+                result.push_str(&arg.to_string());
+            }
+            result.push_str(part);
+        }
+        result
+    }
+
+    /// 本地化的错误信息（带 "错误:" 前缀）
+    pub fn error(&self, message: &dyn Display) -> String {
+        self.format(Msg::ErrorPrefix, &[message])
+    }
+
+    /// 本地化的库错误信息（不含前缀）
+    pub fn dx_message(&self, error: &DxError) -> String {
+        error.localized(self.locale).to_string()
+    }
+
+    /// 本地化的时长解析错误信息（不含前缀）
+    pub fn duration_message(&self, error: &DurationError) -> String {
+        error.localized(self.locale).to_string()
+    }
+
+    /// 本地化的字段编解码错误信息（不含前缀）
+    pub fn transform_message(&self, error: &TransformError) -> String {
+        error.localized(self.locale).to_string()
+    }
+}
+
+const HELP_EN: &str = "\
+dxcode - a custom encoding with the `dx` prefix (v2.3 with checksum, compression and TTL)
+
+Usage:
+  dxc encode <text>                encode text
+  dxc encode --no-compress <text>  encode text (compression disabled)
+  dxc encode --ttl <dur> <text>    encode text with an expiry (e.g. 90m, 1h30m, 7d)
+  dxc encode --expires <time> ...  expire at an RFC 3339 time
+  dxc encode --no-padding <text>   encode text (no = padding)
+  dxc encode --wrap <cols> <text>  encode text and wrap lines (e.g. 64, 76)
+  dxc encode --wrap <cols> --crlf  use CRLF when wrapping
+  dxc decode <encoded>             decode a DX string
+  dxc decode --ignore-ttl <enc>    decode (ignore TTL expiry)
+  dxc encode -i <file> -o <file>   encode a file (raw bytes)
+  dxc decode -i <file> -o <file>   decode to a file (raw bytes)
+  dxc verify --json <encoded>      machine-readable output (also --format tsv)
+  dxc decode --lines -i tokens.txt decode one record per line (-0 for NUL)
+  dxc json decode -p <path> <file> decode fields of a JSON document (also toml)
+  dxc csv encode -c <col> <file>   encode a column of a CSV file
+  dxc scan [--decode] <path>...    find DX strings in files and directories
+  dxc convert -f hex -t dx <str>   convert between dx, base64, hex and base32
+  dxc check <string>               check whether a string is valid DX
+  dxc verify <encoded>             verify checksum integrity
+  dxc ttl [--utc] <encoded>        show TTL info (local time by default)
+  dxc ttl --extend <dur> <encoded> reissue with a later expiry (also --expires, --remove-ttl)
+  dxc inspect [--raw] <encoded>    show header fields and a payload hexdump
+  dxc info                         show encoding info
+  dxc vectors                      print conformance test vectors (JSON)
+  dxc <command> --help             show help for a command
+  dxc help                         show this help
+  dxc --version                    show version
+
+Options:
+  --lang <en|zh-CN>                message language (default: from LC_ALL / LANG)
+  --                               treat the remaining arguments as input
+
+Exit codes:
+  0 success, 1 invalid input, 2 checksum mismatch, 3 expired, 64 usage error
+
+Pipes:
+  echo 'Hello' | dxc encode
+  echo 'dxXXXX' | dxc decode
+  dxc decode 'dxXXXX' > image.png  # decoded bytes are written verbatim
+
+Examples:
+  dxc encode 'Hello, Dogxi!'
+  dxc encode --no-compress 'Hello World'
+  dxc encode --ttl 3600 'temporary token'  # valid for 1 hour
+  dxc encode --ttl 1d 'Data'               # valid for 1 day
+  dxc decode 'dxXXXX...'
+  dxc verify 'dxXXXX...'                   # check data integrity
+  dxc ttl 'dxXXXX...'                      # show TTL status
+
+More info: https://dxc.dogxi.me
+GitHub: https://github.com/dogxii/dxcode";
+
+const HELP_ZH_CN: &str = "\
+dxcode - 带有 `dx` 前缀的自定义编码算法 (v2.3 带校验和、压缩和 TTL)
+
+用法:
+  dxc encode <文本>                编码文本
+  dxc encode --no-compress <文本>  编码文本（禁用压缩）
+  dxc encode --ttl <时长> <文本>   编码文本并设置有效期（如 90m、1h30m、7d）
+  dxc encode --expires <时间> ...  在指定的 RFC 3339 时间过期
+  dxc encode --no-padding <文本>   编码文本（不输出 = 填充）
+  dxc encode --wrap <列> <文本>    编码文本并按列换行（如 64、76）
+  dxc encode --wrap <列> --crlf    换行时使用 CRLF
+  dxc decode <编码>                解码 DX 字符串
+  dxc decode --ignore-ttl <编码>   解码（忽略 TTL 过期）
+  dxc encode -i <文件> -o <文件>   编码文件（原始字节）
+  dxc decode -i <文件> -o <文件>   解码到文件（原始字节）
+  dxc verify --json <编码>         输出 JSON（也支持 --format tsv）
+  dxc decode --lines -i tokens.txt 按行批量解码（-0 以 NUL 分隔）
+  dxc json decode -p <路径> <文件> 解码 JSON 文档中的字段（也支持 toml）
+  dxc csv encode -c <列> <文件>    编码 CSV 文件中的一列
+  dxc scan [--decode] <路径>...    在文件和目录中查找 DX 字符串
+  dxc convert -f hex -t dx <字符串> 在 dx、base64、hex、base32 之间转换
+  dxc check <字符串>               检查是否为有效的 DX 编码
+  dxc verify <编码>                验证校验和完整性
+  dxc ttl [--utc] <编码>           查看 TTL 信息（默认显示本地时间）
+  dxc ttl --extend <时长> <编码>   延长过期时间并重新签发（也支持 --expires、--remove-ttl）
+  dxc inspect [--raw] <编码>       查看头部字段和数据部分的十六进制转储
+  dxc info                         显示编码信息
+  dxc vectors                      输出一致性测试向量（JSON）
+  dxc <命令> --help                显示命令的帮助信息
+  dxc help                         显示帮助信息
+  dxc --version                    显示版本信息
+
+选项:
+  --lang <en|zh-CN>                消息语言（默认根据 LC_ALL / LANG 选择）
+  --                               之后的参数都作为输入
+
+退出码:
+  0 成功，1 输入无效，2 校验和不匹配，3 已过期，64 用法错误
+
+管道用法:
+  echo 'Hello' | dxc encode
+  echo 'dxXXXX' | dxc decode
+  dxc decode 'dxXXXX' > image.png  # 解码结果按原始字节输出
+
+示例:
+  dxc encode '你好，Dogxi！'
+  dxc encode --no-compress 'Hello World'
+  dxc encode --ttl 3600 '临时令牌'    # 1小时有效期
+  dxc encode --ttl 1d 'Data'          # 1天有效期
+  dxc decode 'dxXXXX...'
+  dxc verify 'dxXXXX...'              # 验证数据完整性
+  dxc ttl 'dxXXXX...'                 # 查看 TTL 状态
+
+更多信息: https://dxc.dogxi.me
+GitHub: https://github.com/dogxii/dxcode";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_fills_placeholders_in_order() {
+        let m = Messages::new(Locale::En);
+        assert_eq!(
+            m.format(Msg::TtlCreated, &[&"now", &42]),
+            "Created:    now (42)"
+        );
+        let m = Messages::new(Locale::ZhCn);
+        assert_eq!(m.format(Msg::TtlSeconds, &[&60]), "⏰ TTL: 60 秒");
+    }
+
+    #[test]
+    fn test_dx_error_is_localized() {
+        let err = dxcode::decode("hello").unwrap_err();
+        assert_eq!(
+            Messages::new(Locale::En).dx_message(&err),
+            "invalid DX encoding: missing dx prefix"
+        );
+        assert_eq!(
+            Messages::new(Locale::ZhCn).dx_message(&err),
+            "无效的 DX 编码：缺少 dx 前缀"
+        );
+    }
+}
@@ -0,0 +1,12 @@
+//! 命令行工具的内部模块（不属于库的公开 API）
+
+pub mod args;
+pub mod batch;
+pub mod document;
+pub mod exit;
+pub mod inspect;
+pub mod io;
+pub mod messages;
+pub mod output;
+pub mod scan;
+pub mod time;
@@ -0,0 +1,168 @@
+//! 结构化输出（`--json` / `--format json|text|tsv`）
+//!
+//! JSON 输出为单行对象，顶层带有 `schema` 字段（[`SCHEMA_VERSION`]），
+//! 字段说明见 `CLI_JSON.md`。TSV 输出每行一个 `键<TAB>值`，嵌套字段用 `.` 连接，
+//! `null` 输出为空值。
+
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+/// JSON 输出的 schema 版本，字段发生不兼容的变化时递增
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// 输出格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// 面向人的本地化文本（默认）
+    Text,
+    /// 单行 JSON 对象
+    Json,
+    /// `键<TAB>值` 行
+    Tsv,
+}
+
+impl Format {
+    /// 解析 `--format` 的值
+    pub fn parse(s: &str) -> Option<Format> {
+        match s {
+            "text" => Some(Format::Text),
+            "json" => Some(Format::Json),
+            "tsv" => Some(Format::Tsv),
+            _ => None,
+        }
+    }
+
+    /// 在参数解析失败前粗略判断输出格式，使用法错误也能按请求的格式输出
+    pub fn sniff(args: &[String]) -> Format {
+        let mut format = Format::Text;
+        let mut iter = args.iter().take_while(|a| *a != "--");
+        while let Some(arg) = iter.next() {
+            let value = match arg.as_str() {
+                "--json" => Some("json"),
+                "--format" => iter.next().map(String::as_str),
+                _ => arg.strip_prefix("--format="),
+            };
+            if let Some(f) = value.and_then(Format::parse) {
+                format = f;
+            }
+        }
+        format
+    }
+}
+
+static FORMAT: AtomicU8 = AtomicU8::new(0);
+
+/// 设置当前输出格式
+pub fn set(format: Format) {
+    FORMAT.store(format as u8, Ordering::Relaxed);
+}
+
+/// 当前输出格式
+pub fn get() -> Format {
+    match FORMAT.load(Ordering::Relaxed) {
+        1 => Format::Json,
+        2 => Format::Tsv,
+        _ => Format::Text,
+    }
+}
+
+/// 是否输出结构化结果
+pub fn structured() -> bool {
+    get() != Format::Text
+}
+
+/// 按当前格式渲染结果（含结尾换行），对象会加上 `schema` 字段
+pub fn render<T: Serialize>(value: &T) -> String {
+    let mut value = serde_json::to_value(value).expect("CLI output is always serializable");
+    if let Value::Object(map) = &mut value {
+        map.insert("schema".into(), SCHEMA_VERSION.into());
+    }
+    match get() {
+        Format::Tsv => {
+            let mut out = String::new();
+            flatten("", &value, &mut out);
+            out
+        }
+        _ => format!("{}\n", value),
+    }
+}
+
+/// 错误对象
+pub fn error(kind: &str, message: &str, exit_code: i32) -> Value {
+    let mut error = Map::new();
+    error.insert("kind".into(), kind.into());
+    error.insert("message".into(), message.into());
+    error.insert("exit_code".into(), exit_code.into());
+    let mut map = Map::new();
+    map.insert("error".into(), Value::Object(error));
+    Value::Object(map)
+}
+
+/// 小写十六进制字符串
+pub fn hex(data: &[u8]) -> String {
+    data.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn flatten(prefix: &str, value: &Value, out: &mut String) {
+    let key = |k: &str| {
+        if prefix.is_empty() {
+            k.to_string()
+        } else {
+            format!("{}.{}", prefix, k)
+        }
+    };
+    match value {
+        Value::Object(map) => {
+            for (k, v) in map {
+                flatten(&key(k), v, out);
+            }
+        }
+        Value::Array(items) => {
+            for (i, v) in items.iter().enumerate() {
+                flatten(&key(&i.to_string()), v, out);
+            }
+        }
+        Value::Null => out.push_str(&format!("{}\t\n", prefix)),
+        Value::String(s) => {
+            let escaped = s
+                .replace('\\', "\\\\")
+                .replace('\t', "\\t")
+                .replace('\n', "\\n")
+                .replace('\r', "\\r");
+            out.push_str(&format!("{}\t{}\n", prefix, escaped));
+        }
+        other => out.push_str(&format!("{}\t{}\n", prefix, other)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_sniff() {
+        assert_eq!(Format::sniff(&args(&["x", "--json"])), Format::Json);
+        assert_eq!(Format::sniff(&args(&["--format", "tsv"])), Format::Tsv);
+        assert_eq!(Format::sniff(&args(&["--format=json"])), Format::Json);
+        assert_eq!(Format::sniff(&args(&["--", "--json"])), Format::Text);
+        assert_eq!(Format::sniff(&args(&["--format=xml"])), Format::Text);
+    }
+
+    #[test]
+    fn test_flatten_tsv() {
+        let mut out = String::new();
+        flatten(
+            "",
+            &json!({ "a": 1, "b": { "c": null, "d": "x\ty" }, "e": [true] }),
+            &mut out,
+        );
+        assert_eq!(out, "a\t1\nb.c\t\nb.d\tx\\ty\ne.0\ttrue\n");
+    }
+}
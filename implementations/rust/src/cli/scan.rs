@@ -0,0 +1,200 @@
+//! `dxc scan`：在文件和目录中查找 DX 字符串
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use dxcode::{find_tokens, Header, TtlInfo, STANDARD};
+use serde_json::{json, Value};
+
+use super::messages::{Messages, Msg};
+use super::output;
+use super::time::{iso8601, rfc3339};
+
+/// 展开路径：目录按名称排序递归遍历，跳过 `.git`，目录中的符号链接只跟随到文件
+pub fn collect_files(
+    path: &Path,
+    files: &mut Vec<PathBuf>,
+    errors: &mut Vec<(PathBuf, io::Error)>,
+) {
+    // 命令行中直接给出的路径总是跟随符号链接
+    match fs::metadata(path) {
+        Ok(metadata) if metadata.is_dir() => {}
+        Ok(_) => return files.push(path.to_path_buf()),
+        Err(e) => return errors.push((path.to_path_buf(), e)),
+    }
+
+    let mut entries = match fs::read_dir(path).and_then(|dir| dir.collect::<io::Result<Vec<_>>>()) {
+        Ok(entries) => entries,
+        Err(e) => return errors.push((path.to_path_buf(), e)),
+    };
+    entries.sort_by_key(|entry| entry.file_name());
+    for entry in entries {
+        let path = entry.path();
+        if entry.file_name() == ".git" {
+            continue;
+        }
+        match entry.file_type() {
+            Ok(t) if t.is_dir() => collect_files(&path, files, errors),
+            Ok(t) if t.is_file() => files.push(path),
+            Ok(_) if path.is_file() => files.push(path),
+            Ok(_) => {}
+            Err(e) => errors.push((path, e)),
+        }
+    }
+}
+
+/// 找到的 DX 字符串及其位置和头部信息
+#[derive(Debug)]
+pub struct Hit<'t> {
+    /// 行号（从 1 开始）
+    pub line: usize,
+    /// 列号（从 1 开始，按字符计）
+    pub column: usize,
+    /// 字节偏移
+    pub offset: usize,
+    pub token: &'t str,
+    pub header: Header,
+    pub ttl: Option<TtlInfo>,
+    /// 解码后的数据（不检查 TTL）
+    pub data: Vec<u8>,
+}
+
+impl Hit<'_> {
+    /// 是否已过期
+    pub fn expired(&self) -> bool {
+        self.ttl.as_ref().is_some_and(|ttl| ttl.is_expired)
+    }
+}
+
+/// 查找文本中所有 DX 字符串
+pub fn hits(text: &str) -> Vec<Hit<'_>> {
+    let mut line = 1;
+    let mut line_start = 0;
+    let mut scanned = 0;
+    find_tokens(text)
+        .filter_map(|m| {
+            // 匹配按顺序返回，行号只需要向后累加
+            for (i, b) in text[scanned..m.start()].bytes().enumerate() {
+                if b == b'\n' {
+                    line += 1;
+                    line_start = scanned + i + 1;
+                }
+            }
+            scanned = m.start();
+            let token = m.as_str();
+            Some(Hit {
+                line,
+                column: text[line_start..m.start()].chars().count() + 1,
+                offset: m.start(),
+                token,
+                header: STANDARD.get_header(token).ok()?,
+                ttl: STANDARD.get_ttl_info(token).ok()?,
+                data: STANDARD.decode_with_options(token, false).ok()?,
+            })
+        })
+        .collect()
+}
+
+/// TTL 状态的文本说明
+fn ttl_status(m: &Messages, hit: &Hit) -> String {
+    match &hit.ttl {
+        None => m.get(Msg::ScanNoTtl).to_string(),
+        Some(ttl) => match ttl.expires_at {
+            None => m.get(Msg::ScanNeverExpires).to_string(),
+            Some(at) if ttl.is_expired => m.format(Msg::ScanExpired, &[&rfc3339(at)]),
+            Some(at) => m.format(Msg::ScanExpires, &[&rfc3339(at)]),
+        },
+    }
+}
+
+/// 解码数据的单行表示：UTF-8 文本转义控制字符，二进制数据输出十六进制
+fn preview(data: &[u8]) -> String {
+    match std::str::from_utf8(data) {
+        Ok(text) => text.escape_debug().to_string(),
+        Err(_) => format!("0x{}", output::hex(data)),
+    }
+}
+
+/// 本地化的文本输出
+pub fn text(m: &Messages, file: &str, hit: &Hit, decode: bool) -> String {
+    let location = format!("{}:{}:{}", file, hit.line, hit.column);
+    let mut out = m.format(
+        Msg::ScanHit,
+        &[&location, &hit.token, &hit.data.len(), &ttl_status(m, hit)],
+    );
+    out.push('\n');
+    if decode {
+        out.push_str(&m.format(Msg::ScanDecoded, &[&preview(&hit.data)]));
+        out.push('\n');
+    }
+    out
+}
+
+/// 结构化输出
+pub fn value(file: &str, hit: &Hit, decode: bool) -> Value {
+    let mut value = json!({
+        "file": file,
+        "line": hit.line,
+        "column": hit.column,
+        "offset": hit.offset,
+        "token": hit.token,
+        "size": hit.data.len(),
+        "compressed": hit.header.compressed,
+        "ttl": hit.ttl,
+        "expired": hit.expired(),
+        "expires_at_iso": hit.ttl.as_ref().and_then(|ttl| ttl.expires_at).map(iso8601),
+    });
+    if decode {
+        let (encoding, data) = match std::str::from_utf8(&hit.data) {
+            Ok(text) => ("utf8", text.to_string()),
+            Err(_) => ("hex", output::hex(&hit.data)),
+        };
+        value["encoding"] = encoding.into();
+        value["data"] = data.into();
+    }
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hits_positions() {
+        let token = dxcode::encode_with_ttl(b"secret", 60);
+        let text = format!("first line\n日志 {} dxdiag\n\tx={}\n", token, token);
+        let hits = hits(&text);
+        assert_eq!(hits.len(), 2);
+        assert_eq!((hits[0].line, hits[0].column), (2, 4));
+        assert_eq!((hits[1].line, hits[1].column), (3, 4));
+        assert_eq!(hits[1].data, b"secret");
+        assert!(!hits[1].expired());
+    }
+
+    #[test]
+    fn test_collect_files_sorted() {
+        let dir = std::env::temp_dir().join(format!("dxc-scan-{}", std::process::id()));
+        fs::create_dir_all(dir.join("b/.git")).unwrap();
+        for name in ["b/2.log", "a.log", "b/.git/HEAD", "b/1.log"] {
+            fs::write(dir.join(name), "x").unwrap();
+        }
+        let (mut files, mut errors) = (Vec::new(), Vec::new());
+        collect_files(&dir, &mut files, &mut errors);
+        collect_files(&dir.join("missing"), &mut files, &mut errors);
+        let names: Vec<_> = files
+            .iter()
+            .map(|f| f.strip_prefix(&dir).unwrap())
+            .collect();
+        assert_eq!(
+            names,
+            [
+                Path::new("a.log"),
+                Path::new("b/1.log"),
+                Path::new("b/2.log")
+            ]
+        );
+        assert_eq!(errors.len(), 1);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
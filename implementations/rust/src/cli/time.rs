@@ -0,0 +1,78 @@
+//! 时间格式化与解析
+//!
+//! 文本输出默认使用本地时区的 RFC 3339 时间，`--utc` 时使用 UTC；
+//! 结构化输出的 `*_iso` 字段始终为 UTC。
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use chrono::{DateTime, Local, SecondsFormat, Utc};
+
+static UTC: AtomicBool = AtomicBool::new(false);
+
+/// 设置文本输出是否使用 UTC
+pub fn set_utc(utc: bool) {
+    UTC.store(utc, Ordering::Relaxed);
+}
+
+/// 按当前设置格式化为 RFC 3339 时间（本地时区或 UTC）
+pub fn rfc3339(ts: u64) -> String {
+    match utc(ts) {
+        Some(t) if !UTC.load(Ordering::Relaxed) => t
+            .with_timezone(&Local)
+            .to_rfc3339_opts(SecondsFormat::Secs, true),
+        _ => iso8601(ts),
+    }
+}
+
+/// 解析 RFC 3339 时间（如 `2026-12-31T00:00:00Z`、`2026-12-31T08:00:00+08:00`），
+/// 返回 Unix 时间戳，早于 1970 年时返回 `None`
+pub fn parse_rfc3339(s: &str) -> Option<u64> {
+    let t = DateTime::parse_from_rfc3339(s.trim()).ok()?;
+    u64::try_from(t.timestamp()).ok()
+}
+
+/// 将 Unix 时间戳格式化为 ISO-8601 UTC 时间（如 `2023-11-14T22:13:20Z`）
+///
+/// 超出 chrono 可表示的范围时输出时间戳本身
+pub fn iso8601(ts: u64) -> String {
+    utc(ts).map_or_else(
+        || ts.to_string(),
+        |t| t.to_rfc3339_opts(SecondsFormat::Secs, true),
+    )
+}
+
+fn utc(ts: u64) -> Option<DateTime<Utc>> {
+    DateTime::from_timestamp(i64::try_from(ts).ok()?, 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_iso8601() {
+        assert_eq!(iso8601(0), "1970-01-01T00:00:00Z");
+        assert_eq!(iso8601(951_782_400), "2000-02-29T00:00:00Z");
+        assert_eq!(iso8601(1_700_000_000), "2023-11-14T22:13:20Z");
+        assert_eq!(iso8601(u32::MAX as u64), "2106-02-07T06:28:15Z");
+    }
+
+    #[test]
+    fn test_parse_rfc3339() {
+        assert_eq!(parse_rfc3339("2023-11-14T22:13:20Z"), Some(1_700_000_000));
+        assert_eq!(
+            parse_rfc3339("2023-11-15T06:13:20+08:00"),
+            Some(1_700_000_000)
+        );
+        assert_eq!(parse_rfc3339("2023-11-14T22:13:20.5Z"), Some(1_700_000_000));
+        assert_eq!(parse_rfc3339("1969-12-31T23:59:59Z"), None);
+        assert_eq!(parse_rfc3339("2023-11-14"), None);
+
+        set_utc(true);
+        assert_eq!(rfc3339(1_700_000_000), "2023-11-14T22:13:20Z");
+        let ts = parse_rfc3339(&rfc3339(1_700_000_000));
+        set_utc(false);
+        assert_eq!(ts, Some(1_700_000_000));
+        assert_eq!(parse_rfc3339(&rfc3339(1_700_000_000)), Some(1_700_000_000));
+    }
+}
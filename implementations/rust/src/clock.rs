@@ -0,0 +1,66 @@
+//! TTL 使用的时钟
+
+/// 生成和检查 TTL 时使用的时钟（Unix 时间戳，秒）
+///
+/// 默认使用系统时钟。测试中可以用 [`Clock::Fixed`] 固定时间；没有系统时钟的
+/// 环境（如浏览器）可以用 [`Clock::Custom`] 提供时间来源。
+///
+/// # 示例
+///
+/// ```
+/// use dxcode::{Clock, STANDARD};
+///
+/// let engine = STANDARD.with_clock(Clock::Fixed(1_700_000_000));
+/// let encoded = engine.encode_with_ttl(b"Hello", 60);
+/// assert_eq!(engine.get_ttl_info(&encoded).unwrap().unwrap().created_at, 1_700_000_000);
+///
+/// let later = engine.with_clock(Clock::Fixed(1_700_000_061));
+/// assert!(later.is_expired(&encoded).unwrap());
+/// ```
+#[derive(Debug, Clone, Copy, Eq, Default)]
+pub enum Clock {
+    /// 系统时钟（默认）
+    #[default]
+    System,
+    /// 固定时间
+    Fixed(u64),
+    /// 自定义时间来源
+    Custom(fn() -> u64),
+}
+
+impl PartialEq for Clock {
+    fn eq(&self, other: &Clock) -> bool {
+        match (self, other) {
+            (Clock::System, Clock::System) => true,
+            (Clock::Fixed(a), Clock::Fixed(b)) => a == b,
+            (Clock::Custom(a), Clock::Custom(b)) => *a as usize == *b as usize,
+            _ => false,
+        }
+    }
+}
+
+impl Clock {
+    /// 当前 Unix 时间戳（秒）
+    pub fn now(&self) -> u64 {
+        match self {
+            Clock::System => system_now(),
+            Clock::Fixed(now) => *now,
+            Clock::Custom(f) => f(),
+        }
+    }
+}
+
+/// 系统时钟（浏览器中 `SystemTime` 不可用，改用 `Date.now()`）
+#[cfg(not(all(feature = "wasm", target_arch = "wasm32", target_os = "unknown")))]
+pub(crate) fn system_now() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// 系统时钟（浏览器中 `SystemTime` 不可用，改用 `Date.now()`）
+#[cfg(all(feature = "wasm", target_arch = "wasm32", target_os = "unknown"))]
+pub(crate) fn system_now() -> u64 {
+    (js_sys::Date::now() / 1000.0) as u64
+}
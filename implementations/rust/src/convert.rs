@@ -0,0 +1,185 @@
+//! DX 与 base64、base64url、十六进制、base32 之间的转换
+//!
+//! 其他编码只表示原始字节：转换为 DX 时和 [`Engine::encode`](crate::Engine::encode)
+//! 一样计算校验和并决定是否压缩；从 DX 转换时只保留数据，TTL 等头部信息被丢弃，
+//! 需要保留时用 [`Engine::to_frame`](crate::Engine::to_frame) 和
+//! [`Engine::from_frame`](crate::Engine::from_frame) 转换完整的帧。
+//!
+//! - base64 和 base32（RFC 4648）输出带填充，base64url 输出不带填充
+//! - 解码时填充可以省略，忽略 ASCII 空白，十六进制和 base32 不区分大小写
+
+use std::fmt;
+
+use crate::{DxError, Result, STANDARD};
+
+const BASE64: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const BASE64_URL: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+const BASE32: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+const HEX: &[u8] = b"0123456789abcdef";
+
+/// 可以与 DX 互相转换的文本编码
+///
+/// # 示例
+///
+/// ```
+/// use dxcode::Encoding;
+///
+/// let encoding = Encoding::from_name("base64url").unwrap();
+/// assert_eq!(encoding.encode(b"\xfb\xff"), "-_8");
+/// assert_eq!(Encoding::Hex.decode("FB ff").unwrap(), b"\xfb\xff");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Encoding {
+    /// DX 编码（使用 [`STANDARD`]）
+    Dx,
+    /// 标准 base64
+    Base64,
+    /// URL 安全的 base64
+    Base64Url,
+    /// 十六进制
+    Hex,
+    /// RFC 4648 base32
+    Base32,
+}
+
+impl Encoding {
+    /// 所有编码
+    pub const ALL: [Encoding; 5] = [
+        Encoding::Dx,
+        Encoding::Base64,
+        Encoding::Base64Url,
+        Encoding::Hex,
+        Encoding::Base32,
+    ];
+
+    /// 名称（`dx`、`base64`、`base64url`、`hex`、`base32`）
+    pub fn name(self) -> &'static str {
+        match self {
+            Encoding::Dx => "dx",
+            Encoding::Base64 => "base64",
+            Encoding::Base64Url => "base64url",
+            Encoding::Hex => "hex",
+            Encoding::Base32 => "base32",
+        }
+    }
+
+    /// 按名称查找，不区分大小写
+    pub fn from_name(name: &str) -> Option<Encoding> {
+        Encoding::ALL
+            .into_iter()
+            .find(|e| e.name().eq_ignore_ascii_case(name))
+    }
+
+    /// 编码字节数据
+    pub fn encode(self, data: &[u8]) -> String {
+        match self {
+            Encoding::Dx => STANDARD.encode(data),
+            Encoding::Base64 => encode_bits(data, 6, BASE64, self.block()),
+            Encoding::Base64Url => encode_bits(data, 6, BASE64_URL, 1),
+            Encoding::Hex => encode_bits(data, 4, HEX, 1),
+            Encoding::Base32 => encode_bits(data, 5, BASE32, self.block()),
+        }
+    }
+
+    /// 解码为字节数据
+    ///
+    /// 非法字符、错位的填充或不完整的输入返回 [`DxError::InvalidSource`]
+    pub fn decode(self, input: &str) -> Result<Vec<u8>> {
+        match self {
+            Encoding::Dx => STANDARD.with_ignore_whitespace(true).decode(input),
+            Encoding::Base64 => decode_bits(self, input, 6, |c| position(BASE64, c)),
+            Encoding::Base64Url => decode_bits(self, input, 6, |c| position(BASE64_URL, c)),
+            Encoding::Hex => decode_bits(self, input, 4, |c| position(HEX, c.to_ascii_lowercase())),
+            Encoding::Base32 => {
+                decode_bits(self, input, 5, |c| position(BASE32, c.to_ascii_uppercase()))
+            }
+        }
+    }
+
+    /// 每组字符数，填充到整组
+    fn block(self) -> usize {
+        match self {
+            Encoding::Base64 | Encoding::Base64Url => 4,
+            Encoding::Base32 => 8,
+            Encoding::Dx | Encoding::Hex => 1,
+        }
+    }
+}
+
+impl fmt::Display for Encoding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+fn position(alphabet: &[u8], c: u8) -> Option<u32> {
+    alphabet.iter().position(|&a| a == c).map(|i| i as u32)
+}
+
+/// 每个字符表示 `bits` 位的通用编码，用 `=` 填充到 `block` 的整数倍
+fn encode_bits(data: &[u8], bits: u32, alphabet: &[u8], block: usize) -> String {
+    let mask = (1 << bits) - 1;
+    let mut out = String::with_capacity((data.len() * 8).div_ceil(bits as usize) + 6);
+    let (mut acc, mut nbits) = (0u32, 0u32);
+    for &byte in data {
+        acc = (acc << 8) | byte as u32;
+        nbits += 8;
+        while nbits >= bits {
+            nbits -= bits;
+            out.push(alphabet[((acc >> nbits) & mask) as usize] as char);
+        }
+        acc &= (1 << nbits) - 1;
+    }
+    if nbits > 0 {
+        out.push(alphabet[((acc << (bits - nbits)) & mask) as usize] as char);
+    }
+    while !out.len().is_multiple_of(block) {
+        out.push('=');
+    }
+    out
+}
+
+fn decode_bits(
+    encoding: Encoding,
+    input: &str,
+    bits: u32,
+    lookup: impl Fn(u8) -> Option<u32>,
+) -> Result<Vec<u8>> {
+    let invalid = |index| DxError::InvalidSource { encoding, index };
+    let block = encoding.block();
+    let mut out = Vec::with_capacity(input.len() * bits as usize / 8);
+    let (mut acc, mut nbits) = (0u32, 0u32);
+    let (mut count, mut padding) = (0usize, 0usize);
+    for (index, c) in input.char_indices() {
+        if c.is_ascii_whitespace() {
+            continue;
+        }
+        if c == '=' && block > 1 {
+            padding += 1;
+            continue;
+        }
+        // 填充之后不能再有数据
+        let value = u8::try_from(c)
+            .ok()
+            .filter(|_| padding == 0)
+            .and_then(&lookup)
+            .ok_or_else(|| invalid(index))?;
+        count += 1;
+        acc = (acc << bits) | value;
+        nbits += bits;
+        if nbits >= 8 {
+            nbits -= 8;
+            out.push((acc >> nbits) as u8);
+            acc &= (1 << nbits) - 1;
+        }
+    }
+
+    // 剩余的位数够一个字符说明输入被截断
+    let truncated = nbits >= bits;
+    let bad_padding = padding > 0 && (padding >= block || !(count + padding).is_multiple_of(block));
+    if truncated || bad_padding {
+        return Err(invalid(input.len()));
+    }
+    Ok(out)
+}
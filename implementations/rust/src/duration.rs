@@ -0,0 +1,176 @@
+//! 人类可读的时长
+//!
+//! 解析 `90m`、`1h30m`、`7d` 这样的时长，单位为 `d`、`h`、`m`、`s`，各部分之间可以有空格，
+//! 每个单位最多出现一次且从大到小排列，纯数字按秒计；格式化时只保留最大的两个单位（如 `2h 14m`），用于显示剩余时间。
+
+use std::fmt;
+use std::time::Duration;
+
+use crate::locale::{Locale, Localized};
+
+/// 单位及其秒数，从大到小排列
+const UNITS: [(char, u64); 4] = [('d', 86_400), ('h', 3_600), ('m', 60), ('s', 1)];
+
+/// 时长解析错误
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DurationError {
+    /// 空字符串
+    Empty,
+    /// 位置 `index` 处的字符既不是数字也不是单位
+    InvalidCharacter { ch: char, index: usize },
+    /// 数字后缺少单位（如 `1h30`），`index` 为该数字的起始位置
+    MissingUnit { index: usize },
+    /// 单位重复或排在更小的单位之后（如 `2h2h`、`30m1h`），`index` 为该单位的位置
+    MisplacedUnit { unit: char, index: usize },
+    /// 超出 `u64` 秒
+    Overflow,
+}
+
+impl DurationError {
+    /// 按指定语言格式化错误信息，`Display` 始终输出英文
+    pub fn localized(&self, locale: Locale) -> Localized<'_, DurationError> {
+        Localized {
+            error: self,
+            locale,
+        }
+    }
+}
+
+impl fmt::Display for DurationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.localized(Locale::En), f)
+    }
+}
+
+impl fmt::Display for Localized<'_, DurationError> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.error, self.locale) {
+            (DurationError::Empty, Locale::En) => write!(f, "duration is empty"),
+            (DurationError::Empty, Locale::ZhCn) => write!(f, "时长为空"),
+            (DurationError::InvalidCharacter { ch, index }, Locale::En) => write!(
+                f,
+                "invalid character '{}' at index {} in duration (expected d, h, m or s)",
+                ch, index
+            ),
+            (DurationError::InvalidCharacter { ch, index }, Locale::ZhCn) => write!(
+                f,
+                "时长的位置 {} 包含非法字符 '{}'（单位可选 d、h、m、s）",
+                index, ch
+            ),
+            (DurationError::MissingUnit { index }, Locale::En) => {
+                write!(f, "missing unit after the number at index {}", index)
+            }
+            (DurationError::MissingUnit { index }, Locale::ZhCn) => {
+                write!(f, "时长的位置 {} 的数字缺少单位", index)
+            }
+            (DurationError::MisplacedUnit { unit, index }, Locale::En) => write!(
+                f,
+                "unit '{}' at index {} in duration is repeated or follows a smaller unit",
+                unit, index
+            ),
+            (DurationError::MisplacedUnit { unit, index }, Locale::ZhCn) => write!(
+                f,
+                "时长的位置 {} 的单位 '{}' 重复或排在更小的单位之后",
+                index, unit
+            ),
+            (DurationError::Overflow, Locale::En) => write!(f, "duration is too long"),
+            (DurationError::Overflow, Locale::ZhCn) => write!(f, "时长超出范围"),
+        }
+    }
+}
+
+impl std::error::Error for DurationError {}
+
+/// 解析时长
+///
+/// # 示例
+///
+/// ```
+/// use std::time::Duration;
+/// use dxcode::parse_duration;
+///
+/// assert_eq!(parse_duration("1h30m").unwrap(), Duration::from_secs(5_400));
+/// assert_eq!(parse_duration("7d").unwrap(), Duration::from_secs(604_800));
+/// assert_eq!(parse_duration("3600").unwrap(), Duration::from_secs(3_600));
+/// assert!(parse_duration("1h30").is_err());
+/// assert!(parse_duration("30m1h").is_err());
+/// ```
+pub fn parse_duration(s: &str) -> Result<Duration, DurationError> {
+    let s = s.trim();
+    if s.is_empty() {
+        return Err(DurationError::Empty);
+    }
+    if let Ok(secs) = s.parse::<u64>() {
+        return Ok(Duration::from_secs(secs));
+    }
+
+    let mut total: u64 = 0;
+    let mut number: Option<(usize, u64)> = None;
+    // 上一个单位在 UNITS 中的位置，后面的单位必须更小
+    let mut last_unit: Option<usize> = None;
+    for (index, ch) in s.char_indices() {
+        if let Some(digit) = ch.to_digit(10) {
+            let (start, value) = number.unwrap_or((index, 0));
+            let value = value
+                .checked_mul(10)
+                .and_then(|v| v.checked_add(digit as u64))
+                .ok_or(DurationError::Overflow)?;
+            number = Some((start, value));
+            continue;
+        }
+        if ch.is_whitespace() && number.is_none() {
+            continue;
+        }
+        let unit = UNITS.iter().position(|(name, _)| *name == ch);
+        match (unit, number.take()) {
+            (Some(rank), Some(_)) if last_unit.is_some_and(|last| rank <= last) => {
+                return Err(DurationError::MisplacedUnit { unit: ch, index });
+            }
+            (Some(rank), Some((_, value))) => {
+                last_unit = Some(rank);
+                let secs = UNITS[rank].1;
+                total = value
+                    .checked_mul(secs)
+                    .and_then(|v| total.checked_add(v))
+                    .ok_or(DurationError::Overflow)?;
+            }
+            _ => return Err(DurationError::InvalidCharacter { ch, index }),
+        }
+    }
+    match number {
+        Some((index, _)) => Err(DurationError::MissingUnit { index }),
+        None => Ok(Duration::from_secs(total)),
+    }
+}
+
+/// 格式化时长，只保留最大的两个单位，不足一秒的部分舍去
+///
+/// # 示例
+///
+/// ```
+/// use std::time::Duration;
+/// use dxcode::format_duration;
+///
+/// assert_eq!(format_duration(Duration::from_secs(8_040)), "2h 14m");
+/// assert_eq!(format_duration(Duration::from_secs(86_400)), "1d");
+/// assert_eq!(format_duration(Duration::from_secs(0)), "0s");
+/// ```
+pub fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    let Some(first) = UNITS.iter().position(|(_, unit)| secs >= *unit) else {
+        return "0s".to_string();
+    };
+    UNITS[first..]
+        .iter()
+        .take(2)
+        .scan(secs, |rest, (name, unit)| {
+            let value = *rest / unit;
+            *rest %= unit;
+            Some((value, name))
+        })
+        .filter(|(value, _)| *value > 0)
+        .map(|(value, name)| format!("{}{}", value, name))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
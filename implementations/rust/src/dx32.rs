@@ -0,0 +1,300 @@
+//! dx32 - 便于口述和手工输入的 Base32 变体
+//!
+//! 使用 Crockford Base32 字符集，与 DX 共享相同的头部、CRC16 和 TTL 帧格式：
+//!
+//! - 前缀必须是 `dX`，与 DX 的 `dx` 区分；正文不区分大小写
+//! - `O` 视为 `0`，`I`、`L` 视为 `1`
+//! - 连字符 `-` 仅用于分组，解码时忽略
+//! - 不使用填充字符，长度由字符数推算
+//!
+//! # 示例
+//!
+//! ```
+//! use dxcode::dx32;
+//!
+//! let encoded = dx32::STANDARD.with_group_size(4).encode(b"Hello");
+//! assert!(encoded.starts_with("dX"));
+//!
+//! // 手工抄写时正文的大小写和易混淆字符不影响解码
+//! let typed = format!("dX{}", encoded[2..].to_lowercase().replace('0', "o"));
+//! assert_eq!(dx32::decode(&typed).unwrap(), b"Hello");
+//! assert!(dx32::decode(&encoded.to_lowercase()).is_err());
+//! ```
+
+use std::borrow::Cow;
+
+use crate::alphabet::AlphabetError;
+use crate::frame::{self, Frame};
+use crate::{Clock, DxError, Result, TtlInfo};
+
+/// dx32 字符集（Crockford Base32）
+pub const CHARSET: &str = "0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// dx32 前缀
+pub const PREFIX: &str = "dX";
+
+/// 分组分隔符
+pub const SEPARATOR: char = '-';
+
+/// 反向查找表中的无效标记
+const INVALID: u8 = 0xFF;
+
+/// 反向查找表（不区分大小写，映射易混淆字符）
+const DECODE_TABLE: [u8; 256] = {
+    let mut table = [INVALID; 256];
+    let symbols = CHARSET.as_bytes();
+    let mut i = 0;
+    while i < 32 {
+        table[symbols[i] as usize] = i as u8;
+        table[symbols[i].to_ascii_lowercase() as usize] = i as u8;
+        i += 1;
+    }
+    table[b'O' as usize] = 0;
+    table[b'o' as usize] = 0;
+    table[b'I' as usize] = 1;
+    table[b'i' as usize] = 1;
+    table[b'L' as usize] = 1;
+    table[b'l' as usize] = 1;
+    table
+};
+
+/// 标准 dx32 引擎（前缀 `dX`，不分组）
+pub const STANDARD: Engine = Engine {
+    prefix: Cow::Borrowed(PREFIX),
+    group_size: 0,
+    clock: Clock::System,
+};
+
+/// dx32 编码引擎
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Engine {
+    prefix: Cow<'static, str>,
+    group_size: usize,
+    clock: Clock,
+}
+
+impl Engine {
+    /// 创建使用自定义前缀的引擎
+    ///
+    /// # 参数
+    ///
+    /// * `prefix` - 前缀，必须是非空的 ASCII 可见字符且不含分隔符
+    pub fn new(prefix: impl Into<Cow<'static, str>>) -> Result<Engine> {
+        let prefix = prefix.into();
+        if prefix.is_empty()
+            || !prefix
+                .bytes()
+                .all(|b| b.is_ascii_graphic() && b != SEPARATOR as u8)
+        {
+            return Err(AlphabetError::InvalidPrefix.into());
+        }
+
+        Ok(Engine { prefix, ..STANDARD })
+    }
+
+    /// 设置分组大小，每 `n` 个字符插入一个连字符，`0` 表示不分组
+    pub const fn with_group_size(mut self, n: usize) -> Engine {
+        self.group_size = n;
+        self
+    }
+
+    /// 设置生成和检查 TTL 时使用的时钟（默认 [`Clock::System`]）
+    pub const fn with_clock(mut self, clock: Clock) -> Engine {
+        self.clock = clock;
+        self
+    }
+
+    /// 前缀
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    /// 分组大小
+    pub fn group_size(&self) -> usize {
+        self.group_size
+    }
+
+    /// 时钟
+    pub fn clock(&self) -> Clock {
+        self.clock
+    }
+
+    /// 编码字节数据（带 CRC16 校验和和智能压缩）
+    pub fn encode(&self, data: &[u8]) -> String {
+        self.encode_with_options(data, true)
+    }
+
+    /// 编码字节数据，可选择是否启用压缩
+    pub fn encode_with_options(&self, data: &[u8], allow_compression: bool) -> String {
+        self.encode_frame(&frame::build(data, allow_compression, None, None))
+    }
+
+    /// 使用 TTL 编码字节数据
+    pub fn encode_with_ttl(&self, data: &[u8], ttl_seconds: u32) -> String {
+        self.encode_with_ttl_and_options(data, ttl_seconds, true)
+    }
+
+    /// 使用 TTL 编码字节数据，可选择是否启用压缩
+    pub fn encode_with_ttl_and_options(
+        &self,
+        data: &[u8],
+        ttl_seconds: u32,
+        allow_compression: bool,
+    ) -> String {
+        let created_at = self.clock.now() as u32;
+        self.encode_frame(&frame::build(
+            data,
+            allow_compression,
+            Some((created_at, ttl_seconds)),
+            None,
+        ))
+    }
+
+    /// 解码 dx32 字符串（带校验和验证，自动解压缩）
+    pub fn decode(&self, encoded: &str) -> Result<Vec<u8>> {
+        self.decode_with_options(encoded, true)
+    }
+
+    /// 解码 dx32 字符串，可选择是否检查 TTL
+    pub fn decode_with_options(&self, encoded: &str, check_ttl: bool) -> Result<Vec<u8>> {
+        let bytes = self.decode_frame(encoded)?;
+        let frame = Frame::parse(&bytes)?;
+        if check_ttl {
+            frame.check_ttl(self.clock.now())?;
+        }
+        frame.verified_data()
+    }
+
+    /// 验证校验和，不匹配返回 `Ok(false)`
+    pub fn verify(&self, encoded: &str) -> Result<bool> {
+        match self.decode(encoded) {
+            Ok(_) => Ok(true),
+            Err(DxError::ChecksumMismatch { .. }) => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// 获取 TTL 信息，不带 TTL 时返回 `None`
+    pub fn get_ttl_info(&self, encoded: &str) -> Result<Option<TtlInfo>> {
+        let bytes = self.decode_frame(encoded)?;
+        Ok(Frame::parse(&bytes)?.ttl_info(self.clock.now()))
+    }
+
+    /// 将帧编码为带前缀的字符串
+    fn encode_frame(&self, frame: &[u8]) -> String {
+        let body = encode_raw(frame);
+        let mut result = String::with_capacity(self.prefix.len() + body.len() * 5 / 4);
+        result.push_str(&self.prefix);
+
+        if self.group_size == 0 {
+            result.push_str(&body);
+        } else {
+            for (i, group) in body.as_bytes().chunks(self.group_size).enumerate() {
+                if i > 0 {
+                    result.push(SEPARATOR);
+                }
+                // body 只包含 ASCII 字符
+                result.extend(group.iter().map(|&b| b as char));
+            }
+        }
+        result
+    }
+
+    /// 验证前缀（区分大小写）并解码出帧
+    fn decode_frame(&self, encoded: &str) -> Result<Vec<u8>> {
+        let data = encoded
+            .strip_prefix(self.prefix.as_ref())
+            .ok_or(DxError::InvalidPrefix)?;
+        decode_raw(data).map_err(|e| e.map_index(|i| i + self.prefix.len()))
+    }
+}
+
+impl Default for Engine {
+    fn default() -> Self {
+        STANDARD
+    }
+}
+
+/// 使用标准 dx32 引擎编码
+pub fn encode(data: &[u8]) -> String {
+    STANDARD.encode(data)
+}
+
+/// 使用标准 dx32 引擎编码，带 TTL
+pub fn encode_with_ttl(data: &[u8], ttl_seconds: u32) -> String {
+    STANDARD.encode_with_ttl(data, ttl_seconds)
+}
+
+/// 使用标准 dx32 引擎解码
+pub fn decode(encoded: &str) -> Result<Vec<u8>> {
+    STANDARD.decode(encoded)
+}
+
+/// 内部编码函数（不带前缀和分组）
+fn encode_raw(data: &[u8]) -> String {
+    let symbols = CHARSET.as_bytes();
+    let mut result = String::with_capacity((data.len() * 8).div_ceil(5));
+
+    let mut buffer: u16 = 0;
+    let mut bits = 0;
+    for &byte in data {
+        buffer = (buffer << 8) | byte as u16;
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            result.push(symbols[((buffer >> bits) & 0x1F) as usize] as char);
+        }
+    }
+
+    // 剩余位左对齐补零
+    if bits > 0 {
+        result.push(symbols[((buffer << (5 - bits)) & 0x1F) as usize] as char);
+    }
+
+    result
+}
+
+/// 内部解码函数（忽略分隔符，不带前缀验证）
+fn decode_raw(data: &str) -> Result<Vec<u8>> {
+    let mut result = Vec::with_capacity(data.len() * 5 / 8);
+    let mut buffer: u16 = 0;
+    let mut bits = 0;
+    let mut symbols = 0;
+
+    for (index, ch) in data.char_indices() {
+        if ch == SEPARATOR {
+            continue;
+        }
+        let value = match u8::try_from(ch).map(|b| DECODE_TABLE[b as usize]) {
+            Ok(v) if v != INVALID => v,
+            _ => return Err(DxError::InvalidCharacter { ch, index }),
+        };
+
+        buffer = (buffer << 5) | value as u16;
+        bits += 5;
+        symbols += 1;
+        if bits >= 8 {
+            bits -= 8;
+            result.push((buffer >> bits) as u8);
+        }
+    }
+
+    // 剩余不足 5 位才是合法的末尾补零，否则缺少字符
+    if bits >= 5 {
+        return Err(DxError::InvalidLength {
+            expected: expected_len(symbols),
+            actual: symbols,
+        });
+    }
+
+    Ok(result)
+}
+
+/// 大于等于 `symbols` 的最小合法字符数（不含分隔符）
+///
+/// 每 8 个字符编码 5 个字节，余数只能是 0、2、4、5、7
+fn expected_len(symbols: usize) -> usize {
+    (symbols..)
+        .find(|n| matches!(n % 8, 0 | 2 | 4 | 5 | 7))
+        .unwrap_or(symbols)
+}
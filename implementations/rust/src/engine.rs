@@ -0,0 +1,634 @@
+//! 编码引擎
+//!
+//! `Engine` 组合字符集、魔数和前缀，在其上提供与顶层函数相同的编解码 API。
+//! 顶层函数均委托给 [`STANDARD`] 引擎。
+
+use std::borrow::Cow;
+use std::time::Duration;
+
+use crate::alphabet::{Alphabet, AlphabetError};
+use crate::convert::Encoding;
+use crate::frame::{self, Frame, Header, Inspection};
+use crate::scan::FindTokens;
+use crate::{Clock, DxError, Result, TtlInfo, MAGIC, PADDING, PREFIX};
+
+/// 标准 DX 引擎（标准字符集、魔数 `0x44`、前缀 `dx`）
+pub const STANDARD: Engine = Engine {
+    alphabet: Alphabet::STANDARD,
+    magic: MAGIC,
+    prefix: Cow::Borrowed(PREFIX),
+    encode_padding: true,
+    decode_padding: DecodePadding::Indifferent,
+    wrap_width: 0,
+    line_ending: LineEnding::Lf,
+    ignore_whitespace: false,
+    clock: Clock::System,
+    content_type_tag: false,
+};
+
+/// 解码时对填充字符的要求
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DecodePadding {
+    /// 接受带填充或不带填充的输入（默认）
+    #[default]
+    Indifferent,
+    /// 要求规范填充，长度必须是 4 的倍数
+    RequireCanonical,
+    /// 不允许出现填充字符
+    RequireNone,
+}
+
+/// 换行符
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LineEnding {
+    /// `\n`（默认）
+    #[default]
+    Lf,
+    /// `\r\n`（MIME 风格）
+    CrLf,
+}
+
+impl LineEnding {
+    /// 换行符字符串
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LineEnding::Lf => "\n",
+            LineEnding::CrLf => "\r\n",
+        }
+    }
+}
+
+/// 重新签发时对 TTL 的处理，见 [`Engine::reissue`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ReissuePolicy {
+    /// 在原过期时间上延长，不带 TTL 或永不过期的编码保持不变
+    Extend(Duration),
+    /// 设置新的过期时间（Unix 时间戳，秒），不带 TTL 时以当前时间为创建时间
+    ///
+    /// 过期时间不晚于引擎时钟的当前时间时返回 [`DxError::InvalidExpiry`]。
+    ExpiresAt(u64),
+    /// 移除 TTL，此后永不过期
+    RemoveTtl,
+}
+
+/// DX 编码引擎
+///
+/// # 示例
+///
+/// ```
+/// use dxcode::{Alphabet, Engine};
+///
+/// let engine = Engine::new(Alphabet::NO_CONFUSABLES, 0x2A, "acme:").unwrap();
+/// let encoded = engine.encode(b"Hello");
+/// assert!(encoded.starts_with("acme:"));
+/// assert_eq!(engine.decode(&encoded).unwrap(), b"Hello");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Engine {
+    alphabet: Alphabet,
+    magic: u8,
+    prefix: Cow<'static, str>,
+    encode_padding: bool,
+    decode_padding: DecodePadding,
+    wrap_width: usize,
+    line_ending: LineEnding,
+    ignore_whitespace: bool,
+    clock: Clock,
+    content_type_tag: bool,
+}
+
+impl Engine {
+    /// 创建自定义引擎
+    ///
+    /// # 参数
+    ///
+    /// * `alphabet` - 字符集
+    /// * `magic` - 魔数，用于 XOR 变换（仅低 6 位生效）
+    /// * `prefix` - 前缀，必须是非空的 ASCII 可见字符且不含填充字符，并且至少包含一个
+    ///   字符集以外的字符，以保证前缀不会出现在编码正文中
+    ///
+    /// # 返回值
+    ///
+    /// 新的引擎，前缀无效时返回 `DxError::InvalidAlphabet`
+    pub fn new(
+        alphabet: Alphabet,
+        magic: u8,
+        prefix: impl Into<Cow<'static, str>>,
+    ) -> Result<Engine> {
+        let prefix = prefix.into();
+        if prefix.is_empty()
+            || !prefix
+                .bytes()
+                .all(|b| b.is_ascii_graphic() && b != PADDING as u8)
+        {
+            return Err(AlphabetError::InvalidPrefix.into());
+        }
+        if prefix.bytes().all(|b| alphabet.contains(b)) {
+            return Err(AlphabetError::PrefixInAlphabet.into());
+        }
+
+        Ok(Engine {
+            alphabet,
+            magic,
+            prefix,
+            ..STANDARD
+        })
+    }
+
+    /// 设置编码时是否输出 `=` 填充（默认输出）
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use dxcode::STANDARD;
+    ///
+    /// let engine = STANDARD.with_encode_padding(false);
+    /// let encoded = engine.encode(b"Hello");
+    /// assert!(!encoded.ends_with('='));
+    /// assert_eq!(engine.decode(&encoded).unwrap(), b"Hello");
+    /// ```
+    pub const fn with_encode_padding(mut self, padding: bool) -> Engine {
+        self.encode_padding = padding;
+        self
+    }
+
+    /// 设置解码时对填充的要求（默认 [`DecodePadding::Indifferent`]）
+    pub const fn with_decode_padding(mut self, mode: DecodePadding) -> Engine {
+        self.decode_padding = mode;
+        self
+    }
+
+    /// 设置编码输出按 `width` 列换行（如 MIME 的 76 列、PEM 的 64 列），`0` 表示不换行
+    ///
+    /// 最后一行末尾不添加换行符。解码换行后的输出需要启用
+    /// [`Engine::with_ignore_whitespace`]。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use dxcode::{LineEnding, STANDARD};
+    ///
+    /// let engine = STANDARD
+    ///     .with_line_wrap(76, LineEnding::CrLf)
+    ///     .with_ignore_whitespace(true);
+    /// let data = vec![0x5A; 200];
+    /// let encoded = engine.encode_with_options(&data, false);
+    /// assert!(encoded.split("\r\n").all(|line| line.len() <= 76));
+    /// assert_eq!(engine.decode(&encoded).unwrap(), data);
+    /// ```
+    pub const fn with_line_wrap(mut self, width: usize, ending: LineEnding) -> Engine {
+        self.wrap_width = width;
+        self.line_ending = ending;
+        self
+    }
+
+    /// 设置解码时是否忽略任意位置的 ASCII 空白字符（默认不忽略）
+    ///
+    /// 错误中的位置仍然是原始输入中的偏移。
+    pub const fn with_ignore_whitespace(mut self, ignore: bool) -> Engine {
+        self.ignore_whitespace = ignore;
+        self
+    }
+
+    /// 设置生成和检查 TTL 时使用的时钟（默认 [`Clock::System`]）
+    pub const fn with_clock(mut self, clock: Clock) -> Engine {
+        self.clock = clock;
+        self
+    }
+
+    /// 设置 [`to_dx_string`](crate::to_dx_string) 是否在头部写入内容类型（flags 0x08，默认不写入）
+    ///
+    /// 其他语言实现会把该标志视为无效 flags，只在收发双方都使用 Rust 实现时开启。
+    pub const fn with_content_type_tag(mut self, tag: bool) -> Engine {
+        self.content_type_tag = tag;
+        self
+    }
+
+    /// 字符集
+    pub fn alphabet(&self) -> &Alphabet {
+        &self.alphabet
+    }
+
+    /// 魔数
+    pub fn magic(&self) -> u8 {
+        self.magic
+    }
+
+    /// 前缀
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    /// 时钟
+    pub fn clock(&self) -> Clock {
+        self.clock
+    }
+
+    /// 是否写入内容类型
+    pub fn content_type_tag(&self) -> bool {
+        self.content_type_tag
+    }
+
+    /// 将字节切片编码为 DX 格式（带 CRC16 校验和和智能压缩）
+    pub fn encode(&self, data: &[u8]) -> String {
+        self.encode_with_options(data, true)
+    }
+
+    /// 将字节切片编码为 DX 格式，可选择是否启用压缩
+    pub fn encode_with_options(&self, data: &[u8], allow_compression: bool) -> String {
+        self.encode_frame(&frame::build(data, allow_compression, None, None))
+    }
+
+    /// 使用 TTL 编码字节数据
+    pub fn encode_with_ttl(&self, data: &[u8], ttl_seconds: u32) -> String {
+        self.encode_with_ttl_and_options(data, ttl_seconds, true)
+    }
+
+    /// 使用 TTL 编码字节数据，可选择是否启用压缩
+    pub fn encode_with_ttl_and_options(
+        &self,
+        data: &[u8],
+        ttl_seconds: u32,
+        allow_compression: bool,
+    ) -> String {
+        let created_at = self.clock.now() as u32;
+        self.encode_frame(&frame::build(
+            data,
+            allow_compression,
+            Some((created_at, ttl_seconds)),
+            None,
+        ))
+    }
+
+    /// 将 DX 编码的字符串解码为字节向量（带校验和验证，自动解压缩）
+    pub fn decode(&self, encoded: &str) -> Result<Vec<u8>> {
+        self.decode_with_options(encoded, true)
+    }
+
+    /// 将 DX 编码的字符串解码为字节数组，可选择是否检查 TTL
+    pub fn decode_with_options(&self, encoded: &str, check_ttl: bool) -> Result<Vec<u8>> {
+        let bytes = self.decode_frame(encoded)?;
+        let frame = Frame::parse(&bytes)?;
+        if check_ttl {
+            frame.check_ttl(self.clock.now())?;
+        }
+        frame.verified_data()
+    }
+
+    /// 重新签发：按策略修改 TTL，数据、压缩方式、内容类型和创建时间保持不变
+    ///
+    /// 校验和无效时返回错误，已过期的编码返回 [`DxError::TtlExpired`]
+    pub fn reissue(&self, encoded: &str, policy: ReissuePolicy) -> Result<String> {
+        self.reissue_with_options(encoded, policy, true)
+    }
+
+    /// 重新签发，可选择是否拒绝已过期的编码
+    pub fn reissue_with_options(
+        &self,
+        encoded: &str,
+        policy: ReissuePolicy,
+        check_ttl: bool,
+    ) -> Result<String> {
+        let bytes = self.decode_frame(encoded)?;
+        let frame = Frame::parse(&bytes)?;
+        let now = self.clock.now();
+        if check_ttl {
+            frame.check_ttl(now)?;
+        }
+        frame.verified_data()?;
+
+        // 新的有效期必须为正数且能用 4 字节表示
+        let ttl_until = |created_at: u64, expires_at: u64| {
+            expires_at
+                .checked_sub(created_at)
+                .filter(|&ttl_seconds| ttl_seconds > 0)
+                .and_then(|ttl_seconds| u32::try_from(ttl_seconds).ok())
+                .map(|ttl_seconds| Some((created_at as u32, ttl_seconds)))
+                .ok_or(DxError::InvalidExpiry {
+                    created_at,
+                    expires_at,
+                })
+        };
+        let ttl = match (policy, frame.ttl) {
+            (ReissuePolicy::RemoveTtl, _) | (ReissuePolicy::Extend(_), None) => None,
+            (ReissuePolicy::Extend(_), Some((created_at, 0))) => Some((created_at as u32, 0)),
+            (ReissuePolicy::Extend(by), Some((created_at, ttl_seconds))) => ttl_until(
+                created_at,
+                (created_at + ttl_seconds as u64).saturating_add(by.as_secs()),
+            )?,
+            (ReissuePolicy::ExpiresAt(expires_at), ttl) => {
+                let created_at = ttl.map_or(now as u32 as u64, |(created_at, _)| created_at);
+                // 不签发已经过期的编码
+                if expires_at <= now {
+                    return Err(DxError::InvalidExpiry {
+                        created_at,
+                        expires_at,
+                    });
+                }
+                ttl_until(created_at, expires_at)?
+            }
+        };
+        Ok(self.encode_frame(&frame.with_ttl(ttl)))
+    }
+
+    /// 检查字符串是否为有效的 DX 编码（仅检查前缀、长度和字符）
+    pub fn is_encoded(&self, s: &str) -> bool {
+        let s = self.strip_whitespace(s);
+        let data = match s.strip_prefix(self.prefix()) {
+            Some(data) => data,
+            None => return false,
+        };
+
+        // 检查长度（至少需要头部）和填充
+        match self.strip_padding(data) {
+            Ok(body) if !body.is_empty() => body.bytes().all(|c| self.alphabet.contains(c)),
+            _ => false,
+        }
+    }
+
+    /// 验证 DX 编码的校验和（不返回解码数据）
+    pub fn verify(&self, encoded: &str) -> Result<bool> {
+        match self.decode(encoded) {
+            Ok(_) => Ok(true),
+            Err(DxError::ChecksumMismatch { .. }) => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// 完整验证编码（前缀、字符、长度、头部、校验和），不检查 TTL
+    ///
+    /// 与 [`is_encoded`](Engine::is_encoded) 只检查字符不同，通过验证的字符串一定可以解码
+    pub fn validate(&self, encoded: &str) -> Result<()> {
+        self.decode_with_options(encoded, false).map(|_| ())
+    }
+
+    /// 在文本中查找通过完整验证的 DX 字符串，见 [`find_tokens`](crate::find_tokens)
+    pub fn find_tokens<'t>(&self, text: &'t str) -> FindTokens<'t> {
+        FindTokens::new(self.clone(), text)
+    }
+
+    /// 将标准 base64 转换为 DX 编码（重新计算校验和，按需压缩）
+    pub fn from_base64(&self, input: &str) -> Result<String> {
+        Ok(self.encode(&Encoding::Base64.decode(input)?))
+    }
+
+    /// 将 DX 编码转换为标准 base64，只保留数据，TTL 等头部信息被丢弃
+    pub fn to_base64(&self, encoded: &str) -> Result<String> {
+        Ok(Encoding::Base64.encode(&self.decode(encoded)?))
+    }
+
+    /// 取出完整的帧（flags、校验和、TTL 等头部和数据），验证校验和，不检查 TTL
+    ///
+    /// 与 [`from_frame`](Engine::from_frame) 配合，可以在其他编码中原样保留头部
+    pub fn to_frame(&self, encoded: &str) -> Result<Vec<u8>> {
+        self.to_frame_with_options(encoded, false)
+    }
+
+    /// 取出完整的帧，可选择是否拒绝已过期的编码
+    pub fn to_frame_with_options(&self, encoded: &str, check_ttl: bool) -> Result<Vec<u8>> {
+        let bytes = self.decode_frame(encoded)?;
+        let frame = Frame::parse(&bytes)?;
+        if check_ttl {
+            frame.check_ttl(self.clock.now())?;
+        }
+        frame.verified_data()?;
+        Ok(bytes)
+    }
+
+    /// 将 [`to_frame`](Engine::to_frame) 取出的帧重新编码，帧的头部和校验和必须有效
+    pub fn from_frame(&self, frame: &[u8]) -> Result<String> {
+        Frame::parse(frame)?.verified_data()?;
+        Ok(self.encode_frame(frame))
+    }
+
+    /// 解析头部信息（不验证校验和）
+    pub fn get_header(&self, encoded: &str) -> Result<Header> {
+        let bytes = self.decode_frame(encoded)?;
+        Ok(Frame::parse(&bytes)?.header())
+    }
+
+    /// 解析编码的完整结构，用于排查问题
+    ///
+    /// 不要求 flags、头部长度和校验和有效，只有无法得到原始帧（前缀、字符或长度无效）时返回错误
+    pub fn inspect(&self, encoded: &str) -> Result<Inspection> {
+        Ok(Inspection::parse(self.decode_frame(encoded)?))
+    }
+
+    /// 获取 DX 编码的校验和信息，返回 `(存储的校验和, 实际计算的校验和)`
+    pub fn get_checksum(&self, encoded: &str) -> Result<(u16, u16)> {
+        let bytes = self.decode_frame(encoded)?;
+        let frame = Frame::parse(&bytes)?;
+        let computed = crate::crc16(&frame.original_data()?);
+        Ok((frame.checksum, computed))
+    }
+
+    /// 检查编码是否使用了压缩
+    pub fn is_compressed(&self, encoded: &str) -> Result<bool> {
+        let bytes = self.decode_frame(encoded)?;
+        Ok(Frame::parse(&bytes)?.is_compressed())
+    }
+
+    /// 检查编码是否包含 TTL 信息
+    pub fn has_ttl(&self, encoded: &str) -> Result<bool> {
+        let bytes = self.decode_frame(encoded)?;
+        Ok(Frame::parse(&bytes)?.ttl.is_some())
+    }
+
+    /// 获取编码的 TTL 信息，不带 TTL 时返回 `None`
+    pub fn get_ttl_info(&self, encoded: &str) -> Result<Option<TtlInfo>> {
+        self.ttl_info_at(encoded, self.clock.now())
+    }
+
+    /// 以指定时间为当前时间获取 TTL 信息
+    pub(crate) fn ttl_info_at(&self, encoded: &str, now: u64) -> Result<Option<TtlInfo>> {
+        let bytes = self.decode_frame(encoded)?;
+        Ok(Frame::parse(&bytes)?.ttl_info(now))
+    }
+
+    /// 检查编码是否已过期（没有 TTL 的数据永不过期）
+    pub fn is_expired(&self, encoded: &str) -> Result<bool> {
+        Ok(self
+            .get_ttl_info(encoded)?
+            .is_some_and(|info| info.is_expired))
+    }
+
+    /// 将帧编码为带前缀的字符串
+    pub(crate) fn encode_frame(&self, frame: &[u8]) -> String {
+        let mut result = String::with_capacity(self.prefix.len() + frame.len().div_ceil(3) * 4);
+        result.push_str(&self.prefix);
+        self.encode_raw(frame, &mut result);
+
+        if self.wrap_width == 0 || result.len() <= self.wrap_width {
+            return result;
+        }
+
+        // 输出只包含 ASCII 字符，可以按字节切分
+        let ending = self.line_ending.as_str();
+        let lines = result.len().div_ceil(self.wrap_width);
+        let mut wrapped = String::with_capacity(result.len() + lines * ending.len());
+        for (i, line) in result.as_bytes().chunks(self.wrap_width).enumerate() {
+            if i > 0 {
+                wrapped.push_str(ending);
+            }
+            wrapped.extend(line.iter().map(|&b| b as char));
+        }
+        wrapped
+    }
+
+    /// 验证前缀并解码出帧
+    pub(crate) fn decode_frame(&self, encoded: &str) -> Result<Vec<u8>> {
+        let stripped = self.strip_whitespace(encoded);
+        let data = stripped
+            .strip_prefix(self.prefix())
+            .ok_or(DxError::InvalidPrefix)?;
+
+        self.decode_raw(data).map_err(|e| {
+            e.map_index(|i| {
+                let i = i + self.prefix.len();
+                match stripped {
+                    // 将去除空白后的位置映射回原始输入
+                    Cow::Owned(_) => encoded
+                        .char_indices()
+                        .filter(|(_, c)| !c.is_ascii_whitespace())
+                        .nth(i)
+                        .map_or(encoded.len(), |(pos, _)| pos),
+                    Cow::Borrowed(_) => i,
+                }
+            })
+        })
+    }
+
+    /// 按配置去除 ASCII 空白字符
+    fn strip_whitespace<'a>(&self, s: &'a str) -> Cow<'a, str> {
+        if self.ignore_whitespace && s.bytes().any(|b| b.is_ascii_whitespace()) {
+            Cow::Owned(s.chars().filter(|c| !c.is_ascii_whitespace()).collect())
+        } else {
+            Cow::Borrowed(s)
+        }
+    }
+
+    /// 内部编码函数（不带前缀）
+    fn encode_raw(&self, data: &[u8], result: &mut String) {
+        let magic = self.magic;
+        let symbol = |v: u8| self.alphabet.symbol(v ^ magic) as char;
+
+        // 每 3 字节处理一组
+        for chunk in data.chunks(3) {
+            let b0 = chunk[0];
+            let b1 = chunk.get(1).copied().unwrap_or(0);
+            let b2 = chunk.get(2).copied().unwrap_or(0);
+
+            // 将 3 字节（24位）分成 4 个 6 位组
+            let v0 = b0 >> 2;
+            let v1 = ((b0 & 0x03) << 4) | (b1 >> 4);
+            let v2 = ((b1 & 0x0F) << 2) | (b2 >> 6);
+            let v3 = b2 & 0x3F;
+
+            // XOR 变换并映射到字符
+            result.push(symbol(v0));
+            result.push(symbol(v1));
+            if chunk.len() > 1 {
+                result.push(symbol(v2));
+            } else if self.encode_padding {
+                result.push(PADDING);
+            }
+            if chunk.len() > 2 {
+                result.push(symbol(v3));
+            } else if self.encode_padding {
+                result.push(PADDING);
+            }
+        }
+    }
+
+    /// 去除末尾填充并按解码模式验证长度，返回不含填充的数据部分
+    fn strip_padding<'a>(&self, data: &'a str) -> Result<&'a str> {
+        let body = data.trim_end_matches(PADDING);
+        let padding_count = data.len() - body.len();
+        let invalid_length = || DxError::InvalidLength {
+            expected: self.expected_len(body.len(), padding_count > 0),
+            actual: data.len(),
+        };
+
+        // 余 1 个字符不足一个字节
+        if body.len() % 4 == 1 || padding_count > 2 {
+            return Err(invalid_length());
+        }
+
+        match self.decode_padding {
+            DecodePadding::RequireNone if padding_count > 0 => {
+                return Err(DxError::InvalidCharacter {
+                    ch: PADDING,
+                    index: body.len(),
+                });
+            }
+            DecodePadding::RequireCanonical if !data.len().is_multiple_of(4) => {
+                return Err(invalid_length());
+            }
+            // 出现填充时必须补齐到 4 的倍数
+            _ if padding_count > 0 && !data.len().is_multiple_of(4) => {
+                return Err(invalid_length());
+            }
+            _ => {}
+        }
+
+        Ok(body)
+    }
+
+    /// 与当前长度最接近的合法长度（字符数，含填充）
+    fn expected_len(&self, body_len: usize, padded: bool) -> usize {
+        // 余 1 个字符时至少还缺 1 个字符
+        let body_len = body_len + usize::from(body_len % 4 == 1);
+        match self.decode_padding {
+            DecodePadding::RequireNone => body_len,
+            DecodePadding::RequireCanonical => body_len.next_multiple_of(4),
+            DecodePadding::Indifferent if padded => body_len.next_multiple_of(4),
+            DecodePadding::Indifferent => body_len,
+        }
+    }
+
+    /// 内部解码函数（不带前缀验证）
+    fn decode_raw(&self, data: &str) -> Result<Vec<u8>> {
+        // 先报告非法字符（比长度错误更能说明问题），填充只能出现在末尾
+        let trimmed = data.trim_end_matches(PADDING);
+        if let Some(index) = trimmed.bytes().position(|b| !self.alphabet.contains(b)) {
+            // 之前的字符都是 ASCII，index 一定位于字符边界
+            let ch = trimmed[index..].chars().next().unwrap_or(PADDING);
+            return Err(DxError::InvalidCharacter { ch, index });
+        }
+
+        let body = self.strip_padding(data)?;
+
+        // 由剩余字符数推算输出长度
+        let output_len = body.len() / 4 * 3 + [0, 0, 1, 2][body.len() % 4];
+        let mut result = Vec::with_capacity(output_len);
+
+        // 字符已验证，XOR 逆变换
+        let magic = self.magic;
+        let value = |c: u8| (self.alphabet.value(c).unwrap_or(0) ^ magic) & 0x3F;
+
+        // 每 4 字符处理一组，最后一组可能不足 4 个字符
+        for chunk in body.as_bytes().chunks(4) {
+            let mut v = [0u8; 4];
+            for (slot, &c) in v.iter_mut().zip(chunk) {
+                *slot = value(c);
+            }
+
+            // 重建字节
+            let bytes = [
+                (v[0] << 2) | (v[1] >> 4),
+                ((v[1] & 0x0F) << 4) | (v[2] >> 2),
+                ((v[2] & 0x03) << 6) | v[3],
+            ];
+            result.extend_from_slice(&bytes[..chunk.len() - 1]);
+        }
+
+        Ok(result)
+    }
+}
+
+impl Default for Engine {
+    fn default() -> Self {
+        STANDARD
+    }
+}
@@ -0,0 +1,365 @@
+//! 错误类型
+
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::str::Utf8Error;
+use std::string::FromUtf8Error;
+use std::sync::Arc;
+
+use crate::alphabet::AlphabetError;
+use crate::convert::Encoding;
+use crate::locale::{Locale, Localized};
+
+/// DX 编码错误类型
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum DxError {
+    /// 缺少 dx 前缀
+    InvalidPrefix,
+    /// 编码长度不正确，`expected` 为最接近的合法长度，均为字符数
+    InvalidLength { expected: usize, actual: usize },
+    /// 包含非法字符，`index` 为该字符在原始输入中的位置
+    InvalidCharacter { ch: char, index: usize },
+    /// UTF-8 解码错误
+    Utf8Error(Utf8Error),
+    /// 校验和不匹配
+    ChecksumMismatch { expected: u16, actual: u16 },
+    /// 头部无效，`expected` 为所需的最小字节数，`actual` 为实际字节数
+    InvalidHeader { expected: usize, actual: usize },
+    /// 压缩/解压缩错误
+    CompressionError(Arc<io::Error>),
+    /// 无效的 flags
+    InvalidFlags(u8),
+    /// TTL 已过期
+    TtlExpired {
+        created_at: u64,
+        ttl_seconds: u32,
+        expired_at: u64,
+    },
+    /// 字符集或前缀无效
+    InvalidAlphabet(AlphabetError),
+    /// 内容类型不符，`actual` 为 `None` 表示未标记内容类型
+    InvalidContentType { expected: u8, actual: Option<u8> },
+    /// 序列化/反序列化错误
+    Serialization(Arc<dyn Error + Send + Sync>),
+    /// 令牌超过 Cookie 或 HTTP 头部的大小上限，均为字节数
+    TokenTooLarge { max: usize, actual: usize },
+    /// 待转换的输入不是有效的 `encoding` 编码，`index` 为出错的字节位置，输入不完整时为输入长度
+    InvalidSource { encoding: Encoding, index: usize },
+    /// 过期时间无效：必须晚于创建时间（`ExpiresAt` 还须晚于当前时间），且与创建时间相差不超过 `u32::MAX` 秒
+    InvalidExpiry { created_at: u64, expires_at: u64 },
+    /// Cookie 的名称或属性包含分隔符或控制字符，`attribute` 为 `name`、`Path` 或 `Domain`
+    InvalidCookie { attribute: &'static str },
+}
+
+/// 错误类别，便于程序化匹配
+///
+/// # 示例
+///
+/// ```
+/// use dxcode::{decode, ErrorKind};
+///
+/// let err = decode("hello").unwrap_err();
+/// assert_eq!(err.kind(), ErrorKind::InvalidPrefix);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// 缺少前缀
+    InvalidPrefix,
+    /// 长度不正确
+    InvalidLength,
+    /// 包含非法字符
+    InvalidCharacter,
+    /// 不是有效的 UTF-8
+    InvalidUtf8,
+    /// 校验和不匹配
+    ChecksumMismatch,
+    /// 头部无效
+    InvalidHeader,
+    /// 压缩/解压缩失败
+    Compression,
+    /// flags 无效
+    InvalidFlags,
+    /// TTL 已过期
+    TtlExpired,
+    /// 字符集或前缀无效
+    InvalidAlphabet,
+    /// 内容类型不符
+    InvalidContentType,
+    /// 序列化/反序列化失败
+    Serialization,
+    /// 令牌过大
+    TokenTooLarge,
+    /// 待转换的输入无效
+    InvalidSource,
+    /// 过期时间无效
+    InvalidExpiry,
+    /// Cookie 名称或属性无效
+    InvalidCookie,
+}
+
+impl DxError {
+    /// 错误类别
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            DxError::InvalidPrefix => ErrorKind::InvalidPrefix,
+            DxError::InvalidLength { .. } => ErrorKind::InvalidLength,
+            DxError::InvalidCharacter { .. } => ErrorKind::InvalidCharacter,
+            DxError::Utf8Error(_) => ErrorKind::InvalidUtf8,
+            DxError::ChecksumMismatch { .. } => ErrorKind::ChecksumMismatch,
+            DxError::InvalidHeader { .. } => ErrorKind::InvalidHeader,
+            DxError::CompressionError(_) => ErrorKind::Compression,
+            DxError::InvalidFlags(_) => ErrorKind::InvalidFlags,
+            DxError::TtlExpired { .. } => ErrorKind::TtlExpired,
+            DxError::InvalidAlphabet(_) => ErrorKind::InvalidAlphabet,
+            DxError::InvalidContentType { .. } => ErrorKind::InvalidContentType,
+            DxError::Serialization(_) => ErrorKind::Serialization,
+            DxError::TokenTooLarge { .. } => ErrorKind::TokenTooLarge,
+            DxError::InvalidSource { .. } => ErrorKind::InvalidSource,
+            DxError::InvalidExpiry { .. } => ErrorKind::InvalidExpiry,
+            DxError::InvalidCookie { .. } => ErrorKind::InvalidCookie,
+        }
+    }
+
+    /// 错误在原始输入中的位置（仅 `InvalidCharacter` 有）
+    pub fn index(&self) -> Option<usize> {
+        match self {
+            DxError::InvalidCharacter { index, .. } => Some(*index),
+            _ => None,
+        }
+    }
+
+    /// 调整错误中的字符位置（用于加上前缀长度或映射回原始输入）
+    pub(crate) fn map_index(self, f: impl FnOnce(usize) -> usize) -> DxError {
+        match self {
+            DxError::InvalidCharacter { ch, index } => DxError::InvalidCharacter {
+                ch,
+                index: f(index),
+            },
+            e => e,
+        }
+    }
+}
+
+impl DxError {
+    /// 按指定语言格式化错误信息
+    ///
+    /// `Display` 始终输出英文，便于日志检索；面向用户展示时可选择本地化版本。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use dxcode::{decode, Locale};
+    ///
+    /// let err = decode("hello").unwrap_err();
+    /// assert_eq!(err.to_string(), "invalid DX encoding: missing dx prefix");
+    /// assert_eq!(
+    ///     err.localized(Locale::ZhCn).to_string(),
+    ///     "无效的 DX 编码：缺少 dx 前缀"
+    /// );
+    /// ```
+    pub fn localized(&self, locale: Locale) -> Localized<'_, DxError> {
+        Localized {
+            error: self,
+            locale,
+        }
+    }
+}
+
+impl fmt::Display for DxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.localized(Locale::En), f)
+    }
+}
+
+impl fmt::Display for Localized<'_, DxError> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.locale {
+            Locale::En => fmt_en(self.error, f),
+            Locale::ZhCn => fmt_zh_cn(self.error, f),
+        }
+    }
+}
+
+fn fmt_en(error: &DxError, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match error {
+        DxError::InvalidPrefix => write!(f, "invalid DX encoding: missing dx prefix"),
+        DxError::InvalidLength { expected, actual } => write!(
+            f,
+            "invalid DX encoding: expected {} characters, found {}",
+            expected, actual
+        ),
+        DxError::InvalidCharacter { ch, index } => write!(
+            f,
+            "invalid DX encoding: invalid character '{}' at index {}",
+            ch, index
+        ),
+        DxError::Utf8Error(e) => write!(f, "invalid UTF-8: {}", e),
+        DxError::ChecksumMismatch { expected, actual } => write!(
+            f,
+            "checksum mismatch: expected 0x{:04X}, found 0x{:04X}",
+            expected, actual
+        ),
+        DxError::InvalidHeader { expected, actual } => write!(
+            f,
+            "invalid header: need at least {} bytes, found {}",
+            expected, actual
+        ),
+        DxError::CompressionError(e) => write!(f, "compression error: {}", e),
+        DxError::InvalidFlags(flags) => write!(f, "invalid flags byte: 0x{:02X}", flags),
+        DxError::TtlExpired {
+            created_at,
+            ttl_seconds,
+            expired_at,
+        } => write!(
+            f,
+            "TTL expired: created at {}, valid for {} seconds, expired at {}",
+            created_at, ttl_seconds, expired_at
+        ),
+        DxError::InvalidAlphabet(e) => write!(f, "invalid alphabet: {}", e),
+        DxError::InvalidContentType { expected, actual } => match actual {
+            Some(actual) => write!(
+                f,
+                "unexpected content type: expected 0x{:02X}, found 0x{:02X}",
+                expected, actual
+            ),
+            None => write!(
+                f,
+                "unexpected content type: expected 0x{:02X}, found none",
+                expected
+            ),
+        },
+        DxError::Serialization(e) => write!(f, "serialization error: {}", e),
+        DxError::TokenTooLarge { max, actual } => write!(
+            f,
+            "token too large: limit is {} bytes, found {}",
+            max, actual
+        ),
+        DxError::InvalidSource { encoding, index } => {
+            write!(f, "invalid {} input at index {}", encoding, index)
+        }
+        DxError::InvalidExpiry {
+            created_at,
+            expires_at,
+        } => write!(
+            f,
+            "invalid expiry {}: must be in the future, after the creation time {} and within {} seconds of it",
+            expires_at,
+            created_at,
+            u32::MAX
+        ),
+        DxError::InvalidCookie { attribute } => write!(
+            f,
+            "invalid cookie {}: contains a separator or control character",
+            attribute
+        ),
+    }
+}
+
+fn fmt_zh_cn(error: &DxError, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match error {
+        DxError::InvalidPrefix => write!(f, "无效的 DX 编码：缺少 dx 前缀"),
+        DxError::InvalidLength { expected, actual } => write!(
+            f,
+            "无效的 DX 编码：长度不正确，期望 {} 个字符，实际 {} 个",
+            expected, actual
+        ),
+        DxError::InvalidCharacter { ch, index } => {
+            write!(f, "无效的 DX 编码：位置 {} 包含非法字符 '{}'", index, ch)
+        }
+        DxError::Utf8Error(e) => write!(f, "UTF-8 解码错误：{}", e),
+        DxError::ChecksumMismatch { expected, actual } => write!(
+            f,
+            "校验和不匹配：期望 0x{:04X}，实际 0x{:04X}",
+            expected, actual
+        ),
+        DxError::InvalidHeader { expected, actual } => write!(
+            f,
+            "无效的格式头部：至少需要 {} 字节，实际 {} 字节",
+            expected, actual
+        ),
+        DxError::CompressionError(e) => write!(f, "压缩/解压缩错误：{}", e),
+        DxError::InvalidFlags(flags) => write!(f, "无效的 flags 字节：0x{:02X}", flags),
+        DxError::TtlExpired {
+            created_at,
+            ttl_seconds,
+            expired_at,
+        } => write!(
+            f,
+            "TTL 已过期：创建于 {}，有效期 {} 秒，已于 {} 过期",
+            created_at, ttl_seconds, expired_at
+        ),
+        DxError::InvalidAlphabet(e) => {
+            write!(f, "无效的字符集：{}", e.localized(Locale::ZhCn))
+        }
+        DxError::InvalidContentType { expected, actual } => match actual {
+            Some(actual) => write!(
+                f,
+                "内容类型不符：期望 0x{:02X}，实际 0x{:02X}",
+                expected, actual
+            ),
+            None => write!(f, "内容类型不符：期望 0x{:02X}，实际未标记", expected),
+        },
+        DxError::Serialization(e) => write!(f, "序列化错误：{}", e),
+        DxError::TokenTooLarge { max, actual } => {
+            write!(f, "令牌过大：上限 {} 字节，实际 {} 字节", max, actual)
+        }
+        DxError::InvalidSource { encoding, index } => {
+            write!(f, "无效的 {} 输入：位置 {}", encoding, index)
+        }
+        DxError::InvalidExpiry {
+            created_at,
+            expires_at,
+        } => write!(
+            f,
+            "无效的过期时间 {}：必须晚于当前时间和创建时间 {}，且相差不超过 {} 秒",
+            expires_at,
+            created_at,
+            u32::MAX
+        ),
+        DxError::InvalidCookie { attribute } => {
+            write!(f, "无效的 Cookie {}：包含分隔符或控制字符", attribute)
+        }
+    }
+}
+
+impl Error for DxError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            DxError::Utf8Error(e) => Some(e),
+            DxError::CompressionError(e) => Some(e.as_ref()),
+            DxError::InvalidAlphabet(e) => Some(e),
+            DxError::Serialization(e) => Some(e.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl From<AlphabetError> for DxError {
+    fn from(e: AlphabetError) -> Self {
+        DxError::InvalidAlphabet(e)
+    }
+}
+
+impl From<io::Error> for DxError {
+    fn from(e: io::Error) -> Self {
+        DxError::CompressionError(Arc::new(e))
+    }
+}
+
+impl From<Utf8Error> for DxError {
+    fn from(e: Utf8Error) -> Self {
+        DxError::Utf8Error(e)
+    }
+}
+
+impl From<FromUtf8Error> for DxError {
+    fn from(e: FromUtf8Error) -> Self {
+        DxError::Utf8Error(e.utf8_error())
+    }
+}
+
+/// DX 编码结果类型
+pub type Result<T> = std::result::Result<T, DxError>;
@@ -0,0 +1,450 @@
+//! C ABI（需要启用 `ffi` feature）
+//!
+//! 以 `cdylib`/`staticlib` 形式链接，头文件 `include/dxcode.h` 由 cbindgen 在构建时生成。
+//!
+//! - 所有函数返回 `DX_OK` 或负数错误码（见 `DX_ERROR_*`，与 [`ErrorKind`] 一一对应）
+//! - `dx_encode`/`dx_decode` 分配输出，使用 `dx_free` 释放
+//! - `dx_encode_into`/`dx_decode_into` 写入调用方提供的缓冲区，缓冲区不足时返回
+//!   `DX_ERROR_BUFFER_TOO_SMALL`，并通过 `out_len` 返回所需的大小
+//!
+//! ```c
+//! char *encoded = NULL;
+//! if (dx_encode((const uint8_t *)"Hello", 5, &encoded) == DX_OK) {
+//!     printf("%s\n", encoded);
+//!     dx_free(encoded);
+//! }
+//! ```
+
+use std::alloc::{self, Layout};
+use std::ffi::{c_char, c_int, c_void, CStr};
+use std::mem;
+use std::ptr;
+use std::slice;
+
+use crate::{DxError, ErrorKind, STANDARD};
+
+/// 成功
+pub const DX_OK: c_int = 0;
+/// 无效输入（空指针或不是 UTF-8 的字符串）
+pub const DX_ERROR_INVALID_INPUT: c_int = -1;
+/// 缺少前缀
+pub const DX_ERROR_INVALID_PREFIX: c_int = -2;
+/// 长度不正确
+pub const DX_ERROR_INVALID_LENGTH: c_int = -3;
+/// 包含非法字符
+pub const DX_ERROR_INVALID_CHARACTER: c_int = -4;
+/// 缓冲区太小
+pub const DX_ERROR_BUFFER_TOO_SMALL: c_int = -5;
+/// 内存分配失败
+pub const DX_ERROR_MEMORY: c_int = -6;
+/// 头部无效
+pub const DX_ERROR_INVALID_HEADER: c_int = -7;
+/// flags 无效
+pub const DX_ERROR_INVALID_FLAGS: c_int = -8;
+/// 校验和不匹配
+pub const DX_ERROR_CHECKSUM_MISMATCH: c_int = -9;
+/// 压缩/解压缩失败
+pub const DX_ERROR_COMPRESSION: c_int = -10;
+/// TTL 已过期
+pub const DX_ERROR_TTL_EXPIRED: c_int = -11;
+/// 不是有效的 UTF-8
+pub const DX_ERROR_INVALID_UTF8: c_int = -12;
+/// 字符集或前缀无效
+pub const DX_ERROR_INVALID_ALPHABET: c_int = -13;
+/// 内容类型不符
+pub const DX_ERROR_INVALID_CONTENT_TYPE: c_int = -14;
+/// 序列化/反序列化失败
+pub const DX_ERROR_SERIALIZATION: c_int = -15;
+/// 令牌过大
+pub const DX_ERROR_TOKEN_TOO_LARGE: c_int = -16;
+/// 待转换的输入无效
+pub const DX_ERROR_INVALID_SOURCE: c_int = -17;
+/// 过期时间无效
+pub const DX_ERROR_INVALID_EXPIRY: c_int = -18;
+/// Cookie 名称或属性无效
+pub const DX_ERROR_INVALID_COOKIE: c_int = -19;
+
+/// 头部信息
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DxHeader {
+    /// flags 字节
+    pub flags: u8,
+    /// 存储的 CRC16 校验和
+    pub checksum: u16,
+    /// 是否压缩
+    pub compressed: bool,
+    /// 压缩前的大小，未压缩时为 0
+    pub original_size: u16,
+    /// 是否带 TTL
+    pub has_ttl: bool,
+    /// 创建时间（Unix 时间戳，秒），不带 TTL 时为 0
+    pub created_at: u64,
+    /// 有效期（秒），不带 TTL 或永不过期时为 0
+    pub ttl_seconds: u32,
+    /// 是否标记了内容类型
+    pub has_content_type: bool,
+    /// 内容类型
+    pub content_type: u8,
+    /// 负载字节数
+    pub payload_len: usize,
+}
+
+/// TTL 信息
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DxTtlInfo {
+    /// 是否带 TTL，为 `false` 时其他字段均为 0
+    pub has_ttl: bool,
+    /// 创建时间（Unix 时间戳，秒）
+    pub created_at: u64,
+    /// 有效期（秒），0 表示永不过期
+    pub ttl_seconds: u32,
+    /// 过期时间（Unix 时间戳，秒），永不过期时为 0
+    pub expires_at: u64,
+    /// 是否已过期
+    pub is_expired: bool,
+}
+
+/// 错误对应的错误码
+pub fn error_code(error: &DxError) -> c_int {
+    match error.kind() {
+        ErrorKind::InvalidPrefix => DX_ERROR_INVALID_PREFIX,
+        ErrorKind::InvalidLength => DX_ERROR_INVALID_LENGTH,
+        ErrorKind::InvalidCharacter => DX_ERROR_INVALID_CHARACTER,
+        ErrorKind::InvalidUtf8 => DX_ERROR_INVALID_UTF8,
+        ErrorKind::ChecksumMismatch => DX_ERROR_CHECKSUM_MISMATCH,
+        ErrorKind::InvalidHeader => DX_ERROR_INVALID_HEADER,
+        ErrorKind::Compression => DX_ERROR_COMPRESSION,
+        ErrorKind::InvalidFlags => DX_ERROR_INVALID_FLAGS,
+        ErrorKind::TtlExpired => DX_ERROR_TTL_EXPIRED,
+        ErrorKind::InvalidAlphabet => DX_ERROR_INVALID_ALPHABET,
+        ErrorKind::InvalidContentType => DX_ERROR_INVALID_CONTENT_TYPE,
+        ErrorKind::Serialization => DX_ERROR_SERIALIZATION,
+        ErrorKind::TokenTooLarge => DX_ERROR_TOKEN_TOO_LARGE,
+        ErrorKind::InvalidSource => DX_ERROR_INVALID_SOURCE,
+        ErrorKind::InvalidExpiry => DX_ERROR_INVALID_EXPIRY,
+        ErrorKind::InvalidCookie => DX_ERROR_INVALID_COOKIE,
+    }
+}
+
+/// 分配头部，记录分配大小以便 `dx_free` 释放
+const ALLOC_HEADER: usize = mem::size_of::<usize>();
+
+/// 分配 `data` 的副本（可选追加 NUL），失败时返回空指针
+fn alloc_copy(data: &[u8], nul: bool) -> *mut u8 {
+    let size = ALLOC_HEADER + data.len() + usize::from(nul);
+    let Ok(layout) = Layout::from_size_align(size, mem::align_of::<usize>()) else {
+        return ptr::null_mut();
+    };
+    // SAFETY: size 至少为 ALLOC_HEADER，不为 0；写入范围均在分配的 size 字节内
+    unsafe {
+        let base = alloc::alloc(layout);
+        if base.is_null() {
+            return ptr::null_mut();
+        }
+        base.cast::<usize>().write(size);
+        let out = base.add(ALLOC_HEADER);
+        ptr::copy_nonoverlapping(data.as_ptr(), out, data.len());
+        if nul {
+            out.add(data.len()).write(0);
+        }
+        out
+    }
+}
+
+/// 读取 NUL 结尾的 UTF-8 字符串
+unsafe fn read_str<'a>(s: *const c_char) -> Result<&'a str, c_int> {
+    if s.is_null() {
+        return Err(DX_ERROR_INVALID_INPUT);
+    }
+    CStr::from_ptr(s)
+        .to_str()
+        .map_err(|_| DX_ERROR_INVALID_INPUT)
+}
+
+/// 读取输入字节，`len` 为 0 时允许空指针
+unsafe fn read_bytes<'a>(input: *const u8, len: usize) -> Result<&'a [u8], c_int> {
+    if len == 0 {
+        return Ok(&[]);
+    }
+    if input.is_null() {
+        return Err(DX_ERROR_INVALID_INPUT);
+    }
+    Ok(slice::from_raw_parts(input, len))
+}
+
+/// 将结果写入调用方的缓冲区，`out_len` 总是返回所需的大小（不含 NUL）
+unsafe fn write_into(
+    data: &[u8],
+    nul: bool,
+    buf: *mut u8,
+    buf_size: usize,
+    out_len: *mut usize,
+) -> c_int {
+    if !out_len.is_null() {
+        *out_len = data.len();
+    }
+    if buf_size < data.len() + usize::from(nul) {
+        return DX_ERROR_BUFFER_TOO_SMALL;
+    }
+    if buf.is_null() {
+        return DX_ERROR_INVALID_INPUT;
+    }
+    ptr::copy_nonoverlapping(data.as_ptr(), buf, data.len());
+    if nul {
+        *buf.add(data.len()) = 0;
+    }
+    DX_OK
+}
+
+macro_rules! try_ffi {
+    ($e:expr) => {
+        match $e {
+            Ok(v) => v,
+            Err(code) => return code,
+        }
+    };
+}
+
+/// 编码字节数据，`*out` 为新分配的 NUL 结尾字符串，需要用 `dx_free` 释放
+///
+/// # Safety
+///
+/// `input` 指向至少 `input_len` 个可读字节（`input_len` 为 0 时可以为空），
+/// `out` 为有效的可写指针。
+#[no_mangle]
+pub unsafe extern "C" fn dx_encode(
+    input: *const u8,
+    input_len: usize,
+    out: *mut *mut c_char,
+) -> c_int {
+    dx_encode_with_ttl(input, input_len, 0, out)
+}
+
+/// 使用 TTL 编码字节数据，`ttl_seconds` 为 0 时不带 TTL
+///
+/// # Safety
+///
+/// 同 [`dx_encode`]。
+#[no_mangle]
+pub unsafe extern "C" fn dx_encode_with_ttl(
+    input: *const u8,
+    input_len: usize,
+    ttl_seconds: u32,
+    out: *mut *mut c_char,
+) -> c_int {
+    if out.is_null() {
+        return DX_ERROR_INVALID_INPUT;
+    }
+    let data = try_ffi!(read_bytes(input, input_len));
+    let encoded = if ttl_seconds == 0 {
+        STANDARD.encode(data)
+    } else {
+        STANDARD.encode_with_ttl(data, ttl_seconds)
+    };
+    let ptr = alloc_copy(encoded.as_bytes(), true);
+    if ptr.is_null() {
+        return DX_ERROR_MEMORY;
+    }
+    *out = ptr.cast();
+    DX_OK
+}
+
+/// 编码字节数据到调用方的缓冲区（包含 NUL 结尾）
+///
+/// `out_len` 返回编码后的字符数（不含 NUL），可以为空指针。
+///
+/// # Safety
+///
+/// `input` 同 [`dx_encode`]；`buf` 指向至少 `buf_size` 个可写字节。
+#[no_mangle]
+pub unsafe extern "C" fn dx_encode_into(
+    input: *const u8,
+    input_len: usize,
+    buf: *mut c_char,
+    buf_size: usize,
+    out_len: *mut usize,
+) -> c_int {
+    let data = try_ffi!(read_bytes(input, input_len));
+    let encoded = STANDARD.encode(data);
+    write_into(encoded.as_bytes(), true, buf.cast(), buf_size, out_len)
+}
+
+/// 解码（验证校验和，检查 TTL），`*out` 为新分配的字节数组，需要用 `dx_free` 释放
+///
+/// 解码结果为空时 `*out` 仍为有效指针，`*out_len` 为 0。
+///
+/// # Safety
+///
+/// `encoded` 为 NUL 结尾的字符串，`out`、`out_len` 为有效的可写指针。
+#[no_mangle]
+pub unsafe extern "C" fn dx_decode(
+    encoded: *const c_char,
+    out: *mut *mut u8,
+    out_len: *mut usize,
+) -> c_int {
+    if out.is_null() || out_len.is_null() {
+        return DX_ERROR_INVALID_INPUT;
+    }
+    let encoded = try_ffi!(read_str(encoded));
+    let data = try_ffi!(STANDARD.decode(encoded).map_err(|e| error_code(&e)));
+    let ptr = alloc_copy(&data, false);
+    if ptr.is_null() {
+        return DX_ERROR_MEMORY;
+    }
+    *out = ptr;
+    *out_len = data.len();
+    DX_OK
+}
+
+/// 解码到调用方的缓冲区
+///
+/// `out_len` 返回解码后的字节数；缓冲区不足时返回 `DX_ERROR_BUFFER_TOO_SMALL`，
+/// `out_len` 为所需的大小。
+///
+/// # Safety
+///
+/// `encoded` 为 NUL 结尾的字符串；`buf` 指向至少 `buf_size` 个可写字节。
+#[no_mangle]
+pub unsafe extern "C" fn dx_decode_into(
+    encoded: *const c_char,
+    buf: *mut u8,
+    buf_size: usize,
+    out_len: *mut usize,
+) -> c_int {
+    let encoded = try_ffi!(read_str(encoded));
+    let data = try_ffi!(STANDARD.decode(encoded).map_err(|e| error_code(&e)));
+    write_into(&data, false, buf, buf_size, out_len)
+}
+
+/// 释放 `dx_encode`/`dx_decode` 分配的内存，空指针时不做任何操作
+///
+/// # Safety
+///
+/// `ptr` 必须来自本库的分配函数，且只能释放一次。
+#[no_mangle]
+pub unsafe extern "C" fn dx_free(ptr: *mut c_void) {
+    if ptr.is_null() {
+        return;
+    }
+    let base = ptr.cast::<u8>().sub(ALLOC_HEADER);
+    let size = base.cast::<usize>().read();
+    alloc::dealloc(
+        base,
+        Layout::from_size_align_unchecked(size, mem::align_of::<usize>()),
+    );
+}
+
+/// 解析头部信息（不验证校验和）
+///
+/// # Safety
+///
+/// `encoded` 为 NUL 结尾的字符串，`header` 为有效的可写指针。
+#[no_mangle]
+pub unsafe extern "C" fn dx_inspect(encoded: *const c_char, header: *mut DxHeader) -> c_int {
+    if header.is_null() {
+        return DX_ERROR_INVALID_INPUT;
+    }
+    let encoded = try_ffi!(read_str(encoded));
+    let h = try_ffi!(STANDARD.get_header(encoded).map_err(|e| error_code(&e)));
+    *header = DxHeader {
+        flags: h.flags,
+        checksum: h.checksum,
+        compressed: h.compressed,
+        original_size: h.original_size.unwrap_or(0),
+        has_ttl: h.created_at.is_some(),
+        created_at: h.created_at.unwrap_or(0),
+        ttl_seconds: h.ttl_seconds.unwrap_or(0),
+        has_content_type: h.content_type.is_some(),
+        content_type: h.content_type.unwrap_or(0),
+        payload_len: h.payload_len,
+    };
+    DX_OK
+}
+
+/// 验证校验和，`*is_valid` 为是否匹配；格式错误时返回错误码
+///
+/// # Safety
+///
+/// `encoded` 为 NUL 结尾的字符串，`is_valid` 为有效的可写指针。
+#[no_mangle]
+pub unsafe extern "C" fn dx_verify(encoded: *const c_char, is_valid: *mut bool) -> c_int {
+    if is_valid.is_null() {
+        return DX_ERROR_INVALID_INPUT;
+    }
+    let encoded = try_ffi!(read_str(encoded));
+    *is_valid = try_ffi!(STANDARD.verify(encoded).map_err(|e| error_code(&e)));
+    DX_OK
+}
+
+/// 检查字符串是否为 DX 编码（仅检查前缀、长度和字符）
+///
+/// # Safety
+///
+/// `s` 为空指针或 NUL 结尾的字符串。
+#[no_mangle]
+pub unsafe extern "C" fn dx_is_encoded(s: *const c_char) -> bool {
+    read_str(s).is_ok_and(|s| STANDARD.is_encoded(s))
+}
+
+/// 获取 TTL 信息，不带 TTL 时 `info->has_ttl` 为 `false`
+///
+/// # Safety
+///
+/// `encoded` 为 NUL 结尾的字符串，`info` 为有效的可写指针。
+#[no_mangle]
+pub unsafe extern "C" fn dx_get_ttl_info(encoded: *const c_char, info: *mut DxTtlInfo) -> c_int {
+    if info.is_null() {
+        return DX_ERROR_INVALID_INPUT;
+    }
+    let encoded = try_ffi!(read_str(encoded));
+    let ttl = try_ffi!(STANDARD.get_ttl_info(encoded).map_err(|e| error_code(&e)));
+    *info = ttl.map_or_else(DxTtlInfo::default, |ttl| DxTtlInfo {
+        has_ttl: true,
+        created_at: ttl.created_at,
+        ttl_seconds: ttl.ttl_seconds,
+        expires_at: ttl.expires_at.unwrap_or(0),
+        is_expired: ttl.is_expired,
+    });
+    DX_OK
+}
+
+/// 计算 CRC16-CCITT 校验和
+///
+/// # Safety
+///
+/// `data` 指向至少 `len` 个可读字节（`len` 为 0 时可以为空）。
+#[no_mangle]
+pub unsafe extern "C" fn dx_crc16(data: *const u8, len: usize) -> u16 {
+    read_bytes(data, len).map_or(0, crate::crc16)
+}
+
+/// 错误码的英文描述（静态字符串，无需释放）
+#[no_mangle]
+pub extern "C" fn dx_error_string(code: c_int) -> *const c_char {
+    let s: &'static CStr = match code {
+        DX_OK => c"success",
+        DX_ERROR_INVALID_INPUT => c"invalid input",
+        DX_ERROR_INVALID_PREFIX => c"invalid DX encoding: missing dx prefix",
+        DX_ERROR_INVALID_LENGTH => c"invalid DX encoding: invalid length",
+        DX_ERROR_INVALID_CHARACTER => c"invalid DX encoding: invalid character",
+        DX_ERROR_BUFFER_TOO_SMALL => c"output buffer too small",
+        DX_ERROR_MEMORY => c"memory allocation failed",
+        DX_ERROR_INVALID_HEADER => c"invalid header",
+        DX_ERROR_INVALID_FLAGS => c"invalid flags byte",
+        DX_ERROR_CHECKSUM_MISMATCH => c"checksum mismatch",
+        DX_ERROR_COMPRESSION => c"compression error",
+        DX_ERROR_TTL_EXPIRED => c"TTL expired",
+        DX_ERROR_INVALID_UTF8 => c"invalid UTF-8",
+        DX_ERROR_INVALID_ALPHABET => c"invalid alphabet",
+        DX_ERROR_INVALID_CONTENT_TYPE => c"unexpected content type",
+        DX_ERROR_SERIALIZATION => c"serialization error",
+        DX_ERROR_TOKEN_TOO_LARGE => c"token too large",
+        DX_ERROR_INVALID_SOURCE => c"invalid source encoding",
+        DX_ERROR_INVALID_EXPIRY => c"invalid expiry",
+        DX_ERROR_INVALID_COOKIE => c"invalid cookie",
+        _ => c"unknown error",
+    };
+    s.as_ptr()
+}
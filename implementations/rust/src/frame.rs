@@ -0,0 +1,455 @@
+//! 二进制帧格式
+//!
+//! 帧结构：`[flags(1)] [CRC16(2)] [created_at(4) ttl(4)]? [content_type(1)]? [payload]`，
+//! 与具体的文本编码（字符集、前缀、填充）无关。
+
+use crate::{
+    compress_deflate, crc16, decompress_deflate, inflate_limited, DxError, Result, TtlInfo,
+};
+
+/// 基础头部大小（1字节 flags + 2字节 CRC16）
+pub(crate) const HEADER_SIZE: usize = 3;
+
+/// TTL 头部大小（4字节 created_at + 4字节 ttl_seconds）
+pub(crate) const TTL_HEADER_SIZE: usize = 8;
+
+/// 压缩阈值（字节数），小于此值不压缩
+pub(crate) const COMPRESSION_THRESHOLD: usize = 32;
+
+/// Flags 位定义
+pub(crate) const FLAG_COMPRESSED: u8 = 0x01;
+pub(crate) const FLAG_ALGO_DEFLATE: u8 = 0x02;
+pub(crate) const FLAG_HAS_TTL: u8 = 0x04;
+pub(crate) const FLAG_CONTENT_TYPE: u8 = 0x08;
+
+/// 有效的 flags 掩码（用于验证）
+pub(crate) const VALID_FLAGS_MASK: u8 =
+    FLAG_COMPRESSED | FLAG_ALGO_DEFLATE | FLAG_HAS_TTL | FLAG_CONTENT_TYPE;
+
+/// 内容类型：postcard 序列化的 serde 数据
+#[cfg_attr(not(feature = "serde"), allow(dead_code))]
+pub(crate) const CONTENT_TYPE_POSTCARD: u8 = 0x01;
+
+/// 构建帧
+///
+/// `ttl` 为 `(created_at, ttl_seconds)`，`None` 表示不带 TTL 头部；
+/// `content_type` 为 `None` 时不写入内容类型字节
+pub(crate) fn build(
+    data: &[u8],
+    allow_compression: bool,
+    ttl: Option<(u32, u32)>,
+    content_type: Option<u8>,
+) -> Vec<u8> {
+    // 计算原始数据的 CRC16
+    let checksum = crc16(data);
+
+    // 压缩失败时按未压缩处理
+    let compressed = if allow_compression && data.len() >= COMPRESSION_THRESHOLD {
+        compress_deflate(data).ok()
+    } else {
+        None
+    };
+
+    assemble(data, checksum, compressed.as_deref(), ttl, content_type)
+}
+
+/// 由已计算好的校验和与压缩数据组装帧（用于增量编码）
+///
+/// `compressed` 为 `data` 的 DEFLATE 压缩结果，只有在有收益时才会使用
+pub(crate) fn assemble(
+    data: &[u8],
+    checksum: u16,
+    compressed: Option<&[u8]>,
+    ttl: Option<(u32, u32)>,
+    content_type: Option<u8>,
+) -> Vec<u8> {
+    // 决定是否压缩
+    let (mut flags, payload) = match compressed {
+        // 压缩后需要额外存储 2 字节原始大小
+        // 只有当压缩后的大小 + 2 < 原始大小时才使用压缩
+        Some(compressed) if compressed.len() + 2 < data.len() && data.len() <= 65535 => {
+            let mut payload = Vec::with_capacity(2 + compressed.len());
+            // 存储原始大小（大端序）
+            payload.extend_from_slice(&(data.len() as u16).to_be_bytes());
+            payload.extend_from_slice(compressed);
+            (FLAG_COMPRESSED | FLAG_ALGO_DEFLATE, payload)
+        }
+        // 压缩无收益，使用原始数据
+        _ => (0u8, data.to_vec()),
+    };
+
+    if ttl.is_some() {
+        flags |= FLAG_HAS_TTL;
+    }
+    if content_type.is_some() {
+        flags |= FLAG_CONTENT_TYPE;
+    }
+
+    let mut frame = Vec::with_capacity(HEADER_SIZE + TTL_HEADER_SIZE + 1 + payload.len());
+    frame.push(flags);
+    frame.extend_from_slice(&checksum.to_be_bytes());
+    if let Some((created_at, ttl_seconds)) = ttl {
+        frame.extend_from_slice(&created_at.to_be_bytes());
+        frame.extend_from_slice(&ttl_seconds.to_be_bytes());
+    }
+    frame.extend(content_type);
+    frame.extend_from_slice(&payload);
+    frame
+}
+
+/// DX 编码的头部信息
+///
+/// 由 [`Engine::get_header`](crate::Engine::get_header) 解析，不验证校验和。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct Header {
+    /// flags 字节
+    pub flags: u8,
+    /// 存储的 CRC16 校验和
+    pub checksum: u16,
+    /// 是否已压缩
+    pub compressed: bool,
+    /// 压缩前的原始大小（仅压缩数据记录）
+    pub original_size: Option<u16>,
+    /// 创建时间（Unix 时间戳，秒），不带 TTL 时为 `None`
+    pub created_at: Option<u64>,
+    /// 有效期（秒），不带 TTL 时为 `None`，`Some(0)` 表示永不过期
+    pub ttl_seconds: Option<u32>,
+    /// 内容类型，未标记时为 `None`
+    pub content_type: Option<u8>,
+    /// 存储的数据部分字节数（压缩数据包含 2 字节原始大小）
+    pub payload_len: usize,
+}
+
+#[cfg(feature = "serde")]
+impl ::serde::Serialize for Header {
+    fn serialize<S: ::serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use ::serde::ser::SerializeStruct;
+
+        let mut s = serializer.serialize_struct("Header", 8)?;
+        s.serialize_field("flags", &self.flags)?;
+        s.serialize_field("checksum", &self.checksum)?;
+        s.serialize_field("compressed", &self.compressed)?;
+        s.serialize_field("original_size", &self.original_size)?;
+        s.serialize_field("created_at", &self.created_at)?;
+        s.serialize_field("ttl_seconds", &self.ttl_seconds)?;
+        s.serialize_field("content_type", &self.content_type)?;
+        s.serialize_field("payload_len", &self.payload_len)?;
+        s.end()
+    }
+}
+
+/// flags 位的名称
+const FLAG_NAMES: [(u8, &str); 4] = [
+    (FLAG_COMPRESSED, "compressed"),
+    (FLAG_ALGO_DEFLATE, "deflate"),
+    (FLAG_HAS_TTL, "ttl"),
+    (FLAG_CONTENT_TYPE, "content_type"),
+];
+
+/// DX 编码的完整结构
+///
+/// 由 [`Engine::inspect`](crate::Engine::inspect) 解析。与 [`Header`] 不同，flags 无效、
+/// 头部不完整或校验和不匹配时仍然尽可能解析各个字段，遇到的错误记录在 `error` 中。
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct Inspection {
+    /// 解码后的原始帧（头部和数据部分）
+    pub frame: Vec<u8>,
+    /// flags 字节，帧为空时为 `None`
+    pub flags: Option<u8>,
+    /// 存储的 CRC16 校验和，帧不足 3 字节时为 `None`
+    pub stored_checksum: Option<u16>,
+    /// 根据原始数据计算的 CRC16，无法还原数据时为 `None`
+    pub computed_checksum: Option<u16>,
+    /// 创建时间（Unix 时间戳，秒），不带 TTL 时为 `None`
+    pub created_at: Option<u64>,
+    /// 有效期（秒），不带 TTL 时为 `None`
+    pub ttl_seconds: Option<u32>,
+    /// 内容类型，未标记时为 `None`
+    pub content_type: Option<u8>,
+    /// 压缩数据记录的原始大小
+    pub declared_size: Option<u16>,
+    /// 数据部分在帧中的起始位置
+    pub payload_offset: usize,
+    /// 还原（必要时解压缩）后的原始数据，无法还原时为 `None`
+    pub data: Option<Vec<u8>>,
+    /// 解码时会返回的错误（不检查 TTL），结构有效时为 `None`
+    pub error: Option<DxError>,
+}
+
+impl Inspection {
+    /// 宽松地解析帧
+    pub(crate) fn parse(frame: Vec<u8>) -> Inspection {
+        let error = Frame::parse(&frame).and_then(|f| f.verified_data()).err();
+        let flags = frame.first().copied();
+        let bits = flags.unwrap_or(0);
+        let stored_checksum = frame
+            .get(1..HEADER_SIZE)
+            .map(|c| u16::from_be_bytes([c[0], c[1]]));
+
+        let mut offset = HEADER_SIZE.min(frame.len());
+        let mut ttl = None;
+        if bits & FLAG_HAS_TTL != 0 {
+            match frame.get(offset..offset + TTL_HEADER_SIZE) {
+                Some(t) => {
+                    let created_at = u32::from_be_bytes([t[0], t[1], t[2], t[3]]) as u64;
+                    ttl = Some((created_at, u32::from_be_bytes([t[4], t[5], t[6], t[7]])));
+                    offset += TTL_HEADER_SIZE;
+                }
+                None => offset = frame.len(),
+            }
+        }
+        let mut content_type = None;
+        if bits & FLAG_CONTENT_TYPE != 0 {
+            content_type = frame.get(offset).copied();
+            offset = (offset + 1).min(frame.len());
+        }
+
+        let payload = &frame[offset..];
+        let (declared_size, data) = if bits & FLAG_COMPRESSED != 0 {
+            match payload {
+                // 多解压 1 字节，以便显示与记录的大小不符
+                [hi, lo, deflated @ ..] => (
+                    Some(u16::from_be_bytes([*hi, *lo])),
+                    inflate_limited(deflated, u16::MAX as usize + 1).ok(),
+                ),
+                _ => (None, None),
+            }
+        } else {
+            (None, Some(payload.to_vec()))
+        };
+        let computed_checksum = stored_checksum.and(data.as_deref()).map(crc16);
+
+        Inspection {
+            flags,
+            stored_checksum,
+            computed_checksum,
+            created_at: ttl.map(|(created_at, _)| created_at),
+            ttl_seconds: ttl.map(|(_, ttl_seconds)| ttl_seconds),
+            content_type,
+            declared_size,
+            payload_offset: offset,
+            data,
+            error,
+            frame,
+        }
+    }
+
+    /// 存储的数据部分（压缩数据包含 2 字节原始大小）
+    pub fn payload(&self) -> &[u8] {
+        &self.frame[self.payload_offset..]
+    }
+
+    /// 已设置的 flags 位名称（`compressed`、`deflate`、`ttl`、`content_type`）
+    pub fn flag_names(&self) -> Vec<&'static str> {
+        let flags = self.flags.unwrap_or(0);
+        FLAG_NAMES
+            .iter()
+            .filter(|(bit, _)| flags & bit != 0)
+            .map(|(_, name)| *name)
+            .collect()
+    }
+
+    /// 未定义的 flags 位
+    pub fn unknown_flags(&self) -> u8 {
+        self.flags.unwrap_or(0) & !VALID_FLAGS_MASK
+    }
+
+    /// 存储的校验和与计算结果是否一致，无法计算时为 `None`
+    pub fn checksum_matches(&self) -> Option<bool> {
+        Some(self.stored_checksum? == self.computed_checksum?)
+    }
+
+    /// 过期时间（Unix 时间戳，秒），不带 TTL 或永不过期时为 `None`
+    pub fn expires_at(&self) -> Option<u64> {
+        match (self.created_at, self.ttl_seconds) {
+            (Some(created_at), Some(ttl_seconds)) if ttl_seconds > 0 => {
+                Some(created_at + ttl_seconds as u64)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// 解析后的帧
+pub(crate) struct Frame<'a> {
+    pub flags: u8,
+    pub checksum: u16,
+    /// `(created_at, ttl_seconds)`
+    pub ttl: Option<(u64, u32)>,
+    pub content_type: Option<u8>,
+    pub payload: &'a [u8],
+}
+
+impl<'a> Frame<'a> {
+    /// 解析帧头部（验证 flags 和长度，不验证校验和）
+    pub fn parse(bytes: &'a [u8]) -> Result<Frame<'a>> {
+        // 验证长度
+        if bytes.len() < HEADER_SIZE {
+            return Err(DxError::InvalidHeader {
+                expected: HEADER_SIZE,
+                actual: bytes.len(),
+            });
+        }
+
+        let flags = bytes[0];
+        let checksum = u16::from_be_bytes([bytes[1], bytes[2]]);
+
+        // 验证 flags 的保留位
+        if flags & !VALID_FLAGS_MASK != 0 {
+            return Err(DxError::InvalidFlags(flags));
+        }
+
+        let (ttl, mut payload_start) = if flags & FLAG_HAS_TTL != 0 {
+            // 验证有 TTL 头部的最小长度
+            if bytes.len() < HEADER_SIZE + TTL_HEADER_SIZE {
+                return Err(DxError::InvalidHeader {
+                    expected: HEADER_SIZE + TTL_HEADER_SIZE,
+                    actual: bytes.len(),
+                });
+            }
+            let t = &bytes[HEADER_SIZE..HEADER_SIZE + TTL_HEADER_SIZE];
+            let created_at = u32::from_be_bytes([t[0], t[1], t[2], t[3]]) as u64;
+            let ttl_seconds = u32::from_be_bytes([t[4], t[5], t[6], t[7]]);
+            (
+                Some((created_at, ttl_seconds)),
+                HEADER_SIZE + TTL_HEADER_SIZE,
+            )
+        } else {
+            (None, HEADER_SIZE)
+        };
+
+        let content_type = if flags & FLAG_CONTENT_TYPE != 0 {
+            let content_type = *bytes.get(payload_start).ok_or(DxError::InvalidHeader {
+                expected: payload_start + 1,
+                actual: bytes.len(),
+            })?;
+            payload_start += 1;
+            Some(content_type)
+        } else {
+            None
+        };
+
+        let payload = &bytes[payload_start..];
+
+        // 压缩数据至少包含 2 字节原始大小
+        if flags & FLAG_COMPRESSED != 0 && payload.len() < 2 {
+            return Err(DxError::InvalidHeader {
+                expected: payload_start + 2,
+                actual: bytes.len(),
+            });
+        }
+
+        Ok(Frame {
+            flags,
+            checksum,
+            ttl,
+            content_type,
+            payload,
+        })
+    }
+
+    /// 头部信息
+    pub fn header(&self) -> Header {
+        let compressed = self.is_compressed();
+        Header {
+            flags: self.flags,
+            checksum: self.checksum,
+            compressed,
+            original_size: compressed
+                .then(|| u16::from_be_bytes([self.payload[0], self.payload[1]])),
+            created_at: self.ttl.map(|(created_at, _)| created_at),
+            ttl_seconds: self.ttl.map(|(_, ttl_seconds)| ttl_seconds),
+            content_type: self.content_type,
+            payload_len: self.payload.len(),
+        }
+    }
+
+    /// 是否已压缩
+    pub fn is_compressed(&self) -> bool {
+        self.flags & FLAG_COMPRESSED != 0
+    }
+
+    /// 过期时间（Unix 时间戳，秒），`None` 表示不带 TTL 或永不过期
+    pub fn expires_at(&self) -> Option<u64> {
+        match self.ttl {
+            Some((created_at, ttl_seconds)) if ttl_seconds > 0 => {
+                Some(created_at + ttl_seconds as u64)
+            }
+            _ => None,
+        }
+    }
+
+    /// TTL 信息，不带 TTL 时返回 `None`
+    pub fn ttl_info(&self, now: u64) -> Option<TtlInfo> {
+        let (created_at, ttl_seconds) = self.ttl?;
+        let expires_at = self.expires_at();
+        Some(TtlInfo {
+            created_at,
+            ttl_seconds,
+            expires_at,
+            is_expired: expires_at.is_some_and(|expires| now > expires),
+        })
+    }
+
+    /// 检查 TTL 是否已过期
+    pub fn check_ttl(&self, now: u64) -> Result<()> {
+        if let (Some((created_at, ttl_seconds)), Some(expires_at)) = (self.ttl, self.expires_at()) {
+            if now > expires_at {
+                return Err(DxError::TtlExpired {
+                    created_at,
+                    ttl_seconds,
+                    expired_at: expires_at,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// 替换 TTL 头部后重新组装帧，数据部分（包括压缩方式）、校验和与内容类型保持不变
+    pub fn with_ttl(&self, ttl: Option<(u32, u32)>) -> Vec<u8> {
+        let mut frame = Vec::with_capacity(HEADER_SIZE + TTL_HEADER_SIZE + 1 + self.payload.len());
+        let flags = self.flags & !FLAG_HAS_TTL;
+        frame.push(if ttl.is_some() {
+            flags | FLAG_HAS_TTL
+        } else {
+            flags
+        });
+        frame.extend_from_slice(&self.checksum.to_be_bytes());
+        if let Some((created_at, ttl_seconds)) = ttl {
+            frame.extend_from_slice(&created_at.to_be_bytes());
+            frame.extend_from_slice(&ttl_seconds.to_be_bytes());
+        }
+        frame.extend(self.content_type);
+        frame.extend_from_slice(self.payload);
+        frame
+    }
+
+    /// 还原原始数据（必要时解压缩，不验证校验和）
+    pub fn original_data(&self) -> Result<Vec<u8>> {
+        if self.is_compressed() {
+            // 前 2 字节为原始大小
+            let declared = u16::from_be_bytes([self.payload[0], self.payload[1]]);
+            decompress_deflate(&self.payload[2..], declared as usize)
+        } else {
+            Ok(self.payload.to_vec())
+        }
+    }
+
+    /// 还原原始数据并验证校验和
+    pub fn verified_data(&self) -> Result<Vec<u8>> {
+        let data = self.original_data()?;
+        let actual = crc16(&data);
+        if actual != self.checksum {
+            return Err(DxError::ChecksumMismatch {
+                expected: self.checksum,
+                actual,
+            });
+        }
+        Ok(data)
+    }
+}
@@ -26,20 +26,64 @@
 //!
 //! # 版本
 //!
-//! 2.3.0
+//! 3.0.0（编码格式 2.3）
 //!
 //! # 许可证
 //!
 //! MIT
 
+mod alphabet;
+#[cfg(feature = "axum")]
+pub mod axum;
+mod clock;
+mod convert;
+mod duration;
+pub mod dx32;
+mod engine;
+mod error;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+mod frame;
+mod locale;
+#[cfg(feature = "python")]
+pub mod python;
+mod scan;
+#[cfg(feature = "serde")]
+pub mod serde;
+pub mod session;
+#[cfg(feature = "tokio")]
+pub mod stream;
+mod string;
+#[cfg(feature = "json")]
+mod transform;
+pub mod vectors;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
 use flate2::read::DeflateDecoder;
 use flate2::write::DeflateEncoder;
 use flate2::Compression;
-use std::collections::HashMap;
-use std::error::Error;
-use std::fmt;
 use std::io::{Read, Write};
-use std::sync::LazyLock;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+#[cfg(feature = "serde")]
+pub use self::serde::{from_dx_str, to_dx_string, to_dx_string_with_ttl};
+pub use alphabet::{Alphabet, AlphabetError};
+pub use clock::Clock;
+pub use convert::Encoding;
+pub use duration::{format_duration, parse_duration, DurationError};
+pub use engine::{DecodePadding, Engine, LineEnding, ReissuePolicy, STANDARD};
+pub use error::{DxError, ErrorKind, Result};
+pub use frame::{Header, Inspection};
+pub use locale::{Locale, Localized};
+pub use scan::{FindTokens, Match};
+pub use string::{DxStr, DxString};
+#[cfg(feature = "json")]
+pub use transform::{
+    transform_json, transform_json_with, FieldOp, Segment, Selector, TransformError,
+};
+
+use frame::COMPRESSION_THRESHOLD;
 
 /// DX 字符集 - 以 DXdx 开头作为签名，共64个字符
 pub const CHARSET: &str = "DXdx0123456789ABCEFGHIJKLMNOPQRSTUVWYZabcefghijklmnopqrstuvwyz-_";
@@ -53,270 +97,106 @@ pub const PREFIX: &str = "dx";
 /// 填充字符
 pub const PADDING: char = '=';
 
-/// 基础头部大小（1字节 flags + 2字节 CRC16）
-const HEADER_SIZE: usize = 3;
-
-/// TTL 头部大小（4字节 created_at + 4字节 ttl_seconds）
-const TTL_HEADER_SIZE: usize = 8;
-
-/// 压缩阈值（字节数），小于此值不压缩
-const COMPRESSION_THRESHOLD: usize = 32;
-
-/// Flags 位定义
-const FLAG_COMPRESSED: u8 = 0x01;
-const FLAG_ALGO_DEFLATE: u8 = 0x02;
-const FLAG_HAS_TTL: u8 = 0x04;
-
-/// 有效的 flags 掩码（用于验证）
-const VALID_FLAGS_MASK: u8 = FLAG_COMPRESSED | FLAG_ALGO_DEFLATE | FLAG_HAS_TTL;
-
-/// 字符集字节数组
-static CHARSET_BYTES: LazyLock<Vec<u8>> = LazyLock::new(|| CHARSET.as_bytes().to_vec());
-
-/// 反向查找表
-static DECODE_MAP: LazyLock<HashMap<u8, u8>> = LazyLock::new(|| {
-    let mut map = HashMap::new();
-    for (i, &byte) in CHARSET_BYTES.iter().enumerate() {
-        map.insert(byte, i as u8);
-    }
-    map
-});
-
 /// CRC16 查找表 (CRC-16-CCITT)
-static CRC16_TABLE: LazyLock<[u16; 256]> = LazyLock::new(|| {
+const CRC16_TABLE: [u16; 256] = {
     let mut table = [0u16; 256];
-    for i in 0..256 {
+    let mut i = 0;
+    while i < 256 {
         let mut crc = (i as u16) << 8;
-        for _ in 0..8 {
+        let mut bit = 0;
+        while bit < 8 {
             if crc & 0x8000 != 0 {
                 crc = (crc << 1) ^ 0x1021;
             } else {
                 crc <<= 1;
             }
+            bit += 1;
         }
         table[i] = crc;
+        i += 1;
     }
     table
-});
-
-/// DX 编码错误类型
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub enum DxError {
-    /// 缺少 dx 前缀
-    InvalidPrefix,
-    /// 长度不正确
-    InvalidLength,
-    /// 包含非法字符
-    InvalidCharacter(char),
-    /// UTF-8 解码错误
-    Utf8Error(String),
-    /// 校验和不匹配
-    ChecksumMismatch { expected: u16, actual: u16 },
-    /// 头部无效
-    InvalidHeader,
-    /// 压缩/解压缩错误
-    CompressionError(String),
-    /// 无效的 flags
-    InvalidFlags(u8),
-    /// TTL 已过期
-    TtlExpired {
-        created_at: u64,
-        ttl_seconds: u32,
-        expired_at: u64,
-    },
+};
+
+/// 计算 CRC16-CCITT 校验和
+pub fn crc16(data: &[u8]) -> u16 {
+    let mut crc = Crc16::new();
+    crc.update(data);
+    crc.finish()
 }
 
-impl fmt::Display for DxError {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            DxError::InvalidPrefix => write!(f, "无效的 DX 编码：缺少 dx 前缀"),
-            DxError::InvalidLength => write!(f, "无效的 DX 编码：长度不正确"),
-            DxError::InvalidCharacter(c) => write!(f, "无效的 DX 编码：包含非法字符 '{}'", c),
-            DxError::Utf8Error(s) => write!(f, "UTF-8 解码错误：{}", s),
-            DxError::ChecksumMismatch { expected, actual } => {
-                write!(
-                    f,
-                    "校验和不匹配：期望 0x{:04X}，实际 0x{:04X}",
-                    expected, actual
-                )
-            }
-            DxError::InvalidHeader => write!(f, "无效的格式头部"),
-            DxError::CompressionError(s) => write!(f, "压缩/解压缩错误：{}", s),
-            DxError::InvalidFlags(flags) => write!(f, "无效的 flags 字节：0x{:02X}", flags),
-            DxError::TtlExpired {
-                created_at,
-                ttl_seconds,
-                expired_at,
-            } => {
-                write!(
-                    f,
-                    "TTL 已过期：创建于 {}，有效期 {} 秒，已于 {} 过期",
-                    created_at, ttl_seconds, expired_at
-                )
-            }
-        }
-    }
+/// 增量计算 CRC16-CCITT 校验和
+///
+/// # 示例
+///
+/// ```
+/// use dxcode::{crc16, Crc16};
+///
+/// let mut crc = Crc16::new();
+/// crc.update(b"Hello, ");
+/// crc.update(b"Dogxi!");
+/// assert_eq!(crc.finish(), crc16(b"Hello, Dogxi!"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Crc16 {
+    crc: u16,
 }
 
+impl Crc16 {
+    /// 初始值为 0xFFFF
+    pub const fn new() -> Crc16 {
+        Crc16 { crc: 0xFFFF }
+    }
 
-impl Error for DxError {}
+    /// 追加数据
+    pub fn update(&mut self, data: &[u8]) {
+        for &byte in data {
+            let index = ((self.crc >> 8) ^ (byte as u16)) as usize;
+            self.crc = (self.crc << 8) ^ CRC16_TABLE[index];
+        }
+    }
 
-/// DX 编码结果类型
-pub type Result<T> = std::result::Result<T, DxError>;
+    /// 当前已追加数据的校验和
+    pub fn finish(&self) -> u16 {
+        self.crc
+    }
+}
 
-/// 计算 CRC16-CCITT 校验和
-pub fn crc16(data: &[u8]) -> u16 {
-    let mut crc: u16 = 0xFFFF;
-    for &byte in data {
-        let index = ((crc >> 8) ^ (byte as u16)) as usize;
-        crc = (crc << 8) ^ CRC16_TABLE[index];
+impl Default for Crc16 {
+    fn default() -> Self {
+        Crc16::new()
     }
-    crc
 }
 
 /// 使用 DEFLATE 压缩数据
 fn compress_deflate(data: &[u8]) -> Result<Vec<u8>> {
     let mut encoder = DeflateEncoder::new(Vec::new(), Compression::default());
-    encoder
-        .write_all(data)
-        .map_err(|e| DxError::CompressionError(e.to_string()))?;
-    encoder
-        .finish()
-        .map_err(|e| DxError::CompressionError(e.to_string()))
+    encoder.write_all(data).map_err(DxError::from)?;
+    encoder.finish().map_err(DxError::from)
 }
 
-/// 使用 DEFLATE 解压缩数据
-fn decompress_deflate(data: &[u8]) -> Result<Vec<u8>> {
-    let mut decoder = DeflateDecoder::new(data);
+/// 使用 DEFLATE 解压缩数据，最多输出 `limit` 字节，超出的部分被截断
+///
+/// 压缩数据最多记录 65535 字节的原始大小，上限可以防止小的输入解压出巨大的数据
+fn inflate_limited(data: &[u8], limit: usize) -> Result<Vec<u8>> {
+    let mut decoder = DeflateDecoder::new(data).take(limit as u64);
     let mut decompressed = Vec::new();
     decoder
         .read_to_end(&mut decompressed)
-        .map_err(|e| DxError::CompressionError(e.to_string()))?;
+        .map_err(DxError::from)?;
     Ok(decompressed)
 }
 
-/// 内部编码函数（不带前缀）
-fn encode_raw(data: &[u8]) -> String {
-    if data.is_empty() {
-        return String::new();
+/// 使用 DEFLATE 解压缩数据，解压后的大小必须等于头部记录的 `declared` 字节
+fn decompress_deflate(data: &[u8], declared: usize) -> Result<Vec<u8>> {
+    let decompressed = inflate_limited(data, declared + 1)?;
+    if decompressed.len() != declared {
+        return Err(DxError::from(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            "decompressed size does not match the declared size",
+        )));
     }
-
-    let mut result = String::with_capacity((data.len() + 2) / 3 * 4);
-    let charset = &*CHARSET_BYTES;
-
-    // 每 3 字节处理一组
-    for chunk in data.chunks(3) {
-        let b0 = chunk[0];
-        let b1 = chunk.get(1).copied().unwrap_or(0);
-        let b2 = chunk.get(2).copied().unwrap_or(0);
-
-        // 将 3 字节（24位）分成 4 个 6 位组
-        let v0 = (b0 >> 2) & 0x3F;
-        let v1 = ((b0 & 0x03) << 4 | (b1 >> 4)) & 0x3F;
-        let v2 = ((b1 & 0x0F) << 2 | (b2 >> 6)) & 0x3F;
-        let v3 = b2 & 0x3F;
-
-        // XOR 变换并映射到字符
-        result.push(charset[((v0 ^ MAGIC) & 0x3F) as usize] as char);
-        result.push(charset[((v1 ^ MAGIC) & 0x3F) as usize] as char);
-
-        if chunk.len() > 1 {
-            result.push(charset[((v2 ^ MAGIC) & 0x3F) as usize] as char);
-        } else {
-            result.push(PADDING);
-        }
-
-        if chunk.len() > 2 {
-            result.push(charset[((v3 ^ MAGIC) & 0x3F) as usize] as char);
-        } else {
-            result.push(PADDING);
-        }
-    }
-
-    result
-}
-
-/// 内部解码函数（不带前缀验证）
-fn decode_raw(data: &str) -> Result<Vec<u8>> {
-    if data.is_empty() {
-        return Ok(Vec::new());
-    }
-
-    // 验证长度
-    if data.len() % 4 != 0 {
-        return Err(DxError::InvalidLength);
-    }
-
-    // 计算填充数量
-    let padding_count = if data.ends_with("==") {
-        2
-    } else if data.ends_with('=') {
-        1
-    } else {
-        0
-    };
-
-    // 计算输出长度
-    let output_len = (data.len() / 4) * 3 - padding_count;
-    let mut result = Vec::with_capacity(output_len);
-
-    let decode_map = &*DECODE_MAP;
-    let data_bytes = data.as_bytes();
-
-    // 每 4 字符处理一组
-    for chunk in data_bytes.chunks(4) {
-        let c0 = chunk[0];
-        let c1 = chunk[1];
-        let c2 = chunk[2];
-        let c3 = chunk[3];
-
-        // 字符转索引
-        let i0 = *decode_map
-            .get(&c0)
-            .ok_or_else(|| DxError::InvalidCharacter(c0 as char))?;
-        let i1 = *decode_map
-            .get(&c1)
-            .ok_or_else(|| DxError::InvalidCharacter(c1 as char))?;
-
-        let i2 = if c2 == PADDING as u8 {
-            0
-        } else {
-            *decode_map
-                .get(&c2)
-                .ok_or_else(|| DxError::InvalidCharacter(c2 as char))?
-        };
-
-        let i3 = if c3 == PADDING as u8 {
-            0
-        } else {
-            *decode_map
-                .get(&c3)
-                .ok_or_else(|| DxError::InvalidCharacter(c3 as char))?
-        };
-
-        // XOR 逆变换
-        let v0 = (i0 ^ MAGIC) & 0x3F;
-        let v1 = (i1 ^ MAGIC) & 0x3F;
-        let v2 = (i2 ^ MAGIC) & 0x3F;
-        let v3 = (i3 ^ MAGIC) & 0x3F;
-
-        // 重建字节
-        let b0 = (v0 << 2) | (v1 >> 4);
-        let b1 = ((v1 & 0x0F) << 4) | (v2 >> 2);
-        let b2 = ((v2 & 0x03) << 6) | v3;
-
-        if result.len() < output_len {
-            result.push(b0);
-        }
-        if result.len() < output_len {
-            result.push(b1);
-        }
-        if result.len() < output_len {
-            result.push(b2);
-        }
-    }
-
-    Ok(result)
+    Ok(decompressed)
 }
 
 /// 将字节切片编码为 DX 格式（带 CRC16 校验和和智能压缩）
@@ -352,52 +232,7 @@ pub fn encode(data: &[u8]) -> String {
 ///
 /// 以 'dx' 为前缀的编码字符串
 pub fn encode_with_options(data: &[u8], allow_compression: bool) -> String {
-    // 计算原始数据的 CRC16
-    let checksum = crc16(data);
-
-    // 决定是否压缩
-    let (flags, payload) = if allow_compression && data.len() >= COMPRESSION_THRESHOLD {
-        // 尝试压缩
-        match compress_deflate(data) {
-            Ok(compressed) => {
-                // 压缩后需要额外存储 2 字节原始大小
-                // 只有当压缩后的大小 + 2 < 原始大小时才使用压缩
-                if compressed.len() + 2 < data.len() && data.len() <= 65535 {
-                    // 使用压缩
-                    let mut payload = Vec::with_capacity(2 + compressed.len());
-                    // 存储原始大小（大端序）
-                    payload.push((data.len() >> 8) as u8);
-                    payload.push((data.len() & 0xFF) as u8);
-                    payload.extend_from_slice(&compressed);
-                    (FLAG_COMPRESSED | FLAG_ALGO_DEFLATE, payload)
-                } else {
-                    // 压缩无收益，使用原始数据
-                    (0u8, data.to_vec())
-                }
-            }
-            Err(_) => {
-                // 压缩失败，使用原始数据
-                (0u8, data.to_vec())
-            }
-        }
-    } else {
-        // 不压缩
-        (0u8, data.to_vec())
-    };
-
-    // 构建头部（1字节 flags + 2字节 CRC16，大端序）
-    let header = [flags, (checksum >> 8) as u8, (checksum & 0xFF) as u8];
-
-    // 合并头部和数据
-    let mut combined = Vec::with_capacity(HEADER_SIZE + payload.len());
-    combined.extend_from_slice(&header);
-    combined.extend_from_slice(&payload);
-
-    // 编码
-    let mut result = String::with_capacity(PREFIX.len() + (combined.len() + 2) / 3 * 4);
-    result.push_str(PREFIX);
-    result.push_str(&encode_raw(&combined));
-    result
+    STANDARD.encode_with_options(data, allow_compression)
 }
 
 /// 将字符串编码为 DX 格式（带 CRC16 校验和和智能压缩）
@@ -461,108 +296,7 @@ pub fn decode(encoded: &str) -> Result<Vec<u8>> {
 ///
 /// 解码后的字节数组，如果输入无效或校验和不匹配则返回错误
 pub fn decode_with_options(encoded: &str, check_ttl: bool) -> Result<Vec<u8>> {
-    // 验证前缀
-    if !encoded.starts_with(PREFIX) {
-        return Err(DxError::InvalidPrefix);
-    }
-
-    // 移除前缀
-    let data = &encoded[PREFIX.len()..];
-
-    // 解码
-    let combined = decode_raw(data)?;
-
-    // 验证长度
-    if combined.len() < HEADER_SIZE {
-        return Err(DxError::InvalidHeader);
-    }
-
-    // 提取头部
-    let flags = combined[0];
-    let expected_checksum = ((combined[1] as u16) << 8) | (combined[2] as u16);
-
-    // 验证 flags 的保留位
-    if flags & !VALID_FLAGS_MASK != 0 {
-        return Err(DxError::InvalidFlags(flags));
-    }
-
-    // 确定 payload 的起始位置
-    let payload_start = if flags & FLAG_HAS_TTL != 0 {
-        // 验证有 TTL 头部的最小长度
-        if combined.len() < HEADER_SIZE + TTL_HEADER_SIZE {
-            return Err(DxError::InvalidHeader);
-        }
-
-        // 如果需要检查 TTL
-        if check_ttl {
-            let created_at = u32::from_be_bytes([
-                combined[HEADER_SIZE],
-                combined[HEADER_SIZE + 1],
-                combined[HEADER_SIZE + 2],
-                combined[HEADER_SIZE + 3],
-            ]) as u64;
-
-            let ttl_seconds = u32::from_be_bytes([
-                combined[HEADER_SIZE + 4],
-                combined[HEADER_SIZE + 5],
-                combined[HEADER_SIZE + 6],
-                combined[HEADER_SIZE + 7],
-            ]);
-
-            // 检查是否过期
-            if ttl_seconds > 0 {
-                let expires_at = created_at + ttl_seconds as u64;
-                let now = std::time::SystemTime::now()
-                    .duration_since(std::time::UNIX_EPOCH)
-                    .map(|d| d.as_secs())
-                    .unwrap_or(0);
-
-                if now > expires_at {
-                    return Err(DxError::TtlExpired {
-                        created_at,
-                        ttl_seconds,
-                        expired_at: expires_at,
-                    });
-                }
-            }
-        }
-
-        HEADER_SIZE + TTL_HEADER_SIZE
-    } else {
-        HEADER_SIZE
-    };
-
-    // 提取数据部分
-    let payload = &combined[payload_start..];
-
-    // 根据 flags 决定是否需要解压缩
-    let original_data = if flags & FLAG_COMPRESSED != 0 {
-        // 数据已压缩，需要解压
-        if payload.len() < 2 {
-            return Err(DxError::InvalidHeader);
-        }
-
-        // 提取原始大小（用于验证）
-        let _original_size = ((payload[0] as usize) << 8) | (payload[1] as usize);
-
-        // 解压缩
-        let compressed_data = &payload[2..];
-        decompress_deflate(compressed_data)?
-    } else {
-        // 数据未压缩
-        payload.to_vec()
-    };
-
-    // 验证校验和（针对原始数据）
-    let actual_checksum = crc16(&original_data);
-    if expected_checksum != actual_checksum {
-        return Err(DxError::ChecksumMismatch {
-            expected: expected_checksum,
-            actual: actual_checksum,
-        });
-    }
-
-    Ok(original_data)
+    STANDARD.decode_with_options(encoded, check_ttl)
 }
 
 /// 将 DX 编码的字符串解码为字符串（带校验和验证，自动解压缩）
@@ -600,7 +334,7 @@ pub fn decode_str(encoded: &str) -> Result<String> {
 /// 解码后的字符串
 pub fn decode_str_with_options(encoded: &str, check_ttl: bool) -> Result<String> {
     let bytes = decode_with_options(encoded, check_ttl)?;
-    String::from_utf8(bytes).map_err(|e| DxError::Utf8Error(e.to_string()))
+    Ok(String::from_utf8(bytes)?)
 }
 
 /// 检查字符串是否为有效的 DX 编码
@@ -623,32 +357,7 @@ pub fn decode_str_with_options(encoded: &str, check_ttl: bool) -> Result<String>
 /// assert!(!is_encoded("hello"));
 /// ```
 pub fn is_encoded(s: &str) -> bool {
-    if !s.starts_with(PREFIX) {
-        return false;
-    }
-
-    let data = &s[PREFIX.len()..];
-
-    // 检查长度（至少需要头部）
-    if data.is_empty() || data.len() % 4 != 0 {
-        return false;
-    }
-
-    let decode_map = &*DECODE_MAP;
-
-    // 检查字符
-    for (i, c) in data.bytes().enumerate() {
-        if c == PADDING as u8 {
-            // 填充只能在末尾
-            if i < data.len() - 2 {
-                return false;
-            }
-        } else if !decode_map.contains_key(&c) {
-            return false;
-        }
-    }
-
-    true
+    STANDARD.is_encoded(s)
 }
 
 /// 验证 DX 编码的校验和（不返回解码数据）
@@ -670,11 +379,97 @@ pub fn is_encoded(s: &str) -> bool {
 /// assert!(verify(&encoded).unwrap());
 /// ```
 pub fn verify(encoded: &str) -> Result<bool> {
-    match decode(encoded) {
-        Ok(_) => Ok(true),
-        Err(DxError::ChecksumMismatch { .. }) => Ok(false),
-        Err(e) => Err(e),
-    }
+    STANDARD.verify(encoded)
+}
+
+/// 完整验证 DX 编码（不检查 TTL）
+///
+/// 与 [`is_encoded`] 只检查前缀和字符不同，会验证长度、头部和校验和，
+/// 通过验证的字符串一定可以解码（除非 TTL 过期）
+///
+/// # 示例
+///
+/// ```
+/// use dxcode::{encode_str, validate};
+///
+/// assert!(validate(&encode_str("Hello")).is_ok());
+/// assert!(validate("dxDX").is_err());
+/// ```
+pub fn validate(encoded: &str) -> Result<()> {
+    STANDARD.validate(encoded)
+}
+
+/// 解析 DX 编码的头部信息（不验证校验和）
+///
+/// # 示例
+///
+/// ```
+/// use dxcode::{encode_with_ttl, get_header};
+///
+/// let header = get_header(&encode_with_ttl(b"Hello", 60)).unwrap();
+/// assert!(!header.compressed);
+/// assert_eq!(header.ttl_seconds, Some(60));
+/// ```
+pub fn get_header(encoded: &str) -> Result<Header> {
+    STANDARD.get_header(encoded)
+}
+
+/// 解析 DX 编码的完整结构（flags 或校验和无效时同样解析）
+///
+/// # 示例
+///
+/// ```
+/// use dxcode::{encode, inspect};
+///
+/// let inspection = inspect(&encode(b"Hello")).unwrap();
+/// assert_eq!(inspection.payload(), b"Hello");
+/// assert_eq!(inspection.checksum_matches(), Some(true));
+/// assert!(inspection.error.is_none());
+/// ```
+pub fn inspect(encoded: &str) -> Result<Inspection> {
+    STANDARD.inspect(encoded)
+}
+
+/// 在文本中查找 DX 字符串
+///
+/// 只返回通过完整验证（长度、头部、校验和，不检查 TTL）的子串，
+/// `dxdiag` 这类普通单词不会被误报。
+///
+/// # 示例
+///
+/// ```
+/// use dxcode::{encode_str, find_tokens};
+///
+/// let log = format!("run dxdiag, token={}&next=1", encode_str("secret"));
+/// let found: Vec<_> = find_tokens(&log).collect();
+/// assert_eq!(found.len(), 1);
+/// assert_eq!(found[0].as_str(), encode_str("secret"));
+/// assert_eq!(found[0].start(), 18);
+/// ```
+pub fn find_tokens(text: &str) -> FindTokens<'_> {
+    STANDARD.find_tokens(text)
+}
+
+/// 将标准 base64 转换为 DX 编码
+///
+/// 与 [`encode`] 一样计算校验和并按需压缩；其他编码见 [`Encoding`]。
+///
+/// # 示例
+///
+/// ```
+/// use dxcode::{decode, from_base64, to_base64};
+///
+/// let encoded = from_base64("SGVsbG8=").unwrap();
+/// assert_eq!(decode(&encoded).unwrap(), b"Hello");
+/// assert_eq!(to_base64(&encoded).unwrap(), "SGVsbG8=");
+/// ```
+pub fn from_base64(input: &str) -> Result<String> {
+    STANDARD.from_base64(input)
+}
+
+/// 将 DX 编码转换为标准 base64（丢弃 TTL 等头部信息，已过期时返回错误）
+pub fn to_base64(encoded: &str) -> Result<String> {
+    STANDARD.to_base64(encoded)
 }
 
 /// 获取 DX 编码的校验和信息
@@ -697,43 +492,7 @@ pub fn verify(encoded: &str) -> Result<bool> {
 /// assert_eq!(stored, computed);
 /// ```
 pub fn get_checksum(encoded: &str) -> Result<(u16, u16)> {
-    // 验证前缀
-    if !encoded.starts_with(PREFIX) {
-        return Err(DxError::InvalidPrefix);
-    }
-
-    // 移除前缀
-    let data = &encoded[PREFIX.len()..];
-
-    // 解码
-    let combined = decode_raw(data)?;
-
-    // 验证长度
-    if combined.len() < HEADER_SIZE {
-        return Err(DxError::InvalidHeader);
-    }
-
-    // 提取 flags 和校验和
-    let flags = combined[0];
-    let stored = ((combined[1] as u16) << 8) | (combined[2] as u16);
-
-    // 提取数据部分
-    let payload = &combined[HEADER_SIZE..];
-
-    // 根据 flags 决定是否需要解压缩
-    let original_data = if flags & FLAG_COMPRESSED != 0 {
-        if payload.len() < 2 {
-            return Err(DxError::InvalidHeader);
-        }
-        let compressed_data = &payload[2..];
-        decompress_deflate(compressed_data)?
-    } else {
-        payload.to_vec()
-    };
-
-    let computed = crc16(&original_data);
-
-    Ok((stored, computed))
+    STANDARD.get_checksum(encoded)
 }
 
 /// 检查编码是否使用了压缩
@@ -758,25 +517,7 @@ pub fn get_checksum(encoded: &str) -> Result<(u16, u16)> {
 /// // 可能压缩也可能不压缩，取决于压缩效果
 /// ```
 pub fn is_compressed(encoded: &str) -> Result<bool> {
-    // 验证前缀
-    if !encoded.starts_with(PREFIX) {
-        return Err(DxError::InvalidPrefix);
-    }
-
-    // 移除前缀
-    let data = &encoded[PREFIX.len()..];
-
-    // 解码
-    let combined = decode_raw(data)?;
-
-    // 验证长度
-    if combined.len() < HEADER_SIZE {
-        return Err(DxError::InvalidHeader);
-    }
-
-    // 检查 flags
-    let flags = combined[0];
-    Ok(flags & FLAG_COMPRESSED != 0)
+    STANDARD.is_compressed(encoded)
 }
 
 /// TTL 信息
@@ -792,6 +533,25 @@ pub struct TtlInfo {
     pub is_expired: bool,
 }
 
+impl TtlInfo {
+    /// 过期时间，永不过期时返回 `None`
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use std::time::{Duration, UNIX_EPOCH};
+    /// use dxcode::{Clock, STANDARD};
+    ///
+    /// let engine = STANDARD.with_clock(Clock::Fixed(1_700_000_000));
+    /// let info = engine.get_ttl_info(&engine.encode_with_ttl(b"Hi", 60)).unwrap().unwrap();
+    /// assert_eq!(info.expires_at(), Some(UNIX_EPOCH + Duration::from_secs(1_700_000_060)));
+    /// ```
+    pub fn expires_at(&self) -> Option<SystemTime> {
+        self.expires_at
+            .map(|secs| UNIX_EPOCH + Duration::from_secs(secs))
+    }
+}
+
 /// 检查编码是否包含 TTL 信息
 ///
 /// # 参数
@@ -802,25 +562,7 @@ pub struct TtlInfo {
 ///
 /// 返回 `true` 如果包含 TTL，否则返回 `false`
 pub fn has_ttl(encoded: &str) -> Result<bool> {
-    // 验证前缀
-    if !encoded.starts_with(PREFIX) {
-        return Err(DxError::InvalidPrefix);
-    }
-
-    // 移除前缀
-    let data = &encoded[PREFIX.len()..];
-
-    // 解码
-    let combined = decode_raw(data)?;
-
-    // 验证长度
-    if combined.len() < HEADER_SIZE {
-        return Err(DxError::InvalidHeader);
-    }
-
-    // 检查 flags
-    let flags = combined[0];
-    Ok(flags & FLAG_HAS_TTL != 0)
+    STANDARD.has_ttl(encoded)
 }
 
 /// 获取编码的 TTL 信息
@@ -845,67 +587,7 @@ pub fn has_ttl(encoded: &str) -> Result<bool> {
 /// }
 /// ```
 pub fn get_ttl_info(encoded: &str) -> Result<Option<TtlInfo>> {
-    // 验证前缀
-    if !encoded.starts_with(PREFIX) {
-        return Err(DxError::InvalidPrefix);
-    }
-
-    // 移除前缀
-    let data = &encoded[PREFIX.len()..];
-
-    // 解码
-    let combined = decode_raw(data)?;
-
-    // 验证长度
-    if combined.len() < HEADER_SIZE {
-        return Err(DxError::InvalidHeader);
-    }
-
-    let flags = combined[0];
-
-    // 检查是否有 TTL
-    if flags & FLAG_HAS_TTL == 0 {
-        return Ok(None);
-    }
-
-    // 验证有 TTL 头部的最小长度
-    if combined.len() < HEADER_SIZE + TTL_HEADER_SIZE {
-        return Err(DxError::InvalidHeader);
-    }
-
-    // 提取 TTL 信息
-    let created_at = u32::from_be_bytes([
-        combined[HEADER_SIZE],
-        combined[HEADER_SIZE + 1],
-        combined[HEADER_SIZE + 2],
-        combined[HEADER_SIZE + 3],
-    ]) as u64;
-
-    let ttl_seconds = u32::from_be_bytes([
-        combined[HEADER_SIZE + 4],
-        combined[HEADER_SIZE + 5],
-        combined[HEADER_SIZE + 6],
-        combined[HEADER_SIZE + 7],
-    ]);
-
-    // 计算过期时间和状态
-    let (expires_at, is_expired) = if ttl_seconds == 0 {
-        (None, false)
-    } else {
-        let expires = created_at + ttl_seconds as u64;
-        let now = std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .map(|d| d.as_secs())
-            .unwrap_or(0);
-        (Some(expires), now > expires)
-    };
-
-    Ok(Some(TtlInfo {
-        created_at,
-        ttl_seconds,
-        expires_at,
-        is_expired,
-    }))
+    STANDARD.get_ttl_info(encoded)
 }
 
 /// 检查编码是否已过期
@@ -927,10 +609,7 @@ pub fn get_ttl_info(encoded: &str) -> Result<Option<TtlInfo>> {
 /// assert!(!is_expired(&encoded).unwrap()); // 还没过期
 /// ```
 pub fn is_expired(encoded: &str) -> Result<bool> {
-    match get_ttl_info(encoded)? {
-        Some(info) => Ok(info.is_expired),
-        None => Ok(false), // 没有 TTL 的数据永不过期
-    }
+    STANDARD.is_expired(encoded)
 }
 
 /// 使用 TTL 编码字节数据
@@ -968,53 +647,12 @@ pub fn encode_with_ttl(data: &[u8], ttl_seconds: u32) -> String {
 /// # 返回值
 ///
 /// 带有 TTL 的 DX 编码字符串
-pub fn encode_with_ttl_and_options(data: &[u8], ttl_seconds: u32, allow_compression: bool) -> String {
-    // 获取当前时间戳
-    let created_at = std::time::SystemTime::now()
-        .duration_since(std::time::UNIX_EPOCH)
-        .map(|d| d.as_secs() as u32)
-        .unwrap_or(0);
-
-    // 计算原始数据的 CRC16
-    let checksum = crc16(data);
-
-    // 决定是否压缩
-    let (mut flags, payload) = if allow_compression && data.len() >= COMPRESSION_THRESHOLD {
-        match compress_deflate(data) {
-            Ok(compressed) => {
-                if compressed.len() + 2 < data.len() && data.len() <= 65535 {
-                    let mut payload = Vec::with_capacity(2 + compressed.len());
-                    payload.push((data.len() >> 8) as u8);
-                    payload.push((data.len() & 0xFF) as u8);
-                    payload.extend_from_slice(&compressed);
-                    (FLAG_COMPRESSED | FLAG_ALGO_DEFLATE, payload)
-                } else {
-                    (0u8, data.to_vec())
-                }
-            }
-            Err(_) => (0u8, data.to_vec()),
-        }
-    } else {
-        (0u8, data.to_vec())
-    };
-
-    // 设置 TTL flag
-    flags |= FLAG_HAS_TTL;
-
-    // 构建完整数据：[flags(1)] [CRC16(2)] [created_at(4)] [ttl(4)] [payload]
-    let mut combined = Vec::with_capacity(HEADER_SIZE + TTL_HEADER_SIZE + payload.len());
-    combined.push(flags);
-    combined.push((checksum >> 8) as u8);
-    combined.push((checksum & 0xFF) as u8);
-    combined.extend_from_slice(&created_at.to_be_bytes());
-    combined.extend_from_slice(&ttl_seconds.to_be_bytes());
-    combined.extend_from_slice(&payload);
-
-    // 编码
-    let mut result = String::with_capacity(PREFIX.len() + (combined.len() + 2) / 3 * 4);
-    result.push_str(PREFIX);
-    result.push_str(&encode_raw(&combined));
-    result
+pub fn encode_with_ttl_and_options(
+    data: &[u8],
+    ttl_seconds: u32,
+    allow_compression: bool,
+) -> String {
+    STANDARD.encode_with_ttl_and_options(data, ttl_seconds, allow_compression)
 }
 
 /// 使用 TTL 编码字符串
@@ -1040,6 +678,29 @@ pub fn encode_str_with_ttl(s: &str, ttl_seconds: u32) -> String {
     encode_with_ttl(s.as_bytes(), ttl_seconds)
 }
 
+/// 重新签发 DX 编码，按策略延长、重设或移除 TTL
+///
+/// 数据、压缩方式和创建时间保持不变，已过期的编码返回 [`DxError::TtlExpired`]，
+/// 需要续签已过期的编码时使用 [`Engine::reissue_with_options`]。
+///
+/// # 示例
+///
+/// ```
+/// use std::time::Duration;
+/// use dxcode::{encode_with_ttl, get_ttl_info, reissue, ReissuePolicy};
+///
+/// let token = encode_with_ttl(b"session", 3600);
+/// let renewed = reissue(&token, ReissuePolicy::Extend(Duration::from_secs(1800))).unwrap();
+/// let info = get_ttl_info(&renewed).unwrap().unwrap();
+/// assert_eq!(info.ttl_seconds, 5400);
+///
+/// let forever = reissue(&renewed, ReissuePolicy::RemoveTtl).unwrap();
+/// assert!(get_ttl_info(&forever).unwrap().is_none());
+/// ```
+pub fn reissue(encoded: &str, policy: ReissuePolicy) -> Result<String> {
+    STANDARD.reissue(encoded, policy)
+}
+
 /// DX 编码信息
 #[derive(Debug, Clone)]
 pub struct Info {
@@ -1085,6 +746,41 @@ pub fn get_info() -> Info {
     }
 }
 
+#[cfg(feature = "serde")]
+mod serde_impls {
+    use ::serde::ser::{Serialize, SerializeStruct, Serializer};
+
+    use super::{Info, TtlInfo};
+
+    impl Serialize for TtlInfo {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut s = serializer.serialize_struct("TtlInfo", 4)?;
+            s.serialize_field("created_at", &self.created_at)?;
+            s.serialize_field("ttl_seconds", &self.ttl_seconds)?;
+            s.serialize_field("expires_at", &self.expires_at)?;
+            s.serialize_field("is_expired", &self.is_expired)?;
+            s.end()
+        }
+    }
+
+    impl Serialize for Info {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut s = serializer.serialize_struct("Info", 10)?;
+            s.serialize_field("name", self.name)?;
+            s.serialize_field("version", self.version)?;
+            s.serialize_field("author", self.author)?;
+            s.serialize_field("charset", self.charset)?;
+            s.serialize_field("prefix", self.prefix)?;
+            s.serialize_field("magic", &self.magic)?;
+            s.serialize_field("padding", &self.padding)?;
+            s.serialize_field("checksum", self.checksum)?;
+            s.serialize_field("compression", self.compression)?;
+            s.serialize_field("compression_threshold", &self.compression_threshold)?;
+            s.end()
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1154,8 +850,85 @@ mod tests {
 
     #[test]
     fn test_decode_invalid_length() {
-        let result = decode("dxABC");
-        assert!(matches!(result, Err(DxError::InvalidLength)));
+        // 余 1 个字符无法组成一个字节
+        let result = decode("dxABCDE");
+        assert!(matches!(
+            result,
+            Err(DxError::InvalidLength {
+                expected: 6,
+                actual: 5
+            })
+        ));
+
+        // 填充后长度必须是 4 的倍数
+        let result = decode("dxABCDE=");
+        assert!(matches!(
+            result,
+            Err(DxError::InvalidLength {
+                expected: 8,
+                actual: 6
+            })
+        ));
+    }
+
+    // ========== 填充测试 ==========
+
+    #[test]
+    fn test_no_padding_roundtrip() {
+        let engine = STANDARD.with_encode_padding(false);
+        for length in 0..100 {
+            let original: Vec<u8> = (0..length).map(|i| (i * 13 % 256) as u8).collect();
+            let encoded = engine.encode_with_options(&original, false);
+            assert!(!encoded.contains(PADDING));
+            assert!(is_encoded(&encoded));
+
+            // 默认引擎同时接受带填充和不带填充的输入
+            assert_eq!(decode(&encoded).unwrap(), original, "长度 {} 失败", length);
+            assert_eq!(
+                encoded,
+                encode_with_options(&original, false).trim_end_matches(PADDING)
+            );
+        }
+    }
+
+    #[test]
+    fn test_decode_padding_modes() {
+        let padded = encode(b"Hello");
+        let unpadded = STANDARD.with_encode_padding(false).encode(b"Hello");
+        assert_ne!(padded, unpadded);
+
+        let canonical = STANDARD.with_decode_padding(DecodePadding::RequireCanonical);
+        assert_eq!(canonical.decode(&padded).unwrap(), b"Hello");
+        assert!(matches!(
+            canonical.decode(&unpadded),
+            Err(DxError::InvalidLength {
+                expected: 12,
+                actual: 11
+            })
+        ));
+        assert!(!canonical.is_encoded(&unpadded));
+
+        let none = STANDARD.with_decode_padding(DecodePadding::RequireNone);
+        assert_eq!(none.decode(&unpadded).unwrap(), b"Hello");
+        assert!(matches!(
+            none.decode(&padded),
+            Err(DxError::InvalidCharacter { ch: PADDING, index }) if index == padded.len() - 1
+        ));
+    }
+
+    #[test]
+    fn test_padding_only_at_end() {
+        let encoded = encode(b"Hello");
+        let body = &encoded[PREFIX.len()..];
+        let misplaced = format!("{}={}", PREFIX, &body[..body.len() - 1]);
+        assert!(matches!(
+            decode(&misplaced),
+            Err(DxError::InvalidCharacter {
+                ch: PADDING,
+                index: 2
+            })
+        ));
+        assert!(!is_encoded(&misplaced));
     }
 
     #[test]
@@ -1188,7 +961,7 @@ mod tests {
         let result = decode(&modified);
         assert!(
             matches!(result, Err(DxError::ChecksumMismatch { .. }))
-                || matches!(result, Err(DxError::InvalidCharacter(_)))
+                || matches!(result, Err(DxError::InvalidCharacter { .. }))
         );
     }
 
@@ -1445,4 +1218,1481 @@ mod tests {
         let encoded = encode_with_ttl(b"Data", 86400); // 1天有效期
         assert!(!is_expired(&encoded).unwrap());
     }
+
+    #[test]
+    fn test_parse_duration() {
+        let secs = |s: &str| parse_duration(s).unwrap().as_secs();
+        assert_eq!(secs("90m"), 5_400);
+        assert_eq!(secs("1h30m"), 5_400);
+        assert_eq!(secs("1h 30m 5s"), 5_405);
+        assert_eq!(secs("7d"), 604_800);
+        assert_eq!(secs("0"), 0);
+        assert_eq!(secs("1d 2h 3m 4s"), 93_784);
+
+        assert_eq!(parse_duration(" "), Err(DurationError::Empty));
+        assert_eq!(
+            parse_duration("1h30"),
+            Err(DurationError::MissingUnit { index: 2 })
+        );
+        assert_eq!(
+            parse_duration("3w"),
+            Err(DurationError::InvalidCharacter { ch: 'w', index: 1 })
+        );
+        assert_eq!(
+            parse_duration("h"),
+            Err(DurationError::InvalidCharacter { ch: 'h', index: 0 })
+        );
+        assert_eq!(
+            parse_duration("2h2h"),
+            Err(DurationError::MisplacedUnit {
+                unit: 'h',
+                index: 3
+            })
+        );
+        assert_eq!(
+            parse_duration("30m1h"),
+            Err(DurationError::MisplacedUnit {
+                unit: 'h',
+                index: 4
+            })
+        );
+        assert_eq!(
+            parse_duration("99999999999999999999d"),
+            Err(DurationError::Overflow)
+        );
+        assert_eq!(
+            DurationError::MissingUnit { index: 2 }
+                .localized(Locale::ZhCn)
+                .to_string(),
+            "时长的位置 2 的数字缺少单位"
+        );
+    }
+
+    #[test]
+    fn test_format_duration() {
+        let format = |secs| format_duration(Duration::from_secs(secs));
+        assert_eq!(format(8_040), "2h 14m");
+        assert_eq!(format(8_059), "2h 14m");
+        assert_eq!(format(90_061), "1d 1h");
+        assert_eq!(format(3_601), "1h");
+        assert_eq!(format(65), "1m 5s");
+        assert_eq!(format(59), "59s");
+        assert_eq!(format_duration(Duration::from_millis(999)), "0s");
+        for secs in [1, 61, 3_660, 86_460, 1_000_000] {
+            assert!(parse_duration(&format(secs)).unwrap().as_secs() <= secs);
+        }
+    }
+
+    #[test]
+    fn test_ttl_info_expires_at() {
+        let engine = STANDARD.with_clock(Clock::Fixed(1_000));
+        let info = engine
+            .get_ttl_info(&engine.encode_with_ttl(b"Data", 60))
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            info.expires_at(),
+            Some(UNIX_EPOCH + Duration::from_secs(1_060))
+        );
+        let info = engine
+            .get_ttl_info(&engine.encode_with_ttl(b"Data", 0))
+            .unwrap()
+            .unwrap();
+        assert_eq!(info.expires_at(), None);
+    }
+
+    #[test]
+    fn test_reissue() {
+        let engine = STANDARD.with_clock(Clock::Fixed(1_000));
+        let data = "session ".repeat(20);
+        let token = engine.encode_with_ttl(data.as_bytes(), 60);
+        let extended = engine
+            .reissue(&token, ReissuePolicy::Extend(Duration::from_secs(3_600)))
+            .unwrap();
+        let info = engine.get_ttl_info(&extended).unwrap().unwrap();
+        assert_eq!((info.created_at, info.ttl_seconds), (1_000, 3_660));
+        assert!(engine.is_compressed(&extended).unwrap());
+        assert_eq!(engine.decode(&extended).unwrap(), data.as_bytes());
+
+        let reset = engine
+            .reissue(&extended, ReissuePolicy::ExpiresAt(1_100))
+            .unwrap();
+        let info = engine.get_ttl_info(&reset).unwrap().unwrap();
+        assert_eq!((info.created_at, info.expires_at), (1_000, Some(1_100)));
+
+        let removed = engine.reissue(&reset, ReissuePolicy::RemoveTtl).unwrap();
+        assert!(!engine.has_ttl(&removed).unwrap());
+        assert_eq!(removed, engine.encode(data.as_bytes()));
+
+        // 不带 TTL 的编码以当前时间为创建时间
+        let added = engine
+            .reissue(&removed, ReissuePolicy::ExpiresAt(1_500))
+            .unwrap();
+        let info = engine.get_ttl_info(&added).unwrap().unwrap();
+        assert_eq!((info.created_at, info.ttl_seconds), (1_000, 500));
+
+        // 延长对不带 TTL 或永不过期的编码无效
+        let extend = ReissuePolicy::Extend(Duration::from_secs(60));
+        assert_eq!(engine.reissue(&removed, extend).unwrap(), removed);
+        let forever = engine.encode_with_ttl(b"Data", 0);
+        assert_eq!(engine.reissue(&forever, extend).unwrap(), forever);
+    }
+
+    #[test]
+    fn test_reissue_errors() {
+        let engine = STANDARD.with_clock(Clock::Fixed(1_000));
+        let token = engine.encode_with_ttl(b"Data", 60);
+        let later = STANDARD.with_clock(Clock::Fixed(2_000));
+        let extend = ReissuePolicy::Extend(Duration::from_secs(3_600));
+        assert!(matches!(
+            later.reissue(&token, extend),
+            Err(DxError::TtlExpired { .. })
+        ));
+        let renewed = later.reissue_with_options(&token, extend, false).unwrap();
+        assert!(!later.is_expired(&renewed).unwrap());
+
+        assert!(matches!(
+            engine.reissue(&token, ReissuePolicy::ExpiresAt(1_000)),
+            Err(DxError::InvalidExpiry {
+                created_at: 1_000,
+                expires_at: 1_000
+            })
+        ));
+        // 过期时间已过时不签发，即使晚于创建时间
+        for expires_at in [1_030, 2_000] {
+            assert!(matches!(
+                later.reissue_with_options(&token, ReissuePolicy::ExpiresAt(expires_at), false),
+                Err(DxError::InvalidExpiry {
+                    created_at: 1_000,
+                    ..
+                })
+            ));
+        }
+        let renewed = later
+            .reissue_with_options(&token, ReissuePolicy::ExpiresAt(2_001), false)
+            .unwrap();
+        assert_eq!(
+            later.get_ttl_info(&renewed).unwrap().unwrap().ttl_seconds,
+            1_001
+        );
+        assert!(!later.is_expired(&renewed).unwrap());
+
+        assert_eq!(
+            engine
+                .reissue(&token, ReissuePolicy::Extend(Duration::MAX))
+                .unwrap_err()
+                .kind(),
+            ErrorKind::InvalidExpiry
+        );
+
+        let mut tampered = engine.decode_frame(&token).unwrap();
+        *tampered.last_mut().unwrap() ^= 1;
+        assert!(matches!(
+            engine.reissue(&engine.encode_frame(&tampered), extend),
+            Err(DxError::ChecksumMismatch { .. })
+        ));
+    }
+
+    // ========== 字符集与引擎测试 ==========
+
+    #[test]
+    fn test_alphabet_presets() {
+        for alphabet in [
+            Alphabet::STANDARD,
+            Alphabet::NO_CONFUSABLES,
+            Alphabet::FILENAME_SAFE,
+        ] {
+            assert_eq!(alphabet.as_str().len(), 64);
+            assert_eq!(Alphabet::new(alphabet.as_str()), Ok(alphabet));
+        }
+        assert_eq!(Alphabet::STANDARD.as_str(), CHARSET);
+        for c in "OIl".bytes() {
+            assert!(!Alphabet::NO_CONFUSABLES.contains(c));
+        }
+        // RFC 3986 非保留字符，外加各种 URL 编码器都不转义的 `*`
+        assert!(Alphabet::NO_CONFUSABLES
+            .as_str()
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b"-._~*".contains(&b)));
+    }
+
+    #[test]
+    fn test_alphabet_validation() {
+        assert_eq!(Alphabet::new("abc"), Err(AlphabetError::InvalidLength(3)));
+
+        let duplicate = CHARSET.replace('_', "-");
+        assert_eq!(
+            Alphabet::new(&duplicate),
+            Err(AlphabetError::DuplicateSymbol('-'))
+        );
+
+        let padding = CHARSET.replace('_', "=");
+        assert_eq!(
+            Alphabet::new(&padding),
+            Err(AlphabetError::ReservedSymbol('='))
+        );
+
+        let space = CHARSET.replace('_', " ");
+        assert_eq!(
+            Alphabet::new(&space),
+            Err(AlphabetError::NonPrintable(b' '))
+        );
+    }
+
+    #[test]
+    fn test_standard_engine_matches_functions() {
+        let data = "Hello World! ".repeat(10);
+        assert_eq!(STANDARD.encode(data.as_bytes()), encode(data.as_bytes()));
+        assert_eq!(Engine::default(), STANDARD);
+        assert_eq!(STANDARD.prefix(), PREFIX);
+        assert_eq!(STANDARD.magic(), MAGIC);
+    }
+
+    #[test]
+    fn test_custom_engine_roundtrip() {
+        let engine = Engine::new(Alphabet::NO_CONFUSABLES, 0x2A, "acme:").unwrap();
+        for length in 0..100 {
+            let original: Vec<u8> = (0..length).map(|i| (i * 31 % 256) as u8).collect();
+            let encoded = engine.encode(&original);
+            assert!(encoded.starts_with("acme:"));
+            assert!(engine.is_encoded(&encoded));
+            assert!(!encoded[5..].contains(['O', 'I', 'l', '+', '!']));
+            assert_eq!(engine.decode(&encoded).unwrap(), original);
+        }
+
+        let encoded = engine.encode_with_ttl(b"token", 60);
+        assert!(engine.has_ttl(&encoded).unwrap());
+        assert_eq!(engine.decode(&encoded).unwrap(), b"token");
+
+        // 不同引擎互不兼容
+        assert!(matches!(decode(&encoded), Err(DxError::InvalidPrefix)));
+    }
+
+    #[test]
+    fn test_engine_invalid_prefix() {
+        for prefix in ["", "a=b", "dx ", "前缀"] {
+            assert!(matches!(
+                Engine::new(Alphabet::STANDARD, MAGIC, prefix),
+                Err(DxError::InvalidAlphabet(AlphabetError::InvalidPrefix))
+            ));
+        }
+
+        // 前缀全部由字符集中的字符组成时会与编码正文混淆
+        for (alphabet, prefix) in [
+            (Alphabet::STANDARD, "dx"),
+            (Alphabet::STANDARD, "acme"),
+            (Alphabet::NO_CONFUSABLES, "a.b~"),
+        ] {
+            assert!(matches!(
+                Engine::new(alphabet, MAGIC, prefix),
+                Err(DxError::InvalidAlphabet(AlphabetError::PrefixInAlphabet))
+            ));
+        }
+        assert!(Engine::new(Alphabet::NO_CONFUSABLES, MAGIC, "dxO").is_ok());
+    }
+
+    // ========== 换行与空白测试 ==========
+
+    #[test]
+    fn test_line_wrap() {
+        let data: Vec<u8> = (0..=255).collect();
+        for (width, ending) in [(64, LineEnding::Lf), (76, LineEnding::CrLf)] {
+            let engine = STANDARD
+                .with_line_wrap(width, ending)
+                .with_ignore_whitespace(true);
+            let encoded = engine.encode_with_options(&data, false);
+            let lines: Vec<&str> = encoded.split(ending.as_str()).collect();
+            assert!(lines.len() > 1);
+            assert!(lines.iter().all(|line| line.len() <= width));
+            assert!(lines[..lines.len() - 1].iter().all(|l| l.len() == width));
+            assert!(!encoded.ends_with('\n'));
+
+            assert_eq!(lines.concat(), encode_with_options(&data, false));
+            assert_eq!(engine.decode(&encoded).unwrap(), data);
+            assert!(engine.is_encoded(&encoded));
+
+            // 默认引擎不接受换行
+            assert!(matches!(
+                decode(&encoded),
+                Err(DxError::InvalidCharacter {
+                    ch: '\r' | '\n',
+                    ..
+                })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_ignore_whitespace_anywhere() {
+        let engine = STANDARD.with_ignore_whitespace(true);
+        let encoded = encode_str("Pasted from an email");
+        let pasted: String = encoded
+            .chars()
+            .enumerate()
+            .flat_map(|(i, c)| match i % 5 {
+                0 => vec![' ', c],
+                3 => vec![c, '\t', '\n'],
+                _ => vec![c],
+            })
+            .collect();
+        let pasted = format!("  \r\n{}\n\n", pasted);
+        assert_eq!(
+            engine.decode(&pasted).unwrap(),
+            b"Pasted from an email".to_vec()
+        );
+        assert!(engine.is_encoded(&pasted));
+        assert!(!is_encoded(&pasted));
+    }
+
+    #[test]
+    fn test_whitespace_error_reports_original_index() {
+        let engine = STANDARD.with_ignore_whitespace(true);
+        let input = "dx 05\nk!JdEcSdt=";
+        let expected = input.find('!').unwrap();
+        assert!(matches!(
+            engine.decode(input),
+            Err(DxError::InvalidCharacter { ch: '!', index }) if index == expected
+        ));
+
+        // 不忽略空白时报告空白字符本身的位置
+        assert!(matches!(
+            decode("dx05k RJdEcSdt="),
+            Err(DxError::InvalidCharacter { ch: ' ', index: 5 })
+        ));
+
+        // 非 ASCII 字符报告完整字符
+        assert!(matches!(
+            decode("dx05k好RJdEcSdt"),
+            Err(DxError::InvalidCharacter {
+                ch: '好', index: 5
+            })
+        ));
+    }
+
+    // ========== dx32 测试 ==========
+
+    #[test]
+    fn test_dx32_roundtrip() {
+        for length in 0..100 {
+            let original: Vec<u8> = (0..length).map(|i| (i * 7 % 256) as u8).collect();
+            let encoded = dx32::encode(&original);
+            assert!(encoded.starts_with(dx32::PREFIX));
+            assert_eq!(
+                dx32::decode(&encoded).unwrap(),
+                original,
+                "长度 {} 失败",
+                length
+            );
+        }
+    }
+
+    #[test]
+    fn test_dx32_human_input() {
+        let engine = dx32::STANDARD.with_group_size(4);
+        let encoded = engine.encode(b"Dictation");
+        let body = &encoded[dx32::PREFIX.len()..];
+        assert!(body.split('-').all(|group| group.len() <= 4));
+        assert!(!body.contains(['I', 'L', 'O', 'U']));
+
+        // 正文的大小写、易混淆字符和分组都不影响解码
+        let typed = body
+            .to_lowercase()
+            .replace('0', "o")
+            .replace('1', "l")
+            .replace('-', "");
+        let typed = format!("{}{}", dx32::PREFIX, typed);
+        assert_eq!(dx32::decode(&typed).unwrap(), b"Dictation");
+        assert_eq!(engine.decode(&encoded).unwrap(), b"Dictation");
+    }
+
+    #[test]
+    fn test_dx32_shares_framing() {
+        let original = "Repeated data for compression test. ".repeat(20);
+        let encoded = dx32::encode_with_ttl(original.as_bytes(), 3600);
+        let info = dx32::STANDARD.get_ttl_info(&encoded).unwrap().unwrap();
+        assert_eq!(info.ttl_seconds, 3600);
+        assert_eq!(dx32::decode(&encoded).unwrap(), original.as_bytes());
+
+        // dx32 与 dx 的前缀互不兼容，前缀区分大小写
+        assert!(matches!(decode(&encoded), Err(DxError::InvalidPrefix)));
+        assert!(matches!(
+            dx32::decode(&encode(b"Hello")),
+            Err(DxError::InvalidPrefix)
+        ));
+        let lowered = dx32::encode(b"Hello").to_lowercase();
+        assert!(matches!(
+            dx32::decode(&lowered),
+            Err(DxError::InvalidPrefix)
+        ));
+        assert!(matches!(
+            dx32::decode(&format!("DX{}", &lowered[2..])),
+            Err(DxError::InvalidPrefix)
+        ));
+    }
+
+    #[test]
+    fn test_dx32_clock() {
+        let engine = dx32::STANDARD.with_clock(Clock::Fixed(1_700_000_000));
+        let encoded = engine.encode_with_ttl(b"code", 60);
+        let info = engine.get_ttl_info(&encoded).unwrap().unwrap();
+        assert_eq!(info.created_at, 1_700_000_000);
+        assert_eq!(info.expires_at, Some(1_700_000_060));
+        assert!(!info.is_expired);
+        assert_eq!(engine.decode(&encoded).unwrap(), b"code");
+
+        let later = engine.clone().with_clock(Clock::Fixed(1_700_000_061));
+        assert!(later.get_ttl_info(&encoded).unwrap().unwrap().is_expired);
+        assert!(matches!(
+            later.decode(&encoded),
+            Err(DxError::TtlExpired {
+                expired_at: 1_700_000_060,
+                ..
+            })
+        ));
+        assert_eq!(later.decode_with_options(&encoded, false).unwrap(), b"code");
+        assert_eq!(dx32::STANDARD.clock(), Clock::System);
+    }
+
+    #[test]
+    fn test_dx32_invalid_input() {
+        assert!(matches!(
+            dx32::decode("dX00-U000"),
+            Err(DxError::InvalidCharacter { ch: 'U', index: 5 })
+        ));
+        // 1 个字符只有 5 位，不足一个字节
+        assert!(matches!(
+            dx32::decode("dX0"),
+            Err(DxError::InvalidLength {
+                expected: 2,
+                actual: 1
+            })
+        ));
+
+        let encoded = dx32::encode(b"Hello World");
+        let mut tampered: Vec<char> = encoded.chars().collect();
+        let last = tampered.len() - 3;
+        tampered[last] = if tampered[last] == 'A' { 'B' } else { 'A' };
+        let tampered: String = tampered.into_iter().collect();
+        assert!(!dx32::STANDARD.verify(&tampered).unwrap_or(false));
+    }
+
+    #[test]
+    fn test_ttl_checksum_includes_ttl_header_offset() {
+        let encoded = encode_with_ttl(b"Hello", 3600);
+        let (stored, computed) = get_checksum(&encoded).unwrap();
+        assert_eq!(stored, computed);
+        assert_eq!(stored, crc16(b"Hello"));
+    }
+
+    #[test]
+    fn test_inspect() {
+        let engine = STANDARD.with_clock(Clock::Fixed(1_000));
+        let data = b"Hello, Dogxi! ".repeat(8);
+        let inspection = engine.inspect(&engine.encode_with_ttl(&data, 60)).unwrap();
+        assert!(inspection.error.is_none());
+        assert_eq!(inspection.flag_names(), ["compressed", "deflate", "ttl"]);
+        assert_eq!(inspection.created_at, Some(1_000));
+        assert_eq!(inspection.expires_at(), Some(1_060));
+        assert_eq!(inspection.declared_size, Some(data.len() as u16));
+        assert_eq!(inspection.data.as_deref(), Some(&data[..]));
+        assert_eq!(inspection.payload_offset, 11);
+        assert_eq!(inspection.checksum_matches(), Some(true));
+
+        // flags 无效和校验和不匹配时仍然解析
+        let mut frame = frame::build(b"Hello", false, None, None);
+        frame[0] = 0x80;
+        frame[1] ^= 0xFF;
+        let inspection = inspect(&STANDARD.encode_frame(&frame)).unwrap();
+        assert!(matches!(
+            inspection.error,
+            Some(DxError::InvalidFlags(0x80))
+        ));
+        assert_eq!(inspection.unknown_flags(), 0x80);
+        assert_eq!(inspection.payload(), b"Hello");
+        assert_eq!(inspection.checksum_matches(), Some(false));
+
+        let inspection = inspect(&STANDARD.encode_frame(&[frame::FLAG_HAS_TTL, 0])).unwrap();
+        assert_eq!(inspection.stored_checksum, None);
+        assert_eq!(inspection.created_at, None);
+        assert_eq!(inspection.error.unwrap().kind(), ErrorKind::InvalidHeader);
+
+        assert!(inspect("hello").is_err());
+    }
+
+    #[test]
+    fn test_find_tokens() {
+        let a = encode_str("first");
+        let b = STANDARD.with_encode_padding(false).encode(b"second value");
+        let truncated = &a[..a.len() - 2];
+        let text = format!(
+            "dxdiag dxgi.dll dxvk\n\"{}\",x{} {}\nkey={}=1 {}",
+            a, a, truncated, b, a
+        );
+
+        let found: Vec<Match> = find_tokens(&text).collect();
+        assert_eq!(found.len(), 3);
+        assert_eq!(found[0].as_str(), a);
+        assert_eq!(&text[found[0].range()], a);
+        assert_eq!(found[1].as_str(), b);
+        assert_eq!(found[2].end(), text.len());
+        assert!(["dxdiag", "dxgi", "dxvk", "dx", "dx=="]
+            .iter()
+            .all(|w| find_tokens(w).next().is_none()));
+
+        let engine = Engine::new(Alphabet::STANDARD, MAGIC, "acme:").unwrap();
+        let token = engine.encode(b"Hello");
+        let text = format!("{} {}", a, token);
+        let found: Vec<&str> = engine.find_tokens(&text).map(|m| m.as_str()).collect();
+        assert_eq!(found, [token.as_str()]);
+    }
+
+    // ========== 格式转换测试 ==========
+
+    #[test]
+    fn test_encoding_vectors() {
+        // RFC 4648 第 10 节
+        let cases = [
+            ("", "", ""),
+            ("f", "Zg==", "MY======"),
+            ("fo", "Zm8=", "MZXQ===="),
+            ("foo", "Zm9v", "MZXW6==="),
+            ("foob", "Zm9vYg==", "MZXW6YQ="),
+            ("fooba", "Zm9vYmE=", "MZXW6YTB"),
+            ("foobar", "Zm9vYmFy", "MZXW6YTBOI======"),
+        ];
+        for (data, base64, base32) in cases {
+            assert_eq!(Encoding::Base64.encode(data.as_bytes()), base64);
+            assert_eq!(Encoding::Base32.encode(data.as_bytes()), base32);
+            assert_eq!(Encoding::Base64.decode(base64).unwrap(), data.as_bytes());
+            assert_eq!(Encoding::Base32.decode(base32).unwrap(), data.as_bytes());
+            assert_eq!(
+                Encoding::Base32
+                    .decode(&base32.to_lowercase().replace('=', ""))
+                    .unwrap(),
+                data.as_bytes()
+            );
+        }
+
+        let data = [0xFB, 0xFF, 0x00, 0x7F];
+        assert_eq!(Encoding::Base64.encode(&data), "+/8Afw==");
+        assert_eq!(Encoding::Base64Url.encode(&data), "-_8Afw");
+        assert_eq!(Encoding::Hex.encode(&data), "fbff007f");
+        for encoding in Encoding::ALL {
+            let encoded = encoding.encode(&data);
+            assert_eq!(encoding.decode(&encoded).unwrap(), data, "{}", encoding);
+            assert_eq!(
+                Encoding::from_name(&encoding.name().to_uppercase()),
+                Some(encoding)
+            );
+        }
+        assert_eq!(Encoding::Base64.decode("Zm9v\nYmFy").unwrap(), b"foobar");
+    }
+
+    #[test]
+    fn test_encoding_errors() {
+        let index = |encoding: Encoding, input: &str| match encoding.decode(input) {
+            Err(DxError::InvalidSource { encoding: e, index }) if e == encoding => index,
+            other => panic!("{} {:?}: {:?}", encoding, input, other),
+        };
+        assert_eq!(index(Encoding::Base64, "Zm9v-A=="), 4);
+        assert_eq!(index(Encoding::Base64Url, "Zm9v+A"), 4);
+        assert_eq!(index(Encoding::Base64, "Zg==Zg=="), 4);
+        assert_eq!(index(Encoding::Base64, "Zm9vY"), 5);
+        assert_eq!(index(Encoding::Base64, "Zg="), 3);
+        assert_eq!(index(Encoding::Hex, "abc"), 3);
+        assert_eq!(index(Encoding::Hex, "ab=="), 2);
+        assert_eq!(index(Encoding::Base32, "MZXW6Y"), 6);
+
+        let err = Encoding::Hex.decode("0g").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidSource);
+        assert_eq!(err.to_string(), "invalid hex input at index 1");
+    }
+
+    #[test]
+    fn test_base64_conversion() {
+        let data = b"Hello, Dogxi! ".repeat(8);
+        let base64 = Encoding::Base64.encode(&data);
+        let encoded = from_base64(&base64).unwrap();
+        assert_eq!(encoded, encode(&data));
+        assert!(is_compressed(&encoded).unwrap());
+        assert_eq!(to_base64(&encoded).unwrap(), base64);
+        assert!(from_base64("not base64!").is_err());
+
+        // 帧保留 TTL 头部，可以原样还原
+        let engine = STANDARD.with_clock(Clock::Fixed(1_000));
+        let token = engine.encode_with_ttl(b"Hello", 60);
+        let frame = engine.to_frame(&token).unwrap();
+        let hex = Encoding::Hex.encode(&frame);
+        assert_eq!(
+            engine
+                .from_frame(&Encoding::Hex.decode(&hex).unwrap())
+                .unwrap(),
+            token
+        );
+        assert_eq!(engine.to_base64(&token).unwrap(), "SGVsbG8=");
+        let later = STANDARD.with_clock(Clock::Fixed(2_000));
+        assert_eq!(later.to_frame(&token).unwrap(), frame);
+        assert_eq!(
+            later
+                .to_frame_with_options(&token, true)
+                .unwrap_err()
+                .kind(),
+            ErrorKind::TtlExpired
+        );
+
+        let mut tampered = frame.clone();
+        tampered[1] ^= 0xFF;
+        assert_eq!(
+            engine.from_frame(&tampered).unwrap_err().kind(),
+            ErrorKind::ChecksumMismatch
+        );
+        assert!(STANDARD.to_base64(&token).is_err());
+    }
+
+    // ========== 错误诊断测试 ==========
+
+    #[test]
+    fn test_error_kind() {
+        assert_eq!(
+            decode("hello").unwrap_err().kind(),
+            ErrorKind::InvalidPrefix
+        );
+        assert_eq!(
+            decode("dxABCDE").unwrap_err().kind(),
+            ErrorKind::InvalidLength
+        );
+        let err = decode("dxAB!D").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidCharacter);
+        assert_eq!(err.index(), Some(4));
+        assert_eq!(
+            decode_str(&encode(&[0xFF, 0xFE])).unwrap_err().kind(),
+            ErrorKind::InvalidUtf8
+        );
+    }
+
+    #[test]
+    fn test_invalid_header_lengths() {
+        // 2 字节帧不足基础头部
+        let encoded = STANDARD.encode_frame(&[0x00, 0x00]);
+        assert!(matches!(
+            decode(&encoded),
+            Err(DxError::InvalidHeader {
+                expected: 3,
+                actual: 2
+            })
+        ));
+
+        // 声明了 TTL 但头部不完整
+        let encoded = STANDARD.encode_frame(&[frame::FLAG_HAS_TTL, 0, 0, 0, 0]);
+        assert!(matches!(
+            decode(&encoded),
+            Err(DxError::InvalidHeader {
+                expected: 11,
+                actual: 5
+            })
+        ));
+
+        // 压缩数据缺少原始大小
+        let encoded = STANDARD.encode_frame(&[frame::FLAG_COMPRESSED, 0, 0, 0]);
+        assert!(matches!(
+            decode(&encoded),
+            Err(DxError::InvalidHeader {
+                expected: 5,
+                actual: 4
+            })
+        ));
+    }
+
+    #[test]
+    fn test_error_source() {
+        use std::error::Error;
+
+        let err = decode_str(&encode(&[0xFF])).unwrap_err();
+        assert!(err.source().unwrap().is::<std::str::Utf8Error>());
+
+        // 损坏的 DEFLATE 数据保留底层 io::Error
+        let flags = frame::FLAG_COMPRESSED | frame::FLAG_ALGO_DEFLATE;
+        let encoded = STANDARD.encode_frame(&[flags, 0, 0, 0, 8, 0xFF, 0xFF, 0xFF]);
+        let err = decode(&encoded).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Compression);
+        assert!(err.source().unwrap().is::<std::io::Error>());
+
+        let err = Engine::new(Alphabet::STANDARD, MAGIC, "").unwrap_err();
+        assert!(err.source().unwrap().is::<AlphabetError>());
+        assert!(decode("hello").unwrap_err().source().is_none());
+    }
+
+    /// 记录的原始大小为 16 字节，实际解压出 1 MiB
+    fn deflate_bomb() -> String {
+        let deflated = compress_deflate(&vec![0; 1 << 20]).unwrap();
+        let mut bytes = vec![frame::FLAG_COMPRESSED | frame::FLAG_ALGO_DEFLATE];
+        bytes.extend_from_slice(&crc16(&[0; 16]).to_be_bytes());
+        bytes.extend_from_slice(&16u16.to_be_bytes());
+        bytes.extend_from_slice(&deflated);
+        STANDARD.encode_frame(&bytes)
+    }
+
+    #[test]
+    fn test_decompressed_size_is_bounded() {
+        let bomb = deflate_bomb();
+        assert_eq!(decode(&bomb).unwrap_err().kind(), ErrorKind::Compression);
+        let inspection = inspect(&bomb).unwrap();
+        assert_eq!(inspection.declared_size, Some(16));
+        assert_eq!(inspection.data.unwrap().len(), u16::MAX as usize + 1);
+    }
+
+    // ========== 本地化测试 ==========
+
+    #[test]
+    fn test_locale_parse() {
+        for tag in ["en", "en_US.UTF-8", "en-GB", "C", "POSIX"] {
+            assert_eq!(Locale::parse(tag), Some(Locale::En), "{}", tag);
+        }
+        for tag in ["zh", "zh_CN.UTF-8", "zh-CN", "zh_CN.GB2312@stroke"] {
+            assert_eq!(Locale::parse(tag), Some(Locale::ZhCn), "{}", tag);
+        }
+        assert_eq!(Locale::parse("fr_FR.UTF-8"), None);
+        assert_eq!(Locale::parse(""), None);
+    }
+
+    #[test]
+    fn test_error_messages_english_by_default() {
+        let err = decode("dxABCDE").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid DX encoding: expected 6 characters, found 5"
+        );
+        assert_eq!(
+            err.localized(Locale::ZhCn).to_string(),
+            "无效的 DX 编码：长度不正确，期望 6 个字符，实际 5 个"
+        );
+        assert_eq!(err.localized(Locale::En).to_string(), err.to_string());
+
+        // 嵌套的字符集错误同样本地化
+        let err = Engine::new(Alphabet::STANDARD, MAGIC, "").unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("invalid alphabet: prefix must be"));
+        assert!(err
+            .localized(Locale::ZhCn)
+            .to_string()
+            .starts_with("无效的字符集：前缀必须"));
+    }
+
+    // ========== Serde 测试 ==========
+
+    #[cfg(feature = "serde")]
+    #[derive(::serde::Serialize, ::serde::Deserialize, PartialEq, Debug)]
+    struct Record {
+        id: u32,
+        name: String,
+        #[serde(with = "crate::serde::bytes")]
+        payload: Vec<u8>,
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_roundtrip() {
+        let record = Record {
+            id: 7,
+            name: "Dogxi".repeat(10),
+            payload: vec![0, 1, 2, 255],
+        };
+        for engine in [
+            STANDARD,
+            STANDARD.with_encode_padding(false),
+            STANDARD.with_content_type_tag(true),
+        ] {
+            let encoded = to_dx_string(&record, &engine).unwrap();
+            assert_eq!(from_dx_str::<Record>(&encoded, &engine).unwrap(), record);
+            // 普通解码得到 postcard 字节，校验和同样有效
+            assert!(engine.verify(&encoded).unwrap());
+        }
+
+        let encoded = to_dx_string_with_ttl(&record, &STANDARD, 3600).unwrap();
+        assert!(has_ttl(&encoded).unwrap());
+        assert_eq!(from_dx_str::<Record>(&encoded, &STANDARD).unwrap(), record);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_content_type() {
+        // 默认不写入内容类型，其他实现也能解码
+        let encoded = to_dx_string(&7u32, &STANDARD).unwrap();
+        assert_eq!(inspect(&encoded).unwrap().content_type, None);
+        assert_eq!(decode(&encoded).unwrap(), [7]);
+        assert_eq!(from_dx_str::<u32>(&encode(&[7]), &STANDARD).unwrap(), 7);
+
+        let tagged = STANDARD.with_content_type_tag(true);
+        let encoded = to_dx_string(&7u32, &tagged).unwrap();
+        assert_eq!(inspect(&encoded).unwrap().content_type, Some(0x01));
+        assert_eq!(from_dx_str::<u32>(&encoded, &STANDARD).unwrap(), 7);
+
+        // 标记为其他内容类型时拒绝
+        let other = STANDARD.encode_frame(&frame::build(&[7], false, None, Some(0x02)));
+        let err = from_dx_str::<u32>(&other, &STANDARD).unwrap_err();
+        assert!(matches!(
+            err,
+            DxError::InvalidContentType {
+                expected: 0x01,
+                actual: Some(0x02)
+            }
+        ));
+
+        // 数据不足时返回序列化错误
+        let encoded = to_dx_string(&1u8, &STANDARD).unwrap();
+        let err = from_dx_str::<(u64, String)>(&encoded, &STANDARD).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Serialization);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_bytes_helper() {
+        let record = Record {
+            id: 1,
+            name: "a".into(),
+            payload: b"Hello".to_vec(),
+        };
+        let json = serde_json::to_value(&record).unwrap();
+        assert_eq!(json["payload"], encode(b"Hello"));
+        assert_eq!(serde_json::from_value::<Record>(json).unwrap(), record);
+
+        let invalid = serde_json::json!({ "id": 1, "name": "a", "payload": "hello" });
+        assert!(serde_json::from_value::<Record>(invalid).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serialize_info_types() {
+        let engine = STANDARD.with_clock(Clock::Fixed(1_000));
+        let encoded = engine.encode_with_ttl(&[b'a'; 100], 60);
+
+        let ttl = serde_json::to_value(engine.get_ttl_info(&encoded).unwrap()).unwrap();
+        assert_eq!(
+            ttl,
+            serde_json::json!({
+                "created_at": 1_000,
+                "ttl_seconds": 60,
+                "expires_at": 1_060,
+                "is_expired": false
+            })
+        );
+
+        let header = serde_json::to_value(engine.get_header(&encoded).unwrap()).unwrap();
+        assert_eq!(header["compressed"], true);
+        assert_eq!(header["original_size"], 100);
+        assert_eq!(header["content_type"], serde_json::Value::Null);
+
+        let info = serde_json::to_value(get_info()).unwrap();
+        assert_eq!(info["prefix"], "dx");
+        assert_eq!(info["padding"], "=");
+    }
+
+    // ========== DxString 测试 ==========
+
+    #[test]
+    fn test_dx_string_validated_construction() {
+        let encoded = encode_with_ttl(&[b'a'; 100], 60);
+        let dx: DxString = encoded.parse().unwrap();
+        assert_eq!(dx, encoded.as_str());
+        assert_eq!(dx.to_string(), encoded);
+        assert_eq!(dx.decode().unwrap(), vec![b'a'; 100]);
+        assert!(dx.header().compressed);
+        assert_eq!(dx.header().original_size, Some(100));
+        assert_eq!(dx.ttl().unwrap().ttl_seconds, 60);
+
+        let borrowed: &DxStr = encoded.as_str().try_into().unwrap();
+        assert_eq!(borrowed.to_owned(), dx);
+        assert_eq!(borrowed.len(), encoded.len());
+
+        // 字符合法但校验和错误的字符串无法构造
+        let mut chars: Vec<char> = encode(b"Hello World").chars().collect();
+        let last = chars.len() - 3;
+        chars[last] = if chars[last] == 'A' { 'B' } else { 'A' };
+        let tampered: String = chars.into_iter().collect();
+        assert!(is_encoded(&tampered));
+        assert!(DxString::try_from(tampered.as_str()).is_err());
+        assert!(matches!(
+            DxString::new("hello"),
+            Err(DxError::InvalidPrefix)
+        ));
+    }
+
+    #[test]
+    fn test_dx_string_hash_and_borrow() {
+        use std::collections::HashSet;
+
+        let a = DxString::encode(b"a");
+        let b = DxString::encode(b"b");
+        let set: HashSet<DxString> = [a.clone(), b.clone()].into_iter().collect();
+        assert!(set.contains(a.as_dx_str()));
+        assert_eq!(a.cmp(&b), a.as_str().cmp(b.as_str()));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_dx_string_serde() {
+        let dx = DxString::encode(b"Hello");
+        let json = serde_json::to_string(&dx).unwrap();
+        assert_eq!(json, format!("\"{}\"", dx));
+        assert_eq!(serde_json::from_str::<DxString>(&json).unwrap(), dx);
+        assert_eq!(
+            serde_json::from_str::<&DxStr>(&json).unwrap(),
+            dx.as_dx_str()
+        );
+        assert!(serde_json::from_str::<DxString>("\"dxDX\"").is_err());
+    }
+
+    // ========== 文档字段测试 ==========
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_selector_parse() {
+        let selector = Selector::parse("$.a['b.c'][2].*[\"d\\\"\"]").unwrap();
+        assert_eq!(
+            selector.segments(),
+            [
+                Segment::Key("a".into()),
+                Segment::Key("b.c".into()),
+                Segment::Index(2),
+                Segment::Wildcard,
+                Segment::Key("d\"".into()),
+            ]
+        );
+        assert_eq!(selector.to_string(), "$.a['b.c'][2][*]['d\"']");
+        assert!(Selector::parse("$").unwrap().segments().is_empty());
+
+        for (input, position) in [("a.b", 0), ("$.", 2), ("$[x]", 1), ("$['a'", 1), ("$a", 1)] {
+            match Selector::parse(input) {
+                Err(TransformError::InvalidSelector { position: p, .. }) => {
+                    assert_eq!(p, position, "{}", input)
+                }
+                other => panic!("{}: {:?}", input, other),
+            }
+        }
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_transform_json() {
+        use serde_json::json;
+
+        let encode = FieldOp::Encode {
+            allow_compression: true,
+        };
+        let mut doc = json!({ "a": { "x": "1", "y": "2" }, "list": ["3", 4] });
+        assert_eq!(transform_json(&mut doc, "$.a.*", encode).unwrap(), 2);
+        assert_eq!(transform_json(&mut doc, "$.missing[0]", encode).unwrap(), 0);
+        assert_eq!(decode_str(doc["a"]["y"].as_str().unwrap()).unwrap(), "2");
+
+        // 任一字段失败时文档不变
+        let before = doc.clone();
+        match transform_json(&mut doc, "$.list[*]", encode) {
+            Err(TransformError::NotAString { path }) => assert_eq!(path, "$.list[1]"),
+            other => panic!("{:?}", other),
+        }
+        let decode = FieldOp::Decode { check_ttl: true };
+        let err = transform_json(&mut doc, "$['a', 'list'][*]", decode);
+        assert!(matches!(err, Err(TransformError::InvalidSelector { .. })));
+        match transform_json(&mut doc, "$.*[0]", decode) {
+            Err(TransformError::Field { path, error }) => {
+                assert_eq!(path, "$.list[0]");
+                assert_eq!(error.kind(), ErrorKind::InvalidPrefix);
+            }
+            other => panic!("{:?}", other),
+        }
+        assert_eq!(doc, before);
+
+        transform_json(&mut doc, "$.a[*]", decode).unwrap();
+        assert_eq!(doc["a"], json!({ "x": "1", "y": "2" }));
+        let err = TransformError::NotAString { path: "$.a".into() };
+        assert_eq!(err.localized(Locale::ZhCn).to_string(), "$.a: 不是字符串");
+    }
+
+    // ========== 异步流测试 ==========
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_stream_roundtrip() {
+        use crate::stream::{DxReader, DxWriter};
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let data: Vec<u8> = (0..10_000u32)
+            .flat_map(|i| {
+                if i % 3 == 0 {
+                    [b'a'; 4]
+                } else {
+                    i.to_be_bytes()
+                }
+            })
+            .collect();
+        let mut writer = DxWriter::new(Vec::new()).with_chunk_size(4096);
+        for piece in data.chunks(777) {
+            writer.write_all(piece).await.unwrap();
+        }
+        writer.shutdown().await.unwrap();
+        let encoded = String::from_utf8(writer.into_inner()).unwrap();
+
+        // 每行都是独立的 DX 编码，且分块后仍会压缩
+        let lines: Vec<&str> = encoded.lines().collect();
+        assert_eq!(lines.len(), data.len().div_ceil(4096));
+        assert!(lines.iter().any(|line| is_compressed(line).unwrap()));
+        let joined: Vec<u8> = lines.iter().flat_map(|l| decode(l).unwrap()).collect();
+        assert_eq!(joined, data);
+
+        let mut decoded = Vec::new();
+        DxReader::new(encoded.replace('\n', "\r\n").as_bytes())
+            .read_to_end(&mut decoded)
+            .await
+            .unwrap();
+        assert_eq!(decoded, data);
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_stream_reader_reports_dx_error() {
+        use crate::stream::DxReader;
+        use tokio::io::AsyncReadExt;
+
+        let input = format!("{}\nhello\n", encode(b"ok"));
+        let mut decoded = Vec::new();
+        let err = DxReader::new(input.as_bytes())
+            .read_to_end(&mut decoded)
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        let dx = err.get_ref().unwrap().downcast_ref::<DxError>().unwrap();
+        assert_eq!(dx.kind(), ErrorKind::InvalidPrefix);
+        assert_eq!(decoded, b"ok");
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_stream_chunks_adapter() {
+        use crate::stream::DxChunks;
+        use bytes::Bytes;
+        use futures_core::Stream;
+        use std::pin::Pin;
+        use std::task::{Context, Poll};
+
+        struct Iter(std::vec::IntoIter<Bytes>);
+
+        impl Stream for Iter {
+            type Item = Bytes;
+
+            fn poll_next(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Option<Bytes>> {
+                Poll::Ready(self.0.next())
+            }
+        }
+
+        let parts: Vec<Bytes> = (0..20).map(|i| Bytes::from(vec![i as u8; 300])).collect();
+        let expected: Vec<u8> = parts.iter().flatten().copied().collect();
+        let mut chunks = DxChunks::new(Iter(parts.into_iter())).with_chunk_size(1000);
+
+        let mut output = Vec::new();
+        while let Some(item) = std::future::poll_fn(|cx| Pin::new(&mut chunks).poll_next(cx)).await
+        {
+            // 每一项都以完整的行结束
+            assert!(item.ends_with(b"\n"));
+            output.extend_from_slice(&item);
+        }
+
+        let decoded: Vec<u8> = std::str::from_utf8(&output)
+            .unwrap()
+            .lines()
+            .flat_map(|line| decode(line).unwrap())
+            .collect();
+        assert_eq!(decoded, expected);
+    }
+
+    // ========== axum 测试 ==========
+
+    #[cfg(feature = "axum")]
+    async fn send(
+        app: ::axum::Router,
+        content_type: &str,
+        body: String,
+    ) -> (::axum::http::StatusCode, String) {
+        use ::axum::body::Body;
+        use ::axum::http::Request;
+        use tower::ServiceExt;
+
+        let request = Request::post("/")
+            .header("content-type", content_type)
+            .body(Body::from(body))
+            .unwrap();
+        let response = app.oneshot(request).await.unwrap();
+        let status = response.status();
+        let bytes = ::axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (status, String::from_utf8(bytes.to_vec()).unwrap())
+    }
+
+    #[cfg(feature = "axum")]
+    #[tokio::test]
+    async fn test_axum_layer_status_codes() {
+        use crate::axum::{DxLayer, CONTENT_TYPE_DX};
+        use ::axum::body::Bytes;
+        use ::axum::http::StatusCode;
+        use ::axum::routing::post;
+        use ::axum::Router;
+
+        let app = Router::new()
+            .route("/", post(|body: Bytes| async move { body }))
+            .layer(DxLayer::new());
+
+        let (status, body) = send(app.clone(), CONTENT_TYPE_DX, encode(b"hello")).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(decode(&body).unwrap(), b"hello");
+
+        // 未使用 DX 编码的请求原样通过
+        let (status, body) = send(app.clone(), "text/plain", "plain".into()).await;
+        assert_eq!((status, body.as_str()), (StatusCode::OK, "plain"));
+
+        let expired = STANDARD.encode_frame(&frame::build(b"x", false, Some((0, 1)), None));
+        let (status, _) = send(app.clone(), CONTENT_TYPE_DX, expired).await;
+        assert_eq!(status, StatusCode::GONE);
+
+        let mut chars: Vec<char> = encode(b"Hello World").chars().collect();
+        let last = chars.len() - 3;
+        chars[last] = if chars[last] == 'A' { 'B' } else { 'A' };
+        let (status, _) = send(app.clone(), CONTENT_TYPE_DX, chars.into_iter().collect()).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+        let (status, body) = send(app, CONTENT_TYPE_DX, "hello".into()).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body, DxError::InvalidPrefix.to_string());
+    }
+
+    #[cfg(feature = "axum")]
+    #[tokio::test]
+    async fn test_axum_body_limits() {
+        use crate::axum::{DxBytes, DxLayer, CONTENT_TYPE_DX};
+        use ::axum::body::Bytes;
+        use ::axum::http::StatusCode;
+        use ::axum::routing::post;
+        use ::axum::Router;
+
+        let app = Router::new()
+            .route("/", post(|body: Bytes| async move { body }))
+            .layer(DxLayer::new().with_body_limit(64));
+
+        // 没有 Content-Length 的请求体在读取时超出上限
+        let (status, _) = send(
+            app.clone(),
+            CONTENT_TYPE_DX,
+            encode(&(0..100).collect::<Vec<u8>>()),
+        )
+        .await;
+        assert_eq!(status, StatusCode::PAYLOAD_TOO_LARGE);
+
+        // 解压后的大小受头部记录的原始大小限制
+        let app = Router::new().route("/", post(|DxBytes(body): DxBytes| async move { body }));
+        let (status, body) = send(app, CONTENT_TYPE_DX, deflate_bomb()).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert!(body.contains("declared size"));
+    }
+
+    #[cfg(feature = "axum")]
+    #[tokio::test]
+    async fn test_axum_extractor_and_response() {
+        use crate::axum::{Dx, CONTENT_TYPE_DX};
+        use ::axum::http::StatusCode;
+        use ::axum::routing::post;
+        use ::axum::Router;
+
+        async fn rename(Dx(mut record): Dx<Record>) -> Dx<Record> {
+            record.name = "renamed".into();
+            Dx(record)
+        }
+
+        let app = Router::new().route("/", post(rename));
+        let record = Record {
+            id: 3,
+            name: "original".into(),
+            payload: vec![1, 2, 3],
+        };
+
+        let request = to_dx_string(&record, &STANDARD).unwrap();
+        let (status, body) = send(app.clone(), CONTENT_TYPE_DX, request).await;
+        assert_eq!(status, StatusCode::OK);
+        let response: Record = from_dx_str(&body, &STANDARD).unwrap();
+        assert_eq!(response.name, "renamed");
+        assert_eq!(response.payload, record.payload);
+
+        let (status, _) = send(app.clone(), "application/json", "{}".into()).await;
+        assert_eq!(status, StatusCode::UNSUPPORTED_MEDIA_TYPE);
+
+        // 没有内容类型标记的普通编码不能作为 serde 数据
+        let (status, _) = send(app, CONTENT_TYPE_DX, encode(b"raw")).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    // ========== 会话令牌测试 ==========
+
+    #[test]
+    fn test_set_cookie_expiry_from_ttl() {
+        use crate::session::{SameSite, SetCookie};
+
+        let created_at = 1_000_000_000;
+        let token =
+            STANDARD.encode_frame(&frame::build(b"s", false, Some((created_at, 3600)), None));
+        let token = DxStr::new(&token).unwrap();
+        let at = |now: u32| STANDARD.with_clock(Clock::Fixed(now as u64));
+
+        let engine = at(created_at + 600);
+        let cookie = SetCookie::new("session", token)
+            .with_engine(&engine)
+            .build()
+            .unwrap();
+        assert_eq!(
+            cookie,
+            format!(
+                "session={}; Max-Age=3000; Expires=Sun, 09 Sep 2001 02:46:40 GMT; \
+                 Path=/; Secure; HttpOnly; SameSite=Lax",
+                token
+            )
+        );
+
+        let engine = at(created_at);
+        let cookie = SetCookie::new("session", token)
+            .with_engine(&engine)
+            .with_path(None)
+            .with_domain(Some("example.com"))
+            .with_http_only(false)
+            .with_same_site(Some(SameSite::Strict))
+            .build()
+            .unwrap();
+        assert!(cookie.contains("Max-Age=3600; Expires=Sun, 09 Sep 2001 02:46:40 GMT"));
+        assert!(cookie.ends_with("; Domain=example.com; Secure; SameSite=Strict"));
+
+        // 过期的那一秒令牌仍然有效，Max-Age 不能为 0
+        let engine = at(created_at + 3600);
+        let cookie = SetCookie::new("session", token)
+            .with_engine(&engine)
+            .build()
+            .unwrap();
+        assert!(cookie.contains("; Max-Age=1; "));
+
+        let engine = at(created_at + 3601);
+        let err = SetCookie::new("session", token)
+            .with_engine(&engine)
+            .build()
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::TtlExpired);
+
+        // 不带 TTL 或永不过期的令牌生成会话 Cookie
+        for token in [encode(b"s"), encode_with_ttl(b"s", 0)] {
+            let cookie = SetCookie::new("session", DxStr::new(&token).unwrap())
+                .build()
+                .unwrap();
+            assert!(!cookie.contains("Max-Age"));
+            assert!(!cookie.contains("Expires"));
+        }
+    }
+
+    #[test]
+    fn test_set_cookie_expires_at_century_boundaries() {
+        use crate::session::SetCookie;
+
+        for (created_at, expires) in [
+            (946_684_799 - 60, "Fri, 31 Dec 1999 23:59:59 GMT"),
+            (946_684_800 - 60, "Sat, 01 Jan 2000 00:00:00 GMT"),
+            (951_782_400 - 60, "Tue, 29 Feb 2000 00:00:00 GMT"),
+            (4_102_444_800 - 60, "Fri, 01 Jan 2100 00:00:00 GMT"),
+        ] {
+            let engine = STANDARD.with_clock(Clock::Fixed(created_at));
+            let token = engine.encode_with_ttl(b"s", 60);
+            let cookie = SetCookie::new("session", &token)
+                .with_engine(&engine)
+                .build()
+                .unwrap();
+            assert!(
+                cookie.contains(&format!("; Max-Age=60; Expires={};", expires)),
+                "{}",
+                cookie
+            );
+        }
+    }
+
+    #[test]
+    fn test_session_custom_engine_roundtrip() {
+        use crate::session::{self, SetCookie};
+
+        let engine = Engine::new(Alphabet::NO_CONFUSABLES, 0x2A, "acme:")
+            .unwrap()
+            .with_clock(Clock::Fixed(1_700_000_000));
+        let token = engine.encode_with_ttl(b"user:42", 3600);
+
+        let cookie = SetCookie::new("session", &token)
+            .with_engine(&engine)
+            .build()
+            .unwrap();
+        assert!(cookie.contains("; Max-Age=3600; "));
+        // 浏览器回传时只携带名称和值
+        let header = cookie.split(';').next().unwrap();
+        let parsed = session::parse_cookie_with_engine(&engine, header, "session")
+            .unwrap()
+            .unwrap();
+        assert_eq!(parsed, token);
+        assert_eq!(engine.decode(parsed).unwrap(), b"user:42");
+
+        let header = format!("Dx {}", token);
+        let parsed = session::parse_authorization_with_engine(&engine, &header)
+            .unwrap()
+            .unwrap();
+        assert_eq!(engine.decode(parsed).unwrap(), b"user:42");
+
+        // 标准引擎不接受自定义前缀，反之亦然
+        assert_eq!(
+            SetCookie::new("session", &token)
+                .build()
+                .unwrap_err()
+                .kind(),
+            ErrorKind::InvalidPrefix
+        );
+        assert_eq!(
+            session::parse_authorization(&header).unwrap_err().kind(),
+            ErrorKind::InvalidPrefix
+        );
+        let standard = format!("session={}", encode(b"s"));
+        assert_eq!(
+            session::parse_cookie_with_engine(&engine, &standard, "session")
+                .unwrap_err()
+                .kind(),
+            ErrorKind::InvalidPrefix
+        );
+    }
+
+    #[test]
+    fn test_set_cookie_rejects_injection() {
+        use crate::session::SetCookie;
+
+        let token = DxString::encode(b"s");
+        for name in [
+            "",
+            "a b",
+            "a;b",
+            "a=b",
+            "a\r\nSet-Cookie: x",
+            "a,b",
+            "caf\u{e9}",
+        ] {
+            assert!(
+                matches!(
+                    SetCookie::new(name, &token).build(),
+                    Err(DxError::InvalidCookie { attribute: "name" })
+                ),
+                "{:?}",
+                name
+            );
+        }
+        let err = SetCookie::new("session", &token)
+            .with_path(Some("/; Domain=evil.example"))
+            .build()
+            .unwrap_err();
+        assert!(matches!(err, DxError::InvalidCookie { attribute: "Path" }));
+        let err = SetCookie::new("session", &token)
+            .with_domain(Some("example.com\r\nX-Injected: 1"))
+            .build()
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidCookie);
+        assert!(SetCookie::new("__Host-id", &token)
+            .with_path(Some("/app path"))
+            .build()
+            .is_ok());
+    }
+
+    #[test]
+    fn test_authorization_header() {
+        use crate::session::{self, MAX_HEADER_SIZE};
+
+        let token = DxString::encode(b"user:42");
+        let header = session::authorization(&token).unwrap();
+        assert_eq!(header, format!("Dx {}", token));
+
+        for value in [
+            header.clone(),
+            format!("dx   {}", token),
+            format!(" DX {} ", token),
+        ] {
+            let parsed = session::parse_authorization(&value).unwrap().unwrap();
+            assert_eq!(parsed, token.as_dx_str());
+        }
+        assert!(session::parse_authorization("Bearer abc")
+            .unwrap()
+            .is_none());
+        assert!(session::parse_authorization("Dxfoo").unwrap().is_none());
+        assert_eq!(
+            session::parse_authorization("Dx ").unwrap_err().kind(),
+            ErrorKind::InvalidPrefix
+        );
+
+        let large =
+            DxString::new(STANDARD.encode_frame(&frame::build(&[0; 8192], false, None, None)))
+                .unwrap();
+        let err = session::authorization(&large).unwrap_err();
+        assert!(matches!(
+            err,
+            DxError::TokenTooLarge {
+                max: MAX_HEADER_SIZE,
+                ..
+            }
+        ));
+        let err = session::parse_authorization(&format!("Dx {}", large)).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::TokenTooLarge);
+    }
+
+    #[test]
+    fn test_parse_cookie() {
+        use crate::session::{self, SetCookie};
+
+        let token = encode(b"token");
+        let header = format!("theme=dark; session=\"{}\";other=1", token);
+        let parsed = session::parse_cookie(&header, "session").unwrap().unwrap();
+        assert_eq!(parsed, token.as_str());
+        assert!(session::parse_cookie(&header, "missing").unwrap().is_none());
+        assert!(session::parse_cookie("session=hello", "session").is_err());
+
+        let large =
+            DxString::new(STANDARD.encode_frame(&frame::build(&[0; 4096], false, None, None)))
+                .unwrap();
+        let err = SetCookie::new("session", &large).build().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::TokenTooLarge);
+        let err = session::parse_cookie(&format!("session={}", large), "session").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::TokenTooLarge);
+    }
+
+    // ========== 时钟测试 ==========
+
+    #[test]
+    fn test_engine_clock() {
+        fn custom() -> u64 {
+            1_000
+        }
+
+        let engine = STANDARD.with_clock(Clock::Fixed(1_700_000_000));
+        let encoded = engine.encode_with_ttl(b"Hello", 60);
+        let info = engine.get_ttl_info(&encoded).unwrap().unwrap();
+        assert_eq!(info.created_at, 1_700_000_000);
+        assert!(!info.is_expired);
+        assert_eq!(engine.decode(&encoded).unwrap(), b"Hello");
+
+        let later = STANDARD.with_clock(Clock::Fixed(1_700_000_061));
+        assert!(later.is_expired(&encoded).unwrap());
+        assert!(matches!(
+            later.decode(&encoded),
+            Err(DxError::TtlExpired {
+                expired_at: 1_700_000_060,
+                ..
+            })
+        ));
+
+        let engine = STANDARD.with_clock(Clock::Custom(custom));
+        assert_eq!(engine.clock(), Clock::Custom(custom));
+        assert_eq!(engine.clock().now(), 1_000);
+        assert_eq!(STANDARD.clock(), Clock::System);
+        assert!(Clock::System.now() > 1_700_000_000);
+    }
 }
@@ -0,0 +1,81 @@
+//! 消息语言
+//!
+//! 库的错误信息默认使用英文（`Display`），需要本地化时通过
+//! [`DxError::localized`](crate::DxError::localized) 显式选择语言。
+
+use std::env;
+use std::fmt;
+
+/// 消息语言
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Locale {
+    /// 英文（默认）
+    #[default]
+    En,
+    /// 简体中文
+    ZhCn,
+}
+
+impl Locale {
+    /// 解析语言标签
+    ///
+    /// 接受 `en`、`en_US.UTF-8`、`zh`、`zh-CN`、`zh_CN.UTF-8` 等形式，
+    /// `C` 和 `POSIX` 视为英文，无法识别时返回 `None`
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use dxcode::Locale;
+    ///
+    /// assert_eq!(Locale::parse("zh_CN.UTF-8"), Some(Locale::ZhCn));
+    /// assert_eq!(Locale::parse("en-US"), Some(Locale::En));
+    /// assert_eq!(Locale::parse("fr_FR"), None);
+    /// ```
+    pub fn parse(tag: &str) -> Option<Locale> {
+        // 去掉编码和修饰部分：zh_CN.UTF-8@euro -> zh_CN
+        let tag = tag.split(['.', '@']).next().unwrap_or_default();
+        let language = tag.split(['_', '-']).next().unwrap_or_default();
+
+        if tag == "C" || tag == "POSIX" || language.eq_ignore_ascii_case("en") {
+            Some(Locale::En)
+        } else if language.eq_ignore_ascii_case("zh") {
+            Some(Locale::ZhCn)
+        } else {
+            None
+        }
+    }
+
+    /// 从环境变量选择语言
+    ///
+    /// 按 `LC_ALL`、`LC_MESSAGES`、`LANG` 的顺序取第一个非空值，
+    /// 未设置或无法识别时使用英文
+    pub fn from_env() -> Locale {
+        ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .iter()
+            .filter_map(|name| env::var(name).ok())
+            .find(|value| !value.is_empty())
+            .and_then(|value| Locale::parse(&value))
+            .unwrap_or_default()
+    }
+
+    /// 语言标签（`en` 或 `zh-CN`）
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Locale::En => "en",
+            Locale::ZhCn => "zh-CN",
+        }
+    }
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// 按指定语言格式化错误信息，由 `localized()` 方法创建
+#[derive(Debug, Clone, Copy)]
+pub struct Localized<'a, E> {
+    pub(crate) error: &'a E,
+    pub(crate) locale: Locale,
+}
@@ -1,444 +1,1078 @@
 //! dxcode 命令行工具
 //!
-//! 由 Dogxi 创建 - v2.3.0
+//! 由 Dogxi 创建 - v3.0.0
 
+mod cli;
+
+use cli::args::{self, ArgError, Command, Matches};
+use cli::batch::{self, ErrorPolicy, Failure};
+use cli::document::{self, DocError};
+use cli::exit;
+use cli::inspect;
+use cli::io;
+use cli::messages::{Messages, Msg};
+use cli::output::{self, Format};
+use cli::scan;
+use cli::time;
 use dxcode::{
-    decode_str, decode_str_with_options, encode_str_with_options, encode_str_with_ttl,
-    get_checksum, get_info, get_ttl_info, has_ttl, is_compressed, is_encoded, is_expired, verify,
+    format_duration, get_info, parse_duration, DxError, Encoding, Engine, FieldOp, LineEnding,
+    Locale, ReissuePolicy, Selector, TransformError, STANDARD,
 };
+use serde_json::{json, Value};
 use std::env;
-use std::io::{self, Read};
+use std::io::Write;
+use std::ops::ControlFlow;
 use std::process;
+use std::time::Duration;
+
+/// 解码时忽略粘贴内容中的换行和空白
+const DECODER: Engine = STANDARD.with_ignore_whitespace(true);
 
 fn print_version() {
     println!("dxc {}", env!("CARGO_PKG_VERSION"));
 }
 
-fn print_help() {
-    println!("dxcode - 带有 `dx` 前缀的自定义编码算法 (v2.3 带校验和、压缩和 TTL)");
-    println!();
-    println!("用法:");
-    println!("  dxc encode <文本>                编码文本");
-    println!("  dxc encode --no-compress <文本>  编码文本（禁用压缩）");
-    println!("  dxc encode --ttl <秒> <文本>     编码文本并设置有效期");
-    println!("  dxc decode <编码>                解码 DX 字符串");
-    println!("  dxc decode --ignore-ttl <编码>   解码（忽略 TTL 过期）");
-    println!("  dxc check <字符串>               检查是否为有效的 DX 编码");
-    println!("  dxc verify <编码>                验证校验和完整性");
-    println!("  dxc ttl <编码>                   查看 TTL 信息");
-    println!("  dxc info                         显示编码信息");
-    println!("  dxc help                         显示帮助信息");
-    println!("  dxc --version                    显示版本信息");
-    println!();
-    println!("管道用法:");
-    println!("  echo 'Hello' | dxc encode");
-    println!("  echo 'dxXXXX' | dxc decode");
-    println!();
-    println!("示例:");
-    println!("  dxc encode '你好，Dogxi！'");
-    println!("  dxc encode --no-compress 'Hello World'");
-    println!("  dxc encode --ttl 3600 '临时令牌'    # 1小时有效期");
-    println!("  dxc encode --ttl 86400 'Data'       # 1天有效期");
-    println!("  dxc decode 'dxXXXX...'");
-    println!("  dxc verify 'dxXXXX...'              # 验证数据完整性");
-    println!("  dxc ttl 'dxXXXX...'                 # 查看 TTL 状态");
-    println!();
-    println!("更多信息: https://dxc.dogxi.me");
-    println!("GitHub: https://github.com/dogxii/dxcode");
+fn print_help(m: &Messages) {
+    println!("{}", m.get(Msg::Help));
 }
 
-fn print_info() {
+fn print_info(m: &Messages) {
     let info = get_info();
     println!("╔════════════════════════════════════════════╗");
-    println!("║             dxcode 信息                    ║");
+    println!("{}", m.get(Msg::InfoBanner));
     println!("╚════════════════════════════════════════════╝");
     println!();
-    println!("名称:       {}", info.name);
-    println!("版本:       {}", info.version);
-    println!("作者:       {}", info.author);
-    println!("前缀:       {}", info.prefix);
-    println!("魔数:       0x{:02X} ('{}')", info.magic, info.magic as char);
-    println!("填充:       {}", info.padding);
-    println!("校验和:     {}", info.checksum);
-    println!("压缩算法:   {}", info.compression);
-    println!("压缩阈值:   {} 字节", info.compression_threshold);
-    println!("字符集长度: {}", info.charset.len());
+    println!("{}", m.format(Msg::InfoName, &[&info.name]));
+    println!("{}", m.format(Msg::InfoVersion, &[&info.version]));
+    println!("{}", m.format(Msg::InfoAuthor, &[&info.author]));
+    println!("{}", m.format(Msg::InfoPrefix, &[&info.prefix]));
+    let magic = format!("0x{:02X}", info.magic);
+    println!(
+        "{}",
+        m.format(Msg::InfoMagic, &[&magic, &(info.magic as char)])
+    );
+    println!("{}", m.format(Msg::InfoPadding, &[&info.padding]));
+    println!("{}", m.format(Msg::InfoChecksum, &[&info.checksum]));
+    println!("{}", m.format(Msg::InfoCompression, &[&info.compression]));
+    println!(
+        "{}",
+        m.format(Msg::InfoThreshold, &[&info.compression_threshold])
+    );
+    println!(
+        "{}",
+        m.format(Msg::InfoCharsetLength, &[&info.charset.len()])
+    );
     println!();
-    println!("字符集:");
+    println!("{}", m.get(Msg::InfoCharset));
     println!("  {}", info.charset);
 }
 
-fn encode_command(input: &str, allow_compression: bool, ttl_seconds: Option<u32>) {
+/// 按当前输出格式打印错误并退出，文本格式下附加提示行
+fn report(m: &Messages, kind: &str, message: &str, hint: Option<String>, code: i32) -> ! {
+    if output::structured() {
+        eprint!("{}", output::render(&output::error(kind, message, code)));
+    } else {
+        eprintln!("{}", m.error(&message));
+        if let Some(hint) = hint {
+            eprintln!("{}", hint);
+        }
+    }
+    process::exit(code);
+}
+
+/// 打印本地化的用法错误并退出
+fn fail(m: &Messages, msg: Msg) -> ! {
+    report(m, "Usage", m.get(msg), None, exit::USAGE);
+}
+
+/// 打印子命令的用法错误和帮助提示并退出
+fn usage_error(m: &Messages, cmd: &Command, message: &str) -> ! {
+    let hint = m.format(Msg::UsageHint, &[&cmd.name]);
+    report(m, "Usage", message, Some(hint), exit::USAGE);
+}
+
+/// 打印输入无效的错误并退出
+fn fail_invalid(m: &Messages, kind: &str, msg: Msg) -> ! {
+    report(m, kind, m.get(msg), None, exit::INVALID);
+}
+
+/// 打印读写文件失败的错误并退出
+fn fail_io(m: &Messages, msg: Msg, path: &str, e: std::io::Error) -> ! {
+    report(m, "Io", &m.format(msg, &[&path, &e]), None, exit::INVALID);
+}
+
+/// 打印库错误并按错误类别退出
+fn fail_with(m: &Messages, e: &DxError) -> ! {
+    let kind = format!("{:?}", e.kind());
+    report(m, &kind, &m.dx_message(e), None, exit::for_error(e));
+}
+
+/// 按当前输出格式打印结构化结果
+fn print_value(value: &Value) {
+    print!("{}", output::render(value));
+}
+
+/// 存储的和重新计算的 CRC16（`0x1234` 格式）
+fn checksums(encoded: &str) -> (Option<String>, Option<String>) {
+    match DECODER.get_checksum(encoded) {
+        Ok((stored, computed)) => (
+            Some(format!("0x{:04X}", stored)),
+            Some(format!("0x{:04X}", computed)),
+        ),
+        Err(_) => (None, None),
+    }
+}
+
+fn encode_command(
+    m: &Messages,
+    input: &[u8],
+    engine: &Engine,
+    allow_compression: bool,
+    ttl_seconds: Option<u32>,
+    output: Option<&str>,
+) {
     let encoded = if let Some(ttl) = ttl_seconds {
-        encode_str_with_ttl(input, ttl)
+        engine.encode_with_ttl_and_options(input, ttl, allow_compression)
     } else {
-        encode_str_with_options(input, allow_compression)
+        engine.encode_with_options(input, allow_compression)
     };
-    println!("{}", encoded);
+    if output::structured() {
+        let value = json!({
+            "encoded": encoded,
+            "compressed": DECODER.is_compressed(&encoded).ok(),
+            "ttl": DECODER.get_ttl_info(&encoded).ok().flatten(),
+        });
+        write_output(m, output, output::render(&value).as_bytes());
+        return;
+    }
+    write_output(m, output, format!("{}\n", encoded).as_bytes());
 
     // 显示状态信息
-    if let Ok(compressed) = is_compressed(&encoded) {
+    if let Ok(compressed) = DECODER.is_compressed(&encoded) {
         if compressed {
-            eprintln!("📦 已压缩");
+            eprintln!("{}", m.get(Msg::Compressed));
         }
     }
     if ttl_seconds.is_some() {
-        if let Ok(Some(info)) = get_ttl_info(&encoded) {
+        if let Ok(Some(info)) = DECODER.get_ttl_info(&encoded) {
             if info.ttl_seconds == 0 {
-                eprintln!("⏰ TTL: 永不过期");
+                eprintln!("{}", m.get(Msg::TtlNever));
             } else {
-                eprintln!("⏰ TTL: {} 秒", info.ttl_seconds);
+                eprintln!("{}", m.format(Msg::TtlSeconds, &[&info.ttl_seconds]));
             }
         }
     }
 }
 
-fn decode_command(input: &str, check_ttl: bool) {
-    match decode_str_with_options(input.trim(), check_ttl) {
-        Ok(decoded) => println!("{}", decoded),
-        Err(e) => {
-            eprintln!("错误: {}", e);
-            process::exit(1);
+fn decode_command(m: &Messages, input: &str, check_ttl: bool, matches: &Matches) {
+    let mut decoded = DECODER
+        .decode_with_options(input.trim(), check_ttl)
+        .unwrap_or_else(|e| fail_with(m, &e));
+    if matches.flag("text") {
+        if let Err(e) = std::str::from_utf8(&decoded) {
+            fail_with(m, &DxError::from(e));
+        }
+    }
+    let output = matches.value("output");
+    if output::structured() {
+        let value = decoded_value(decoded);
+        write_output(m, output, output::render(&value).as_bytes());
+        return;
+    }
+
+    // 终端上只输出文本，并补上结尾换行；文件和管道按原始字节输出
+    if is_stdout(output) && io::stdout_is_terminal() {
+        if !matches.flag("force") && io::is_binary(&decoded) {
+            fail_invalid(m, "BinaryOutput", Msg::BinaryToTerminal);
+        }
+        if !decoded.is_empty() && !decoded.ends_with(b"\n") {
+            decoded.push(b'\n');
+        }
+    }
+    write_output(m, output, &decoded);
+}
+
+fn run_decode_batch(m: &Messages, cmd: &Command, matches: &Matches) {
+    let check_ttl = !matches.flag("ignore-ttl");
+    let text = matches.flag("text");
+    let refuse_binary = !output::structured()
+        && is_stdout(matches.value("output"))
+        && io::stdout_is_terminal()
+        && !matches.flag("force");
+
+    let decode = |record: &[u8]| {
+        let token = std::str::from_utf8(record)
+            .map_err(|_| invalid_failure(m, "InvalidUtf8", Msg::InputNotUtf8))?;
+        let data = DECODER
+            .decode_with_options(token.trim(), check_ttl)
+            .map_err(|e| dx_failure(m, &e))?;
+        if text {
+            if let Err(e) = std::str::from_utf8(&data) {
+                return Err(dx_failure(m, &DxError::from(e)));
+            }
+        }
+        if refuse_binary && io::is_binary(&data) {
+            return Err(invalid_failure(m, "BinaryOutput", Msg::BinaryToTerminal));
         }
+        Ok(data)
+    };
+    let delimiter = batch_delimiter(matches).unwrap_or(b'\n');
+    run_batch(m, cmd, matches, delimiter, decode, decoded_value);
+}
+
+/// 解码结果的结构化输出：UTF-8 文本原样输出，否则输出十六进制
+fn decoded_value(decoded: Vec<u8>) -> Value {
+    let size = decoded.len();
+    match String::from_utf8(decoded) {
+        Ok(text) => json!({ "size": size, "encoding": "utf8", "data": text }),
+        Err(e) => json!({ "size": size, "encoding": "hex", "data": output::hex(e.as_bytes()) }),
     }
 }
 
-fn check_command(input: &str) {
+fn check_command(m: &Messages, input: &str) {
     let trimmed = input.trim();
-    let is_valid = is_encoded(trimmed);
+    let is_valid = DECODER.is_encoded(trimmed);
+    if output::structured() {
+        if is_valid {
+            let (crc16, _) = checksums(trimmed);
+            print_value(&json!({
+                "valid": true,
+                "compressed": DECODER.is_compressed(trimmed).ok(),
+                "crc16": crc16,
+                "header": DECODER.get_header(trimmed).ok(),
+                "ttl": DECODER.get_ttl_info(trimmed).ok().flatten(),
+            }));
+            return;
+        }
+        print_value(&json!({ "valid": false }));
+        process::exit(exit::INVALID);
+    }
     if is_valid {
-        println!("✅ 是有效的 DX 编码");
+        println!("{}", m.get(Msg::CheckValid));
 
         // 显示额外信息
-        if let Ok(compressed) = is_compressed(trimmed) {
+        if let Ok(compressed) = DECODER.is_compressed(trimmed) {
             if compressed {
-                println!("   📦 数据已压缩");
+                println!("   {}", m.get(Msg::DataCompressed));
             } else {
-                println!("   📄 数据未压缩");
+                println!("   {}", m.get(Msg::DataUncompressed));
             }
         }
 
-        if let Ok(has) = has_ttl(trimmed) {
+        if let Ok(has) = DECODER.has_ttl(trimmed) {
             if has {
-                if let Ok(Some(info)) = get_ttl_info(trimmed) {
+                if let Ok(Some(info)) = DECODER.get_ttl_info(trimmed) {
                     if info.ttl_seconds == 0 {
-                        println!("   ⏰ TTL: 永不过期");
+                        println!("   {}", m.get(Msg::TtlNever));
                     } else if info.is_expired {
-                        println!("   ⏰ TTL: 已过期");
+                        println!("   {}", m.get(Msg::TtlExpired));
                     } else {
-                        println!("   ⏰ TTL: {} 秒 (未过期)", info.ttl_seconds);
+                        println!("   {}", m.format(Msg::TtlNotExpired, &[&info.ttl_seconds]));
                     }
                 }
             }
         }
     } else {
-        println!("❌ 不是有效的 DX 编码");
-        process::exit(1);
+        println!("{}", m.get(Msg::CheckInvalid));
+        process::exit(exit::INVALID);
     }
 }
 
-fn verify_command(input: &str) {
+fn verify_command(m: &Messages, input: &str) {
     let trimmed = input.trim();
 
-    match verify(trimmed) {
+    match DECODER.verify(trimmed) {
+        Ok(valid) if output::structured() => {
+            let (crc16, computed) = checksums(trimmed);
+            print_value(&json!({
+                "valid": valid,
+                "crc16": crc16,
+                "computed_crc16": computed,
+                "compressed": DECODER.is_compressed(trimmed).ok(),
+                "ttl": DECODER.get_ttl_info(trimmed).ok().flatten(),
+            }));
+            if !valid {
+                process::exit(exit::CHECKSUM);
+            }
+        }
         Ok(true) => {
+            println!("{}", m.get(Msg::ChecksumOk));
+
             // 获取校验和详情
-            if let Ok((stored, _computed)) = get_checksum(trimmed) {
-                println!("✅ 校验和验证通过");
+            if let Ok((stored, _computed)) = DECODER.get_checksum(trimmed) {
                 println!("   CRC16: 0x{:04X}", stored);
 
                 // 显示压缩状态
-                if let Ok(compressed) = is_compressed(trimmed) {
+                if let Ok(compressed) = DECODER.is_compressed(trimmed) {
                     if compressed {
-                        println!("   📦 数据已压缩");
+                        println!("   {}", m.get(Msg::DataCompressed));
                     }
                 }
 
                 // 显示 TTL 状态
-                if let Ok(Some(info)) = get_ttl_info(trimmed) {
+                if let Ok(Some(info)) = DECODER.get_ttl_info(trimmed) {
                     if info.ttl_seconds == 0 {
-                        println!("   ⏰ TTL: 永不过期");
+                        println!("   {}", m.get(Msg::TtlNever));
                     } else if info.is_expired {
-                        println!("   ⏰ TTL: 已过期");
+                        println!("   {}", m.get(Msg::TtlExpired));
                     } else {
-                        println!("   ⏰ TTL: {} 秒 (有效)", info.ttl_seconds);
+                        println!("   {}", m.format(Msg::TtlValid, &[&info.ttl_seconds]));
                     }
                 }
-            } else {
-                println!("✅ 校验和验证通过");
             }
         }
         Ok(false) => {
             // 校验和不匹配
-            if let Ok((stored, computed)) = get_checksum(trimmed) {
-                println!("❌ 校验和验证失败");
-                println!("   存储的 CRC16: 0x{:04X}", stored);
-                println!("   计算的 CRC16: 0x{:04X}", computed);
-                println!("   数据可能已被篡改或损坏");
-            } else {
-                println!("❌ 校验和验证失败");
+            println!("{}", m.get(Msg::ChecksumFailed));
+            if let Ok((stored, computed)) = DECODER.get_checksum(trimmed) {
+                let stored = format!("{:04X}", stored);
+                let computed = format!("{:04X}", computed);
+                println!("   {}", m.format(Msg::StoredCrc, &[&stored]));
+                println!("   {}", m.format(Msg::ComputedCrc, &[&computed]));
+                println!("   {}", m.get(Msg::MaybeTampered));
             }
-            process::exit(1);
-        }
-        Err(e) => {
-            eprintln!("错误: {}", e);
-            process::exit(1);
+            process::exit(exit::CHECKSUM);
         }
+        Err(e) => fail_with(m, &e),
     }
 }
 
-fn ttl_command(input: &str) {
+fn ttl_command(m: &Messages, input: &str) {
     let trimmed = input.trim();
 
-    match has_ttl(trimmed) {
+    match DECODER.has_ttl(trimmed) {
+        Ok(has_ttl) if output::structured() => {
+            let info = DECODER.get_ttl_info(trimmed).ok().flatten();
+            let remaining = info
+                .as_ref()
+                .and_then(|i| i.expires_at)
+                .map(|expires| expires.saturating_sub(unix_now()));
+            print_value(&json!({
+                "has_ttl": has_ttl,
+                "ttl": info,
+                "remaining_seconds": remaining,
+            }));
+        }
         Ok(true) => {
-            if let Ok(Some(info)) = get_ttl_info(trimmed) {
-                println!("⏰ TTL 信息");
+            if let Ok(Some(info)) = DECODER.get_ttl_info(trimmed) {
+                println!("{}", m.get(Msg::TtlInfoTitle));
                 println!();
 
-                // 格式化时间戳
-                let created_str = format_timestamp(info.created_at);
-                println!("   创建时间:   {} ({})", created_str, info.created_at);
+                let created_str = time::rfc3339(info.created_at);
+                println!(
+                    "   {}",
+                    m.format(Msg::TtlCreated, &[&created_str, &info.created_at])
+                );
 
                 if info.ttl_seconds == 0 {
-                    println!("   有效期:     永不过期");
-                    println!("   状态:       ✅ 永久有效");
+                    println!("   {}", m.get(Msg::TtlLifetimeNever));
+                    println!("   {}", m.get(Msg::TtlStatusPermanent));
                 } else {
-                    println!("   有效期:     {} 秒", info.ttl_seconds);
+                    let lifetime = format_duration(Duration::from_secs(info.ttl_seconds.into()));
+                    println!(
+                        "   {}",
+                        m.format(Msg::TtlLifetime, &[&lifetime, &info.ttl_seconds])
+                    );
 
                     if let Some(expires) = info.expires_at {
-                        let expires_str = format_timestamp(expires);
-                        println!("   过期时间:   {} ({})", expires_str, expires);
+                        let expires_str = time::rfc3339(expires);
+                        println!(
+                            "   {}",
+                            m.format(Msg::TtlExpires, &[&expires_str, &expires])
+                        );
                     }
 
                     if info.is_expired {
-                        println!("   状态:       ❌ 已过期");
+                        println!("   {}", m.get(Msg::TtlStatusExpired));
                     } else {
                         // 计算剩余时间
                         if let Some(expires) = info.expires_at {
-                            let now = std::time::SystemTime::now()
-                                .duration_since(std::time::UNIX_EPOCH)
-                                .map(|d| d.as_secs())
-                                .unwrap_or(0);
-                            let remaining = expires.saturating_sub(now);
-                            println!("   剩余时间:   {} 秒", remaining);
+                            let remaining = expires.saturating_sub(unix_now());
+                            let remaining = format_duration(Duration::from_secs(remaining));
+                            println!("   {}", m.format(Msg::TtlRemaining, &[&remaining]));
                         }
-                        println!("   状态:       ✅ 有效");
+                        println!("   {}", m.get(Msg::TtlStatusValid));
                     }
                 }
             }
         }
         Ok(false) => {
-            println!("ℹ️  此编码不包含 TTL 信息");
-            println!("   数据永不过期");
-        }
-        Err(e) => {
-            eprintln!("错误: {}", e);
-            process::exit(1);
+            println!("{}", m.get(Msg::NoTtl));
+            println!("   {}", m.get(Msg::NeverExpires));
         }
+        Err(e) => fail_with(m, &e),
     }
 }
 
-fn format_timestamp(ts: u64) -> String {
-    // 简单格式化 - 如果需要更复杂的格式化可以使用 chrono crate
-    use std::time::{Duration, UNIX_EPOCH};
-    let datetime = UNIX_EPOCH + Duration::from_secs(ts);
-    match datetime.duration_since(UNIX_EPOCH) {
-        Ok(_) => {
-            // 返回 ISO 格式近似
-            format!("Unix timestamp {}", ts)
-        }
-        Err(_) => format!("{}", ts),
+fn inspect_command(m: &Messages, input: &str, raw: bool) {
+    let inspection = DECODER.inspect(input).unwrap_or_else(|e| fail_with(m, &e));
+    if let (Some(e), false) = (&inspection.error, raw) {
+        fail_with(m, e);
+    }
+    let engine = DECODER;
+    if output::structured() {
+        print_value(&inspect::value(m, engine.prefix(), &inspection));
+    } else {
+        print!("{}", inspect::text(m, engine.prefix(), &inspection));
     }
 }
 
-fn read_stdin() -> String {
-    let stdin = io::stdin();
-    let mut input = String::new();
+/// 当前 Unix 时间戳（秒）
+fn unix_now() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// 读取管道输入的原始字节，没有管道输入或输入为空时返回 `None`
+fn read_stdin(m: &Messages) -> Option<Vec<u8>> {
+    io::read_stdin()
+        .unwrap_or_else(|e| fail_io(m, Msg::ReadFailed, "-", e))
+        .filter(|data| !data.is_empty())
+}
+
+/// `--output` 是否指向标准输出
+fn is_stdout(output: Option<&str>) -> bool {
+    matches!(output, None | Some("-"))
+}
 
-    // 检查是否有管道输入
-    if atty::isnt(atty::Stream::Stdin) {
-        stdin.lock().read_to_string(&mut input).unwrap_or_default();
+/// 写出结果到 `--output` 指定的文件或标准输出
+fn write_output(m: &Messages, output: Option<&str>, data: &[u8]) {
+    let path = output.unwrap_or("-");
+    if let Err(e) = io::write_path(path, data) {
+        fail_io(m, Msg::WriteFailed, path, e);
     }
+}
+
+/// 取出 `--lang <语言>` / `--lang=<语言>` 参数，未指定时根据环境变量选择
+///
+/// `--` 之后的参数都是位置参数，不再查找
+fn take_locale(args: &mut Vec<String>) -> Locale {
+    let env_locale = Locale::from_env();
+    let Some(pos) = args
+        .iter()
+        .take_while(|a| *a != "--")
+        .position(|a| a == "--lang" || a.starts_with("--lang="))
+    else {
+        return env_locale;
+    };
 
-    input.trim().to_string()
+    let arg = args.remove(pos);
+    let value = match arg.strip_prefix("--lang=") {
+        Some(value) => value.to_string(),
+        None if pos < args.len() && args[pos] != "--" => args.remove(pos),
+        None => fail(&Messages::new(env_locale), Msg::LangRequiresValue),
+    };
+
+    Locale::parse(&value).unwrap_or_else(|| {
+        let m = Messages::new(env_locale);
+        eprintln!("{}", m.error(&m.format(Msg::UnsupportedLang, &[&value])));
+        process::exit(exit::USAGE);
+    })
 }
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-
-    // 如果没有参数，检查是否有管道输入
-    if args.len() < 2 {
-        let stdin_input = read_stdin();
-        if !stdin_input.is_empty() {
-            // 默认尝试编码
-            encode_command(&stdin_input, true, None);
-            return;
+/// 读取输入的原始字节：`--input` 指定的文件、位置参数（以空格连接）或管道输入
+fn read_input(m: &Messages, cmd: &Command, matches: &Matches) -> Option<Vec<u8>> {
+    match matches.value("input") {
+        Some(_) if !matches.args.is_empty() => usage_error(m, cmd, m.get(Msg::InputConflict)),
+        Some(path) => {
+            Some(io::read_path(path).unwrap_or_else(|e| fail_io(m, Msg::ReadFailed, path, e)))
         }
-        print_help();
-        return;
+        None if matches.args.is_empty() => read_stdin(m),
+        None => Some(matches.args.join(" ").into_bytes()),
     }
+}
 
-    let command = args[1].to_lowercase();
+/// 读取 DX 字符串输入，去除首尾空白
+fn input_or_stdin(m: &Messages, cmd: &Command, matches: &Matches, missing: Msg) -> String {
+    let input = read_input(m, cmd, matches).unwrap_or_default();
+    let input = String::from_utf8(input)
+        .unwrap_or_else(|_| fail_invalid(m, "InvalidUtf8", Msg::InputNotUtf8));
+    let input = input.trim();
+    if input.is_empty() {
+        fail(m, missing);
+    }
+    input.to_string()
+}
 
-    match command.as_str() {
-        "version" | "-v" | "-V" | "--version" => {
-            print_version();
-        }
-        "help" | "-h" | "--help" => {
-            print_help();
-        }
-        "info" | "-i" | "--info" => {
-            print_info();
-        }
-        "encode" | "e" | "-e" => {
-            // 解析选项
-            let mut allow_compression = true;
-            let mut ttl_seconds: Option<u32> = None;
-            let mut input_start_idx = 2;
-
-            let mut i = 2;
-            while i < args.len() {
-                match args[i].as_str() {
-                    "--no-compress" | "-nc" => {
-                        allow_compression = false;
-                        input_start_idx = i + 1;
-                    }
-                    "--ttl" | "-t" => {
-                        if i + 1 < args.len() {
-                            match args[i + 1].parse::<u32>() {
-                                Ok(ttl) => {
-                                    ttl_seconds = Some(ttl);
-                                    i += 1;
-                                    input_start_idx = i + 1;
-                                }
-                                Err(_) => {
-                                    eprintln!("错误: TTL 必须是正整数（秒）");
-                                    process::exit(1);
-                                }
-                            }
-                        } else {
-                            eprintln!("错误: --ttl 需要指定秒数");
-                            process::exit(1);
-                        }
-                    }
-                    _ => {
-                        if !args[i].starts_with('-') {
-                            break;
-                        }
-                    }
-                }
-                i += 1;
+/// 解析 `--ttl <时长>` 或 `--expires <时间>`，返回有效期（秒）
+fn ttl_option(m: &Messages, cmd: &Command, matches: &Matches) -> Option<u32> {
+    let secs = match (matches.value("ttl"), matches.value("expires")) {
+        (Some(_), Some(_)) => usage_error(m, cmd, m.get(Msg::TtlConflict)),
+        (Some(ttl), None) => match parse_duration(ttl) {
+            Ok(duration) => duration.as_secs(),
+            Err(e) => {
+                let message = m.format(Msg::InvalidTtl, &[&ttl, &m.duration_message(&e)]);
+                usage_error(m, cmd, &message)
             }
+        },
+        (None, Some(at)) => {
+            let at = time::parse_rfc3339(at)
+                .unwrap_or_else(|| usage_error(m, cmd, &m.format(Msg::InvalidExpires, &[&at])));
+            match at.checked_sub(unix_now()) {
+                Some(secs) if secs > 0 => secs,
+                _ => usage_error(m, cmd, m.get(Msg::ExpiresInPast)),
+            }
+        }
+        (None, None) => return None,
+    };
+    match u32::try_from(secs) {
+        Ok(secs) => Some(secs),
+        Err(_) => usage_error(m, cmd, &m.format(Msg::TtlTooLong, &[&u32::MAX])),
+    }
+}
 
-            let input = if args.len() > input_start_idx {
-                args[input_start_idx..].join(" ")
-            } else {
-                read_stdin()
+/// 按 `--no-padding`、`--wrap` 和 `--crlf` 配置输出 DX 编码的引擎
+fn encode_engine(m: &Messages, cmd: &Command, matches: &Matches) -> Engine {
+    let wrap_width = matches.value("wrap").map_or(0, |w| {
+        w.parse::<usize>()
+            .unwrap_or_else(|_| usage_error(m, cmd, m.get(Msg::WrapRequiresWidth)))
+    });
+    let line_ending = if matches.flag("crlf") {
+        LineEnding::CrLf
+    } else {
+        LineEnding::Lf
+    };
+
+    STANDARD
+        .with_encode_padding(!matches.flag("no-padding"))
+        .with_line_wrap(wrap_width, line_ending)
+}
+
+fn run_encode(m: &Messages, cmd: &Command, matches: &Matches) {
+    let ttl_seconds = ttl_option(m, cmd, matches);
+    let engine = encode_engine(m, cmd, matches);
+    let allow_compression = !matches.flag("no-compress");
+    let text = matches.flag("text");
+
+    if let Some(delimiter) = batch_delimiter(matches) {
+        // 换行会把一条记录拆成多行，输出与输入不再一一对应
+        if matches.value("wrap").is_some() || matches.flag("crlf") {
+            usage_error(m, cmd, m.get(Msg::WrapInBatch));
+        }
+        let encode = |record: &[u8]| {
+            if text && std::str::from_utf8(record).is_err() {
+                return Err(invalid_failure(m, "InvalidUtf8", Msg::InputNotUtf8));
+            }
+            let encoded = match ttl_seconds {
+                Some(ttl) => engine.encode_with_ttl_and_options(record, ttl, allow_compression),
+                None => engine.encode_with_options(record, allow_compression),
             };
+            Ok(encoded.into_bytes())
+        };
+        let to_value = |encoded: Vec<u8>| json!({ "encoded": String::from_utf8_lossy(&encoded) });
+        return run_batch(m, cmd, matches, delimiter, encode, to_value);
+    }
+
+    let input = read_input(m, cmd, matches).unwrap_or_else(|| fail(m, Msg::MissingEncodeInput));
+    if text && std::str::from_utf8(&input).is_err() {
+        fail_invalid(m, "InvalidUtf8", Msg::InputNotUtf8);
+    }
+    encode_command(
+        m,
+        &input,
+        &engine,
+        allow_compression,
+        ttl_seconds,
+        matches.value("output"),
+    );
+}
+
+/// 库错误对应的失败记录
+fn dx_failure(m: &Messages, e: &DxError) -> Failure {
+    Failure {
+        kind: format!("{:?}", e.kind()),
+        message: m.dx_message(e),
+        exit_code: exit::for_error(e),
+    }
+}
+
+/// 输入无效的失败记录
+fn invalid_failure(m: &Messages, kind: &str, msg: Msg) -> Failure {
+    Failure {
+        kind: kind.to_string(),
+        message: m.get(msg).to_string(),
+        exit_code: exit::INVALID,
+    }
+}
+
+/// 批处理模式的分隔符，未指定 `--lines` / `-0` 时返回 `None`
+fn batch_delimiter(matches: &Matches) -> Option<u8> {
+    if matches.flag("null") {
+        Some(0)
+    } else if matches.flag("lines") {
+        Some(b'\n')
+    } else {
+        None
+    }
+}
+
+/// 逐条处理记录并按输入顺序输出
+///
+/// `process` 返回写出的原始字节，`to_value` 把结果转换为结构化输出的字段
+fn run_batch<F, V>(
+    m: &Messages,
+    cmd: &Command,
+    matches: &Matches,
+    delimiter: u8,
+    process: F,
+    to_value: V,
+) where
+    F: Fn(&[u8]) -> Result<Vec<u8>, Failure> + Sync,
+    V: Fn(Vec<u8>) -> Value,
+{
+    let policy = match (matches.flag("skip-errors"), matches.flag("fail-fast")) {
+        (true, true) => usage_error(m, cmd, m.get(Msg::PolicyConflict)),
+        (true, false) => ErrorPolicy::Skip,
+        (false, true) => ErrorPolicy::FailFast,
+        (false, false) => ErrorPolicy::Report,
+    };
+    let jobs = match matches.value("jobs") {
+        Some(jobs) => jobs
+            .parse::<usize>()
+            .ok()
+            .filter(|&j| j > 0)
+            .unwrap_or_else(|| usage_error(m, cmd, m.get(Msg::JobsMustBePositive))),
+        None => std::thread::available_parallelism().map_or(1, |n| n.get()),
+    };
 
-            if input.is_empty() {
-                eprintln!("错误: 请提供要编码的文本");
-                process::exit(1);
+    let input = read_input(m, cmd, matches).unwrap_or_default();
+    let records = batch::records(&input, delimiter);
+    let path = matches.value("output").unwrap_or("-");
+    let mut out = io::create(path).unwrap_or_else(|e| fail_io(m, Msg::WriteFailed, path, e));
+    let location = if delimiter == 0 {
+        Msg::BatchRecord
+    } else {
+        Msg::BatchLine
+    };
+
+    let (mut failed, mut first_code, mut write_error) = (0, None, None);
+    batch::process(&records, jobs, process, |i, result| {
+        let written = match result {
+            Ok(data) if output::structured() => {
+                let mut value = to_value(data);
+                value["line"] = (i + 1).into();
+                out.write_all(output::render(&value).as_bytes())
+            }
+            Ok(data) => out
+                .write_all(&data)
+                .and_then(|_| out.write_all(&[delimiter])),
+            Err(failure) => {
+                failed += 1;
+                first_code.get_or_insert(failure.exit_code);
+                if output::structured() {
+                    let mut value =
+                        output::error(&failure.kind, &failure.message, failure.exit_code);
+                    value["line"] = (i + 1).into();
+                    eprint!("{}", output::render(&value));
+                } else {
+                    let message = m.format(location, &[&(i + 1), &failure.message]);
+                    eprintln!("{}", m.error(&message));
+                }
+                match policy {
+                    ErrorPolicy::FailFast => return ControlFlow::Break(()),
+                    // 输出空记录占位，保持与输入逐行对应
+                    ErrorPolicy::Report if !output::structured() => out.write_all(&[delimiter]),
+                    _ => Ok(()),
+                }
+            }
+        };
+        match written {
+            Ok(()) => ControlFlow::Continue(()),
+            Err(e) => {
+                write_error = Some(e);
+                ControlFlow::Break(())
             }
+        }
+    });
 
-            encode_command(&input, allow_compression, ttl_seconds);
+    if let Some(e) = write_error.or_else(|| out.flush().err()) {
+        fail_io(m, Msg::WriteFailed, path, e);
+    }
+    if failed > 0 && !output::structured() {
+        eprintln!(
+            "{}",
+            m.format(Msg::BatchSummary, &[&records.len(), &failed])
+        );
+    }
+    match first_code {
+        Some(code) if policy != ErrorPolicy::Skip => process::exit(code),
+        _ => {}
+    }
+}
+
+/// 打印文档处理错误并退出
+fn fail_document(m: &Messages, cmd: &Command, e: DocError) -> ! {
+    match e {
+        DocError::Parse(message) => {
+            let message = m.format(Msg::DocumentInvalid, &[&cmd.name.to_uppercase(), &message]);
+            report(m, "InvalidDocument", &message, None, exit::INVALID);
+        }
+        DocError::Unterminated(line) => {
+            let message = m.format(Msg::CsvUnterminated, &[&line]);
+            report(m, "InvalidDocument", &message, None, exit::INVALID);
         }
-        "decode" | "d" | "-d" => {
-            let mut check_ttl = true;
-            let mut input_idx = 2;
+        DocError::ColumnNotFound(column) => {
+            usage_error(m, cmd, &m.format(Msg::ColumnNotFound, &[&column]))
+        }
+        DocError::Transform(e @ TransformError::InvalidSelector { .. }) => {
+            usage_error(m, cmd, &m.transform_message(&e))
+        }
+        DocError::Transform(e) => {
+            let (kind, code) = match &e {
+                TransformError::Field { error, .. } => {
+                    (format!("{:?}", error.kind()), exit::for_error(error))
+                }
+                _ => ("NotAString".to_string(), exit::INVALID),
+            };
+            report(m, &kind, &m.transform_message(&e), None, code);
+        }
+        DocError::CsvField {
+            line,
+            column,
+            error,
+        } => {
+            let message = m.format(Msg::CsvField, &[&line, &column, &m.dx_message(&error)]);
+            let kind = format!("{:?}", error.kind());
+            report(m, &kind, &message, None, exit::for_error(&error));
+        }
+    }
+}
 
-            if args.len() > 2 && (args[2] == "--ignore-ttl" || args[2] == "-it") {
-                check_ttl = false;
-                input_idx = 3;
-            }
+/// `dxc json|toml|csv <encode|decode>`：改写文档中选中的字段
+fn run_document(m: &Messages, cmd: &Command, matches: &Matches) {
+    let op = match matches.args.first().map(String::as_str) {
+        Some("encode") => FieldOp::Encode {
+            allow_compression: !matches.flag("no-compress"),
+        },
+        Some("decode") => FieldOp::Decode {
+            check_ttl: !matches.flag("ignore-ttl"),
+        },
+        Some(action) => usage_error(m, cmd, &m.format(Msg::UnknownAction, &[&action])),
+        None => usage_error(m, cmd, m.get(Msg::MissingAction)),
+    };
+    let selectors = match cmd.name {
+        "csv" => matches.values("column"),
+        _ => matches.values("path"),
+    };
+    if selectors.is_empty() {
+        let missing = match cmd.name {
+            "csv" => Msg::MissingColumn,
+            _ => Msg::MissingPath,
+        };
+        usage_error(m, cmd, m.get(missing));
+    }
+    if cmd.name != "csv" {
+        if let Some(e) = selectors.iter().find_map(|s| Selector::parse(s).err()) {
+            usage_error(m, cmd, &m.transform_message(&e));
+        }
+    }
 
-            let input = if args.len() > input_idx {
-                args[input_idx].clone()
-            } else {
-                read_stdin()
+    let path = matches.args.get(1).map_or("-", String::as_str);
+    let input = io::read_path(path).unwrap_or_else(|e| fail_io(m, Msg::ReadFailed, path, e));
+    let input = String::from_utf8(input)
+        .unwrap_or_else(|_| fail_invalid(m, "InvalidUtf8", Msg::InputNotUtf8));
+
+    let result = match cmd.name {
+        "json" => document::json(&input, &selectors, op, &DECODER),
+        "toml" => document::toml(&input, &selectors, op, &DECODER),
+        _ => {
+            let delimiter = match matches.value("delimiter") {
+                None => b',',
+                Some("\\t") => b'\t',
+                Some(d) if d.len() == 1 && d.is_ascii() => d.as_bytes()[0],
+                Some(_) => usage_error(m, cmd, m.get(Msg::InvalidDelimiter)),
             };
+            let header = !matches.flag("no-header");
+            document::csv(&input, &selectors, delimiter, header, op, &DECODER)
+        }
+    };
+    let document = result.unwrap_or_else(|e| fail_document(m, cmd, e));
+    let output = matches.value("output");
+    if output::structured() {
+        let value = json!({ "document": document });
+        write_output(m, output, output::render(&value).as_bytes());
+    } else {
+        write_output(m, output, document.as_bytes());
+    }
+}
 
-            if input.is_empty() {
-                eprintln!("错误: 请提供要解码的 DX 字符串");
-                process::exit(1);
-            }
+/// `dxc scan`：逐个文件查找 DX 字符串，读取失败的文件报告后继续，最后以 1 退出
+fn run_scan(m: &Messages, cmd: &Command, matches: &Matches) {
+    let expired_only = matches.flag("expired-only");
+    let decode = matches.flag("decode");
+    let mut stdout = std::io::stdout().lock();
+    let (mut files, mut matched_files, mut total, mut failed) = (0, 0, 0, false);
 
-            decode_command(&input, check_ttl);
+    let mut scan_source = |file: &str, data: std::io::Result<Vec<u8>>| {
+        files += 1;
+        let data = match data {
+            Ok(data) => data,
+            Err(e) => {
+                failed = true;
+                let message = m.format(Msg::ReadFailed, &[&file, &e]);
+                if output::structured() {
+                    let error = output::error("Io", &message, exit::INVALID);
+                    eprint!("{}", output::render(&error));
+                } else {
+                    eprintln!("{}", m.error(&message));
+                }
+                return;
+            }
+        };
+        let text = String::from_utf8_lossy(&data);
+        let hits: Vec<_> = scan::hits(&text)
+            .into_iter()
+            .filter(|hit| !expired_only || hit.expired())
+            .collect();
+        if !hits.is_empty() {
+            matched_files += 1;
         }
-        "check" | "c" | "-c" => {
-            let input = if args.len() > 2 {
-                args[2].clone()
+        for hit in &hits {
+            total += 1;
+            let out = if output::structured() {
+                output::render(&scan::value(file, hit, decode))
             } else {
-                read_stdin()
+                scan::text(m, file, hit, decode)
             };
-
-            if input.is_empty() {
-                eprintln!("错误: 请提供要检查的字符串");
-                process::exit(1);
+            if let Err(e) = stdout.write_all(out.as_bytes()) {
+                fail_io(m, Msg::WriteFailed, "-", e);
             }
+        }
+    };
 
-            check_command(&input);
+    if matches.args.is_empty() {
+        match read_stdin(m) {
+            Some(data) => scan_source("-", Ok(data)),
+            None => usage_error(m, cmd, m.get(Msg::MissingScanInput)),
         }
-        "verify" | "v" => {
-            let input = if args.len() > 2 {
-                args[2].clone()
-            } else {
-                read_stdin()
-            };
+    }
+    for arg in &matches.args {
+        if arg == "-" {
+            scan_source(arg, io::read_path(arg));
+            continue;
+        }
+        let (mut paths, mut errors) = (Vec::new(), Vec::new());
+        scan::collect_files(std::path::Path::new(arg), &mut paths, &mut errors);
+        for (path, e) in errors {
+            scan_source(&path.display().to_string(), Err(e));
+        }
+        for path in paths {
+            scan_source(&path.display().to_string(), std::fs::read(&path));
+        }
+    }
 
-            if input.is_empty() {
-                eprintln!("错误: 请提供要验证的 DX 字符串");
-                process::exit(1);
-            }
+    if !output::structured() {
+        eprintln!(
+            "{}",
+            m.format(Msg::ScanSummary, &[&matched_files, &files, &total])
+        );
+    }
+    if failed {
+        process::exit(exit::INVALID);
+    }
+}
+
+fn run_convert(m: &Messages, cmd: &Command, matches: &Matches) {
+    let encoding = |long| {
+        let name = matches
+            .value(long)
+            .unwrap_or_else(|| usage_error(m, cmd, m.get(Msg::MissingEncoding)));
+        Encoding::from_name(name)
+            .unwrap_or_else(|| usage_error(m, cmd, &m.format(Msg::UnknownEncoding, &[&name])))
+    };
+    let (from, to) = (encoding("from"), encoding("to"));
+    let keep_header = matches.flag("keep-header");
+    if keep_header && from != Encoding::Dx && to != Encoding::Dx {
+        usage_error(m, cmd, m.get(Msg::KeepHeaderWithoutDx));
+    }
+    let engine = encode_engine(m, cmd, matches);
+    let input = input_or_stdin(m, cmd, matches, Msg::MissingConvertInput);
+
+    // 带 --keep-header 时中间结果是完整的帧，否则是解码后的数据
+    let check_ttl = !matches.flag("ignore-ttl");
+    let bytes = match from {
+        Encoding::Dx if keep_header => DECODER.to_frame_with_options(&input, check_ttl),
+        Encoding::Dx => DECODER.decode_with_options(&input, check_ttl),
+        _ => from.decode(&input),
+    }
+    .unwrap_or_else(|e| fail_with(m, &e));
+    let converted = match to {
+        Encoding::Dx if keep_header => engine
+            .from_frame(&bytes)
+            .unwrap_or_else(|e| fail_with(m, &e)),
+        Encoding::Dx => engine.encode_with_options(&bytes, !matches.flag("no-compress")),
+        _ => to.encode(&bytes),
+    };
+
+    let output = matches.value("output");
+    if output::structured() {
+        let value = json!({ "from": from.name(), "to": to.name(), "converted": converted });
+        write_output(m, output, output::render(&value).as_bytes());
+    } else {
+        write_output(m, output, format!("{}\n", converted).as_bytes());
+    }
+}
 
-            verify_command(&input);
+/// `dxc ttl --extend` / `--expires` / `--remove-ttl`：以新的 TTL 重新签发
+fn run_reissue(m: &Messages, cmd: &Command, matches: &Matches, policy: ReissuePolicy) {
+    let input = input_or_stdin(m, cmd, matches, Msg::MissingTtlInput);
+    let reissued = DECODER
+        .reissue_with_options(input.trim(), policy, !matches.flag("ignore-ttl"))
+        .unwrap_or_else(|e| fail_with(m, &e));
+
+    let output = matches.value("output");
+    if output::structured() {
+        let ttl = DECODER.get_ttl_info(&reissued).ok().flatten();
+        let value = json!({ "encoded": reissued, "ttl": ttl });
+        write_output(m, output, output::render(&value).as_bytes());
+    } else {
+        write_output(m, output, format!("{}\n", reissued).as_bytes());
+    }
+}
+
+/// 解析 `dxc ttl` 的重新签发选项，只查看 TTL 信息时返回 `None`
+fn reissue_policy(m: &Messages, cmd: &Command, matches: &Matches) -> Option<ReissuePolicy> {
+    let (extend, expires) = (matches.value("extend"), matches.value("expires"));
+    let remove = matches.flag("remove-ttl");
+    if [extend.is_some(), expires.is_some(), remove]
+        .into_iter()
+        .filter(|&set| set)
+        .count()
+        > 1
+    {
+        usage_error(m, cmd, m.get(Msg::ReissueConflict));
+    }
+    if let Some(extend) = extend {
+        let duration = parse_duration(extend).unwrap_or_else(|e| {
+            let message = m.format(Msg::InvalidTtl, &[&extend, &m.duration_message(&e)]);
+            usage_error(m, cmd, &message)
+        });
+        return Some(ReissuePolicy::Extend(duration));
+    }
+    if let Some(at) = expires {
+        let at = time::parse_rfc3339(at)
+            .unwrap_or_else(|| usage_error(m, cmd, &m.format(Msg::InvalidExpires, &[&at])));
+        if at <= unix_now() {
+            usage_error(m, cmd, m.get(Msg::ExpiresInPast));
         }
-        "ttl" | "expire" | "expiry" => {
-            let input = if args.len() > 2 {
-                args[2].clone()
-            } else {
-                read_stdin()
-            };
+        return Some(ReissuePolicy::ExpiresAt(at));
+    }
+    remove.then_some(ReissuePolicy::RemoveTtl)
+}
 
-            if input.is_empty() {
-                eprintln!("错误: 请提供要检查 TTL 的 DX 字符串");
-                process::exit(1);
-            }
+/// 按 `--json` / `--format` 选择输出格式
+fn select_format(m: &Messages, cmd: &Command, matches: &Matches) -> Format {
+    if matches.flag("json") {
+        return Format::Json;
+    }
+    match matches.value("format") {
+        Some(value) => Format::parse(value)
+            .unwrap_or_else(|| usage_error(m, cmd, &m.format(Msg::InvalidFormat, &[&value]))),
+        None => Format::Text,
+    }
+}
 
-            ttl_command(&input);
+fn run(m: &Messages, cmd: &Command, matches: &Matches) {
+    output::set(select_format(m, cmd, matches));
+    time::set_utc(matches.flag("utc"));
+    match cmd.name {
+        "encode" => run_encode(m, cmd, matches),
+        "decode" if batch_delimiter(matches).is_some() => run_decode_batch(m, cmd, matches),
+        "decode" => {
+            let input = input_or_stdin(m, cmd, matches, Msg::MissingDecodeInput);
+            decode_command(m, &input, !matches.flag("ignore-ttl"), matches);
         }
+        "check" => check_command(m, &input_or_stdin(m, cmd, matches, Msg::MissingCheckInput)),
+        "verify" => verify_command(m, &input_or_stdin(m, cmd, matches, Msg::MissingVerifyInput)),
+        "ttl" => match reissue_policy(m, cmd, matches) {
+            Some(policy) => run_reissue(m, cmd, matches, policy),
+            None => ttl_command(m, &input_or_stdin(m, cmd, matches, Msg::MissingTtlInput)),
+        },
         "expired" => {
             // 快速检查是否过期
-            let input = if args.len() > 2 {
-                args[2].clone()
-            } else {
-                read_stdin()
-            };
-
-            if input.is_empty() {
-                eprintln!("错误: 请提供要检查的 DX 字符串");
-                process::exit(1);
-            }
-
-            match is_expired(input.trim()) {
-                Ok(true) => {
-                    println!("❌ 已过期");
-                    process::exit(1);
-                }
-                Ok(false) => {
-                    println!("✅ 未过期");
+            let input = input_or_stdin(m, cmd, matches, Msg::MissingCheckInput);
+            match DECODER.is_expired(input.trim()) {
+                Ok(expired) if output::structured() => {
+                    print_value(&json!({ "expired": expired }));
+                    if expired {
+                        process::exit(exit::EXPIRED);
+                    }
                 }
-                Err(e) => {
-                    eprintln!("错误: {}", e);
-                    process::exit(1);
+                Ok(true) => {
+                    println!("{}", m.get(Msg::Expired));
+                    process::exit(exit::EXPIRED);
                 }
+                Ok(false) => println!("{}", m.get(Msg::NotExpired)),
+                Err(e) => fail_with(m, &e),
             }
         }
-        _ => {
-            // 如果第一个参数不是命令，尝试将其作为要编码的文本
-            let input = args[1..].join(" ");
-            encode_command(&input, true, None);
+        "inspect" => {
+            let input = input_or_stdin(m, cmd, matches, Msg::MissingInspectInput);
+            inspect_command(m, &input, matches.flag("raw"));
+        }
+        "json" | "toml" | "csv" => run_document(m, cmd, matches),
+        "scan" => run_scan(m, cmd, matches),
+        "convert" => run_convert(m, cmd, matches),
+        "info" if output::structured() => print!("{}", output::render(&get_info())),
+        "info" => print_info(m),
+        "vectors" if output::get() == Format::Tsv => {
+            let vectors: Value = serde_json::from_str(&dxcode::vectors::to_json())
+                .expect("test vectors are valid JSON");
+            print_value(&vectors);
+        }
+        // 测试向量本身就是 JSON
+        "vectors" => print!("{}", dxcode::vectors::to_json()),
+        _ => unreachable!("command {} is declared but not handled", cmd.name),
+    }
+}
+
+fn main() {
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    let m = Messages::new(take_locale(&mut args));
+
+    let Some((first, rest)) = args.split_first() else {
+        // 没有参数时，如果有管道输入则编码
+        if let Some(input) = read_stdin(&m) {
+            encode_command(&m, &input, &STANDARD, true, None, None);
+            return;
+        }
+        print_help(&m);
+        return;
+    };
+
+    match first.as_str() {
+        "version" | "-v" | "-V" | "--version" => {
+            print_version();
+            return;
+        }
+        "help" | "-h" | "--help" => {
+            match rest.first() {
+                Some(name) => match args::find(name) {
+                    Some(cmd) => println!("{}", args::help(cmd, &m)),
+                    None => {
+                        eprintln!("{}", m.error(&m.format(Msg::UnknownCommand, &[name])));
+                        process::exit(exit::USAGE);
+                    }
+                },
+                None => print_help(&m),
+            }
+            return;
         }
+        _ => {}
+    }
+
+    output::set(Format::sniff(rest));
+    let Some(cmd) = args::find(first) else {
+        let message = m.format(Msg::UnknownCommand, &[first]);
+        let hint = m.get(Msg::HelpHint).to_string();
+        report(&m, "Usage", &message, Some(hint), exit::USAGE);
+    };
+
+    match args::parse(cmd, rest) {
+        Ok(matches) => run(&m, cmd, &matches),
+        Err(ArgError::Help) => println!("{}", args::help(cmd, &m)),
+        Err(e) => usage_error(&m, cmd, &e.message(&m, cmd)),
     }
 }
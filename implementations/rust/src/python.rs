@@ -0,0 +1,296 @@
+//! Python 绑定（需要启用 `python` feature）
+//!
+//! 使用 PyO3 导出与 `implementations/python/dxcode.py` 相同的模块接口，可以直接替换纯
+//! Python 实现：
+//!
+//! ```sh
+//! maturin build --release   # 读取 pyproject.toml，启用 python feature
+//! ```
+//!
+//! ```python
+//! import dxcode
+//!
+//! encoded = dxcode.encode("你好，Dogxi！")
+//! assert dxcode.decode(encoded) == "你好，Dogxi！"
+//! ```
+//!
+//! 错误映射为与 `dxcode.py` 相同的异常层次，均继承自 `DxEncodingError`：
+//!
+//! - `DxChecksumError`：校验和不匹配，带 `expected`、`actual` 属性
+//! - `DxCompressionError`：解压缩失败
+//! - `DxTtlExpiredError`：TTL 已过期，带 `created_at`、`ttl_seconds`、`expired_at` 属性
+//!
+//! 所有异常都带有 `kind` 属性，为错误类别的名称（如 `"InvalidPrefix"`）。
+
+use std::borrow::Cow;
+
+use pyo3::create_exception;
+use pyo3::exceptions::PyException;
+use pyo3::prelude::*;
+use pyo3::types::{PyByteArray, PyBytes, PyDict, PyString};
+
+use crate::frame::{
+    COMPRESSION_THRESHOLD, FLAG_ALGO_DEFLATE, FLAG_COMPRESSED, FLAG_HAS_TTL, HEADER_SIZE,
+    TTL_HEADER_SIZE,
+};
+use crate::{DecodePadding, DxError, Engine, Locale, CHARSET, MAGIC, PADDING, PREFIX, STANDARD};
+
+/// 与 `dxcode.py` 一样要求规范填充
+const ENGINE: Engine = STANDARD.with_decode_padding(DecodePadding::RequireCanonical);
+
+create_exception!(dxcode, DxEncodingError, PyException, "DX 编码错误");
+create_exception!(dxcode, DxChecksumError, DxEncodingError, "DX 校验和错误");
+create_exception!(
+    dxcode,
+    DxCompressionError,
+    DxEncodingError,
+    "DX 压缩/解压缩错误"
+);
+create_exception!(
+    dxcode,
+    DxTtlExpiredError,
+    DxEncodingError,
+    "DX TTL 过期错误"
+);
+
+/// 将 [`DxError`] 转换为对应的 Python 异常，消息与 `dxcode.py` 一样使用中文
+fn to_py_err(py: Python<'_>, e: DxError) -> PyErr {
+    let message = e.localized(Locale::ZhCn).to_string();
+    let (err, attrs): (PyErr, Vec<(&str, u64)>) = match e {
+        DxError::ChecksumMismatch { expected, actual } => (
+            DxChecksumError::new_err(message),
+            vec![("expected", expected.into()), ("actual", actual.into())],
+        ),
+        DxError::TtlExpired {
+            created_at,
+            ttl_seconds,
+            expired_at,
+        } => (
+            DxTtlExpiredError::new_err(message),
+            vec![
+                ("created_at", created_at),
+                ("ttl_seconds", ttl_seconds.into()),
+                ("expired_at", expired_at),
+            ],
+        ),
+        DxError::CompressionError(_) => (DxCompressionError::new_err(message), vec![]),
+        _ => (DxEncodingError::new_err(message), vec![]),
+    };
+    let value = err.value(py);
+    let _ = value.setattr("kind", format!("{:?}", e.kind()));
+    for (name, v) in attrs {
+        let _ = value.setattr(name, v);
+    }
+    err
+}
+
+/// 读取 `str`（按 UTF-8 编码）、`bytes` 或 `bytearray`
+fn to_bytes<'a>(data: &'a Bound<'_, PyAny>) -> PyResult<Cow<'a, [u8]>> {
+    if let Ok(s) = data.cast::<PyString>() {
+        Ok(Cow::Owned(s.to_str()?.as_bytes().to_vec()))
+    } else if let Ok(b) = data.cast::<PyBytes>() {
+        Ok(Cow::Borrowed(b.as_bytes()))
+    } else if let Ok(b) = data.cast::<PyByteArray>() {
+        Ok(Cow::Owned(b.to_vec()))
+    } else {
+        Err(DxEncodingError::new_err(
+            "输入必须是 str、bytes 或 bytearray",
+        ))
+    }
+}
+
+/// TTL 信息
+#[pyclass(name = "TtlInfo", module = "dxcode", frozen, eq, get_all)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PyTtlInfo {
+    /// 创建时间（Unix 时间戳，秒）
+    created_at: u64,
+    /// 有效期（秒），0 表示永不过期
+    ttl_seconds: u32,
+    /// 过期时间（Unix 时间戳，秒），`None` 表示永不过期
+    expires_at: Option<u64>,
+    /// 是否已过期
+    is_expired: bool,
+}
+
+#[pymethods]
+impl PyTtlInfo {
+    #[new]
+    fn new(created_at: u64, ttl_seconds: u32, expires_at: Option<u64>, is_expired: bool) -> Self {
+        PyTtlInfo {
+            created_at,
+            ttl_seconds,
+            expires_at,
+            is_expired,
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        let expires_at = self
+            .expires_at
+            .map_or_else(|| "None".to_string(), |t| t.to_string());
+        let is_expired = if self.is_expired { "True" } else { "False" };
+        format!(
+            "TtlInfo(created_at={}, ttl_seconds={}, expires_at={}, is_expired={})",
+            self.created_at, self.ttl_seconds, expires_at, is_expired
+        )
+    }
+}
+
+/// 计算 CRC16-CCITT 校验和
+#[pyfunction]
+fn crc16(data: &Bound<'_, PyAny>) -> PyResult<u16> {
+    Ok(crate::crc16(&to_bytes(data)?))
+}
+
+/// 将数据编码为 DX 格式（带 CRC16 校验和和智能压缩）
+#[pyfunction]
+#[pyo3(signature = (data, allow_compression = true))]
+fn dx_encode(py: Python<'_>, data: &Bound<'_, PyAny>, allow_compression: bool) -> PyResult<String> {
+    let data = to_bytes(data)?;
+    Ok(py.detach(|| ENGINE.encode_with_options(&data, allow_compression)))
+}
+
+/// 使用 TTL 编码数据，`ttl_seconds` 为 0 表示永不过期
+#[pyfunction]
+#[pyo3(signature = (data, ttl_seconds, allow_compression = true))]
+fn dx_encode_with_ttl(
+    py: Python<'_>,
+    data: &Bound<'_, PyAny>,
+    ttl_seconds: u32,
+    allow_compression: bool,
+) -> PyResult<String> {
+    let data = to_bytes(data)?;
+    Ok(py.detach(|| ENGINE.encode_with_ttl_and_options(&data, ttl_seconds, allow_compression)))
+}
+
+/// 将 DX 编码的字符串解码（带校验和验证，自动解压缩）
+#[pyfunction]
+#[pyo3(signature = (encoded, as_string = true, check_ttl = true))]
+fn dx_decode<'py>(
+    py: Python<'py>,
+    encoded: &str,
+    as_string: bool,
+    check_ttl: bool,
+) -> PyResult<Bound<'py, PyAny>> {
+    let data = py
+        .detach(|| ENGINE.decode_with_options(encoded, check_ttl))
+        .map_err(|e| to_py_err(py, e))?;
+    if !as_string {
+        return Ok(PyBytes::new(py, &data).into_any());
+    }
+    let s = String::from_utf8(data).map_err(|e| to_py_err(py, e.into()))?;
+    Ok(PyString::new(py, &s).into_any())
+}
+
+/// 检查字符串是否为有效的 DX 编码（非字符串返回 `False`）
+#[pyfunction]
+fn is_dx_encoded(s: &Bound<'_, PyAny>) -> bool {
+    s.cast::<PyString>()
+        .ok()
+        .and_then(|s| s.to_str().ok().map(|s| ENGINE.is_encoded(s)))
+        .unwrap_or(false)
+}
+
+/// 验证 DX 编码的校验和，格式无效时抛出异常
+#[pyfunction]
+fn dx_verify(py: Python<'_>, encoded: &str) -> PyResult<bool> {
+    ENGINE.verify(encoded).map_err(|e| to_py_err(py, e))
+}
+
+/// 获取 `(存储的校验和, 计算的校验和)`
+#[pyfunction]
+fn get_checksum(py: Python<'_>, encoded: &str) -> PyResult<(u16, u16)> {
+    ENGINE.get_checksum(encoded).map_err(|e| to_py_err(py, e))
+}
+
+/// 检查编码是否使用了压缩
+#[pyfunction]
+fn is_compressed(py: Python<'_>, encoded: &str) -> PyResult<bool> {
+    ENGINE.is_compressed(encoded).map_err(|e| to_py_err(py, e))
+}
+
+/// 检查编码是否包含 TTL 信息
+#[pyfunction]
+fn has_ttl(py: Python<'_>, encoded: &str) -> PyResult<bool> {
+    ENGINE.has_ttl(encoded).map_err(|e| to_py_err(py, e))
+}
+
+/// 获取编码的 TTL 信息，没有 TTL 返回 `None`
+#[pyfunction]
+fn get_ttl_info(py: Python<'_>, encoded: &str) -> PyResult<Option<PyTtlInfo>> {
+    let info = ENGINE.get_ttl_info(encoded).map_err(|e| to_py_err(py, e))?;
+    Ok(info.map(|info| PyTtlInfo {
+        created_at: info.created_at,
+        ttl_seconds: info.ttl_seconds,
+        expires_at: info.expires_at,
+        is_expired: info.is_expired,
+    }))
+}
+
+/// 检查编码是否已过期（没有 TTL 的数据返回 `False`）
+#[pyfunction]
+fn is_expired(py: Python<'_>, encoded: &str) -> PyResult<bool> {
+    ENGINE.is_expired(encoded).map_err(|e| to_py_err(py, e))
+}
+
+/// 获取 DX 编码的信息
+#[pyfunction]
+fn get_dx_info(py: Python<'_>) -> PyResult<Bound<'_, PyDict>> {
+    let info = crate::get_info();
+    let dict = PyDict::new(py);
+    dict.set_item("name", info.name)?;
+    dict.set_item("version", info.version)?;
+    dict.set_item("author", info.author)?;
+    dict.set_item("charset", info.charset)?;
+    dict.set_item("prefix", info.prefix)?;
+    dict.set_item("magic", info.magic)?;
+    dict.set_item("padding", info.padding)?;
+    dict.set_item("checksum", info.checksum)?;
+    dict.set_item("compression", info.compression)?;
+    dict.set_item("compression_threshold", info.compression_threshold)?;
+    Ok(dict)
+}
+
+#[pymodule]
+fn dxcode(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    let py = m.py();
+
+    m.add("DX_CHARSET", CHARSET)?;
+    m.add("MAGIC", MAGIC)?;
+    m.add("PREFIX", PREFIX)?;
+    m.add("PADDING", PADDING)?;
+    m.add("HEADER_SIZE", HEADER_SIZE)?;
+    m.add("TTL_HEADER_SIZE", TTL_HEADER_SIZE)?;
+    m.add("COMPRESSION_THRESHOLD", COMPRESSION_THRESHOLD)?;
+    m.add("FLAG_COMPRESSED", FLAG_COMPRESSED)?;
+    m.add("FLAG_ALGO_DEFLATE", FLAG_ALGO_DEFLATE)?;
+    m.add("FLAG_HAS_TTL", FLAG_HAS_TTL)?;
+
+    m.add("DxEncodingError", py.get_type::<DxEncodingError>())?;
+    m.add("DxChecksumError", py.get_type::<DxChecksumError>())?;
+    m.add("DxCompressionError", py.get_type::<DxCompressionError>())?;
+    m.add("DxTtlExpiredError", py.get_type::<DxTtlExpiredError>())?;
+    m.add_class::<PyTtlInfo>()?;
+
+    m.add_function(wrap_pyfunction!(crc16, m)?)?;
+    m.add_function(wrap_pyfunction!(dx_encode, m)?)?;
+    m.add_function(wrap_pyfunction!(dx_encode_with_ttl, m)?)?;
+    m.add_function(wrap_pyfunction!(dx_decode, m)?)?;
+    m.add_function(wrap_pyfunction!(is_dx_encoded, m)?)?;
+    m.add_function(wrap_pyfunction!(dx_verify, m)?)?;
+    m.add_function(wrap_pyfunction!(get_checksum, m)?)?;
+    m.add_function(wrap_pyfunction!(is_compressed, m)?)?;
+    m.add_function(wrap_pyfunction!(has_ttl, m)?)?;
+    m.add_function(wrap_pyfunction!(get_ttl_info, m)?)?;
+    m.add_function(wrap_pyfunction!(is_expired, m)?)?;
+    m.add_function(wrap_pyfunction!(get_dx_info, m)?)?;
+
+    // 别名，与 dxcode.py 一致
+    m.add("encode", m.getattr("dx_encode")?)?;
+    m.add("decode", m.getattr("dx_decode")?)?;
+    m.add("is_encoded", m.getattr("is_dx_encoded")?)?;
+    m.add("verify", m.getattr("dx_verify")?)?;
+    m.add("info", m.getattr("get_dx_info")?)?;
+    Ok(())
+}
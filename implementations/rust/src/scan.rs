@@ -0,0 +1,102 @@
+//! 在任意文本中查找 DX 字符串
+//!
+//! 候选子串以前缀开头、前面不紧跟字符集中的字符，向后取尽字符集中的字符和
+//! 最多两个填充字符；只有通过完整验证（长度、头部、校验和）的候选才会返回，
+//! 因此 `dxdiag` 这类普通单词不会被误报。
+
+use std::ops::Range;
+
+use crate::{Engine, PADDING};
+
+/// 找到的 DX 字符串
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Match<'t> {
+    start: usize,
+    token: &'t str,
+}
+
+impl<'t> Match<'t> {
+    /// DX 字符串
+    pub fn as_str(&self) -> &'t str {
+        self.token
+    }
+
+    /// 在原文中的起始字节偏移
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    /// 在原文中的结束字节偏移（不含）
+    pub fn end(&self) -> usize {
+        self.start + self.token.len()
+    }
+
+    /// 在原文中的字节范围
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.end()
+    }
+}
+
+/// [`find_tokens`](crate::find_tokens) 返回的迭代器
+#[derive(Debug, Clone)]
+pub struct FindTokens<'t> {
+    engine: Engine,
+    text: &'t str,
+    pos: usize,
+}
+
+impl<'t> FindTokens<'t> {
+    pub(crate) fn new(engine: Engine, text: &'t str) -> FindTokens<'t> {
+        FindTokens {
+            engine,
+            text,
+            pos: 0,
+        }
+    }
+}
+
+impl<'t> Iterator for FindTokens<'t> {
+    type Item = Match<'t>;
+
+    fn next(&mut self) -> Option<Match<'t>> {
+        let bytes = self.text.as_bytes();
+        let prefix = self.engine.prefix();
+        let alphabet = self.engine.alphabet();
+        while let Some(found) = self.text[self.pos..].find(prefix) {
+            let start = self.pos + found;
+            self.pos = start + 1;
+
+            // 前面紧跟字符集中的字符说明是其他单词的一部分
+            if start > 0 && alphabet.contains(bytes[start - 1]) {
+                continue;
+            }
+            let body = start + prefix.len();
+            let mut end = body;
+            while end < bytes.len() && alphabet.contains(bytes[end]) {
+                end += 1;
+            }
+            let padding = bytes[end..]
+                .iter()
+                .take(2)
+                .take_while(|&&b| b == PADDING as u8)
+                .count();
+
+            // 同一段字符不再从中间重新匹配
+            self.pos = (end + padding).max(self.pos);
+            if end == body {
+                continue;
+            }
+            // 填充不合法时（如紧跟在 `=` 分隔符前）按不带填充的字符串验证
+            let found = [end + padding, end]
+                .into_iter()
+                .take(if padding > 0 { 2 } else { 1 })
+                .map(|end| &self.text[start..end])
+                .find(|token| self.engine.validate(token).is_ok());
+            if let Some(token) = found {
+                return Some(Match { start, token });
+            }
+        }
+        self.pos = self.text.len();
+        None
+    }
+}
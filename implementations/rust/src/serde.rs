@@ -0,0 +1,185 @@
+//! Serde 集成（需要启用 `serde` feature）
+//!
+//! [`to_dx_string`] 将任意 `Serialize` 类型用 postcard 序列化为紧凑的二进制，
+//! 再编码为 DX 字符串；[`from_dx_str`] 执行相反的操作。
+//!
+//! 引擎启用 [`Engine::with_content_type_tag`] 时会在帧头部标记内容类型，
+//! 这样的字符串只有 Rust 实现能解码。[`from_dx_str`] 接受未标记的字符串，
+//! 标记为其他内容类型时返回 [`DxError::InvalidContentType`]。
+//!
+//! # 示例
+//!
+//! ```
+//! use dxcode::STANDARD;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize, PartialEq, Debug)]
+//! struct Session {
+//!     user_id: u64,
+//!     roles: Vec<String>,
+//! }
+//!
+//! let session = Session { user_id: 42, roles: vec!["admin".into()] };
+//! let encoded = dxcode::to_dx_string(&session, &STANDARD).unwrap();
+//! assert!(encoded.starts_with("dx"));
+//!
+//! let decoded: Session = dxcode::from_dx_str(&encoded, &STANDARD).unwrap();
+//! assert_eq!(decoded, session);
+//! ```
+
+use std::sync::Arc;
+
+use ::serde::de::DeserializeOwned;
+use ::serde::Serialize;
+
+use crate::frame::{self, Frame, CONTENT_TYPE_POSTCARD};
+use crate::{DxError, Engine, Result};
+
+/// 将值序列化为 DX 字符串
+///
+/// # 参数
+///
+/// * `value` - 要序列化的值
+/// * `engine` - 编码引擎，决定字符集、前缀和填充等
+pub fn to_dx_string<T: Serialize + ?Sized>(value: &T, engine: &Engine) -> Result<String> {
+    let body = postcard::to_stdvec(value).map_err(serialization_error)?;
+    Ok(engine.encode_frame(&frame::build(&body, true, None, content_type(engine))))
+}
+
+/// 使用 TTL 将值序列化为 DX 字符串
+pub fn to_dx_string_with_ttl<T: Serialize + ?Sized>(
+    value: &T,
+    engine: &Engine,
+    ttl_seconds: u32,
+) -> Result<String> {
+    let body = postcard::to_stdvec(value).map_err(serialization_error)?;
+    let created_at = engine.clock().now() as u32;
+    Ok(engine.encode_frame(&frame::build(
+        &body,
+        true,
+        Some((created_at, ttl_seconds)),
+        content_type(engine),
+    )))
+}
+
+/// 从 DX 字符串反序列化值（验证校验和和 TTL）
+///
+/// # 参数
+///
+/// * `encoded` - 由 [`to_dx_string`] 生成的 DX 字符串
+/// * `engine` - 编码时使用的引擎
+pub fn from_dx_str<T: DeserializeOwned>(encoded: &str, engine: &Engine) -> Result<T> {
+    let bytes = engine.decode_frame(encoded)?;
+    let frame = Frame::parse(&bytes)?;
+    if let Some(actual) = frame.content_type.filter(|&c| c != CONTENT_TYPE_POSTCARD) {
+        return Err(DxError::InvalidContentType {
+            expected: CONTENT_TYPE_POSTCARD,
+            actual: Some(actual),
+        });
+    }
+    frame.check_ttl(engine.clock().now())?;
+    let body = frame.verified_data()?;
+    postcard::from_bytes(&body).map_err(serialization_error)
+}
+
+fn content_type(engine: &Engine) -> Option<u8> {
+    engine.content_type_tag().then_some(CONTENT_TYPE_POSTCARD)
+}
+
+fn serialization_error(e: postcard::Error) -> DxError {
+    DxError::Serialization(Arc::new(e))
+}
+
+/// 将字节字段存储为 DX 字符串的 `#[serde(with = "...")]` 辅助模块
+///
+/// 对 JSON、TOML 等文本格式输出标准 DX 字符串；对二进制格式直接存储字节，
+/// 避免重复编码。
+///
+/// # 示例
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Token {
+///     #[serde(with = "dxcode::serde::bytes")]
+///     secret: Vec<u8>,
+/// }
+///
+/// let json = serde_json::to_string(&Token { secret: b"hi".to_vec() }).unwrap();
+/// assert!(json.starts_with(r#"{"secret":"dx"#));
+///
+/// let token: Token = serde_json::from_str(&json).unwrap();
+/// assert_eq!(token.secret, b"hi");
+/// ```
+pub mod bytes {
+    use std::fmt;
+
+    use ::serde::de::{self, Deserializer, Visitor};
+    use ::serde::Serializer;
+
+    /// 序列化字节（文本格式为 DX 字符串）
+    pub fn serialize<T, S>(bytes: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: AsRef<[u8]> + ?Sized,
+        S: Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&crate::encode(bytes.as_ref()))
+        } else {
+            serializer.serialize_bytes(bytes.as_ref())
+        }
+    }
+
+    /// 反序列化字节（文本格式需要是有效的 DX 字符串）
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(DxVisitor)
+        } else {
+            deserializer.deserialize_byte_buf(BytesVisitor)
+        }
+    }
+
+    struct DxVisitor;
+
+    impl Visitor<'_> for DxVisitor {
+        type Value = Vec<u8>;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("a DX encoded string")
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<Vec<u8>, E> {
+            crate::decode(v).map_err(E::custom)
+        }
+    }
+
+    struct BytesVisitor;
+
+    impl<'de> Visitor<'de> for BytesVisitor {
+        type Value = Vec<u8>;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("a byte array")
+        }
+
+        fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Vec<u8>, E> {
+            Ok(v.to_vec())
+        }
+
+        fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> Result<Vec<u8>, E> {
+            Ok(v)
+        }
+
+        fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Vec<u8>, A::Error> {
+            let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+            while let Some(b) = seq.next_element()? {
+                bytes.push(b);
+            }
+            Ok(bytes)
+        }
+    }
+}
@@ -0,0 +1,315 @@
+//! HTTP Cookie 和 `Authorization` 头部辅助函数
+//!
+//! 用于在 Cookie 或 `Authorization: Dx <token>` 中传递 [`encode_with_ttl`] 生成的会话令牌。
+//! [`SetCookie`] 的 `Max-Age`/`Expires` 由令牌的 TTL 头部和引擎的时钟计算（见
+//! [`Engine::get_ttl_info`]），Cookie 与令牌的过期时间始终一致。
+//!
+//! 默认按标准引擎验证令牌；使用自定义引擎时，通过 [`SetCookie::with_engine`]、
+//! [`parse_cookie_with_engine`] 和 [`parse_authorization_with_engine`] 指定。
+//!
+//! # 示例
+//!
+//! ```
+//! use dxcode::session::{self, SetCookie};
+//! use dxcode::{encode_with_ttl, DxString};
+//!
+//! let token = DxString::new(encode_with_ttl(b"user:42", 3600)).unwrap();
+//!
+//! let cookie = SetCookie::new("session", &token).build().unwrap();
+//! assert!(cookie.contains("Max-Age=3600") || cookie.contains("Max-Age=3599"));
+//!
+//! let header = session::authorization(&token).unwrap();
+//! let parsed = session::parse_authorization(&header).unwrap().unwrap();
+//! assert_eq!(parsed.decode().unwrap(), b"user:42");
+//! ```
+//!
+//! [`encode_with_ttl`]: crate::encode_with_ttl
+
+use std::fmt::Write;
+use std::time::{Duration, UNIX_EPOCH};
+
+use crate::{DxError, DxStr, Engine, Result, STANDARD};
+
+/// 未指定引擎时使用的标准引擎
+static STANDARD_ENGINE: Engine = STANDARD;
+
+/// `Authorization` 头部使用的认证方案
+pub const AUTH_SCHEME: &str = "Dx";
+
+/// 单个 Cookie（名称、`=` 和值）的大小上限，见 RFC 6265 第 6.1 节
+pub const MAX_COOKIE_SIZE: usize = 4096;
+
+/// 单个头部值的大小上限（常见服务器默认 8 KiB）
+pub const MAX_HEADER_SIZE: usize = 8192;
+
+/// Cookie 的 `SameSite` 属性
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SameSite {
+    /// 仅同站请求携带
+    Strict,
+    /// 同站请求和顶级导航携带
+    Lax,
+    /// 所有请求都携带（需要 `Secure`）
+    None,
+}
+
+impl SameSite {
+    fn as_str(self) -> &'static str {
+        match self {
+            SameSite::Strict => "Strict",
+            SameSite::Lax => "Lax",
+            SameSite::None => "None",
+        }
+    }
+}
+
+/// `Set-Cookie` 头部值构建器
+///
+/// 默认 `Path=/; Secure; HttpOnly; SameSite=Lax`。令牌带 TTL 时添加
+/// `Max-Age` 和 `Expires`，不带 TTL 或永不过期时为会话 Cookie。
+#[derive(Debug, Clone, Copy)]
+pub struct SetCookie<'a> {
+    name: &'a str,
+    token: &'a str,
+    engine: &'a Engine,
+    path: Option<&'a str>,
+    domain: Option<&'a str>,
+    secure: bool,
+    http_only: bool,
+    same_site: Option<SameSite>,
+}
+
+impl<'a> SetCookie<'a> {
+    /// 创建构建器，令牌在 [`build`](SetCookie::build) 时按引擎验证
+    pub fn new<T: AsRef<str> + ?Sized>(name: &'a str, token: &'a T) -> Self {
+        SetCookie {
+            name,
+            token: token.as_ref(),
+            engine: &STANDARD_ENGINE,
+            path: Some("/"),
+            domain: None,
+            secure: true,
+            http_only: true,
+            same_site: Some(SameSite::Lax),
+        }
+    }
+
+    /// 设置验证令牌和读取 TTL 使用的引擎，`Max-Age` 按引擎的时钟计算（默认 [`STANDARD`]）
+    pub const fn with_engine(mut self, engine: &'a Engine) -> Self {
+        self.engine = engine;
+        self
+    }
+
+    /// 设置 `Path`，`None` 表示不输出
+    pub const fn with_path(mut self, path: Option<&'a str>) -> Self {
+        self.path = path;
+        self
+    }
+
+    /// 设置 `Domain`
+    pub const fn with_domain(mut self, domain: Option<&'a str>) -> Self {
+        self.domain = domain;
+        self
+    }
+
+    /// 设置是否输出 `Secure`
+    pub const fn with_secure(mut self, secure: bool) -> Self {
+        self.secure = secure;
+        self
+    }
+
+    /// 设置是否输出 `HttpOnly`
+    pub const fn with_http_only(mut self, http_only: bool) -> Self {
+        self.http_only = http_only;
+        self
+    }
+
+    /// 设置 `SameSite`，`None` 表示不输出
+    pub const fn with_same_site(mut self, same_site: Option<SameSite>) -> Self {
+        self.same_site = same_site;
+        self
+    }
+
+    /// 生成 `Set-Cookie` 头部值
+    ///
+    /// 令牌不是该引擎的有效编码时返回对应的解码错误，已过期时返回
+    /// [`DxError::TtlExpired`]，超过 [`MAX_COOKIE_SIZE`] 时返回
+    /// [`DxError::TokenTooLarge`]，名称不是 RFC 6265 的 token 或 `Path`、`Domain`
+    /// 包含 `;`、控制字符时返回 [`DxError::InvalidCookie`]。
+    pub fn build(&self) -> Result<String> {
+        if self.name.is_empty() || !self.name.bytes().all(is_token_char) {
+            return Err(DxError::InvalidCookie { attribute: "name" });
+        }
+        for (attribute, value) in [("Path", self.path), ("Domain", self.domain)] {
+            if value.is_some_and(|v| !v.bytes().all(is_attribute_char)) {
+                return Err(DxError::InvalidCookie { attribute });
+            }
+        }
+        check_size(self.name.len() + 1 + self.token.len(), MAX_COOKIE_SIZE)?;
+        self.engine.validate(self.token)?;
+
+        let now = self.engine.clock().now();
+        let mut cookie = format!("{}={}", self.name, self.token);
+        if let Some(info) = self.engine.ttl_info_at(self.token, now)? {
+            if let Some(expires_at) = info.expires_at {
+                if info.is_expired {
+                    return Err(DxError::TtlExpired {
+                        created_at: info.created_at,
+                        ttl_seconds: info.ttl_seconds,
+                        expired_at: expires_at,
+                    });
+                }
+                // 过期的那一秒令牌仍然有效，而 `Max-Age=0` 会立即删除 Cookie
+                let _ = write!(
+                    cookie,
+                    "; Max-Age={}; Expires={}",
+                    (expires_at - now).max(1),
+                    http_date(expires_at)
+                );
+            }
+        }
+        if let Some(path) = self.path {
+            let _ = write!(cookie, "; Path={}", path);
+        }
+        if let Some(domain) = self.domain {
+            let _ = write!(cookie, "; Domain={}", domain);
+        }
+        if self.secure {
+            cookie.push_str("; Secure");
+        }
+        if self.http_only {
+            cookie.push_str("; HttpOnly");
+        }
+        if let Some(same_site) = self.same_site {
+            let _ = write!(cookie, "; SameSite={}", same_site.as_str());
+        }
+        Ok(cookie)
+    }
+}
+
+/// 生成 `Authorization` 头部值 `Dx <token>`
+///
+/// 超过 [`MAX_HEADER_SIZE`] 时返回 [`DxError::TokenTooLarge`]。
+pub fn authorization<T: AsRef<DxStr> + ?Sized>(token: &T) -> Result<String> {
+    let token = token.as_ref();
+    check_size(AUTH_SCHEME.len() + 1 + token.len(), MAX_HEADER_SIZE)?;
+    Ok(format!("{} {}", AUTH_SCHEME, token))
+}
+
+/// 解析 `Authorization` 头部值
+///
+/// 认证方案不区分大小写。方案不是 `Dx` 时返回 `Ok(None)`，便于与其他方案共存；
+/// 令牌无效或过大时返回错误。返回的令牌只经过格式验证，TTL 在
+/// [`DxStr::decode`] 时检查。
+///
+/// # 示例
+///
+/// ```
+/// use dxcode::{encode, session};
+///
+/// let header = format!("Dx {}", encode(b"token"));
+/// assert!(session::parse_authorization(&header).unwrap().is_some());
+/// assert!(session::parse_authorization("Bearer abc").unwrap().is_none());
+/// assert!(session::parse_authorization("Dx hello").is_err());
+/// ```
+pub fn parse_authorization(value: &str) -> Result<Option<&DxStr>> {
+    authorization_token(value)?.map(DxStr::new).transpose()
+}
+
+/// 解析 `Authorization` 头部值，令牌按指定引擎验证
+///
+/// # 示例
+///
+/// ```
+/// use dxcode::{session, Alphabet, Engine};
+///
+/// let engine = Engine::new(Alphabet::STANDARD, 0x2A, "acme:").unwrap();
+/// let header = format!("Dx {}", engine.encode(b"token"));
+/// let token = session::parse_authorization_with_engine(&engine, &header).unwrap().unwrap();
+/// assert_eq!(engine.decode(token).unwrap(), b"token");
+/// ```
+pub fn parse_authorization_with_engine<'a>(
+    engine: &Engine,
+    value: &'a str,
+) -> Result<Option<&'a str>> {
+    let token = authorization_token(value)?;
+    token.map(|t| engine.validate(t)).transpose()?;
+    Ok(token)
+}
+
+fn authorization_token(value: &str) -> Result<Option<&str>> {
+    check_size(value.len(), MAX_HEADER_SIZE)?;
+    let value = value.trim();
+    let (scheme, token) = value.split_once(' ').unwrap_or((value, ""));
+    if !scheme.eq_ignore_ascii_case(AUTH_SCHEME) {
+        return Ok(None);
+    }
+    Ok(Some(token.trim_start()))
+}
+
+/// 从 `Cookie` 请求头部中查找指定名称的令牌
+///
+/// 未找到时返回 `Ok(None)`；找到但无效或过大时返回错误。
+///
+/// # 示例
+///
+/// ```
+/// use dxcode::{encode, session};
+///
+/// let header = format!("theme=dark; session={}", encode(b"token"));
+/// let token = session::parse_cookie(&header, "session").unwrap().unwrap();
+/// assert_eq!(token.decode().unwrap(), b"token");
+/// assert!(session::parse_cookie(&header, "other").unwrap().is_none());
+/// ```
+pub fn parse_cookie<'a>(header: &'a str, name: &str) -> Result<Option<&'a DxStr>> {
+    cookie_value(header, name)?.map(DxStr::new).transpose()
+}
+
+/// 从 `Cookie` 请求头部中查找指定名称的令牌，令牌按指定引擎验证
+pub fn parse_cookie_with_engine<'a>(
+    engine: &Engine,
+    header: &'a str,
+    name: &str,
+) -> Result<Option<&'a str>> {
+    let token = cookie_value(header, name)?;
+    token.map(|t| engine.validate(t)).transpose()?;
+    Ok(token)
+}
+
+fn cookie_value<'a>(header: &'a str, name: &str) -> Result<Option<&'a str>> {
+    for pair in header.split(';') {
+        let pair = pair.trim();
+        let Some((key, value)) = pair.split_once('=') else {
+            continue;
+        };
+        if key == name {
+            check_size(pair.len(), MAX_COOKIE_SIZE)?;
+            let value = value.strip_prefix('"').unwrap_or(value);
+            let value = value.strip_suffix('"').unwrap_or(value);
+            return Ok(Some(value));
+        }
+    }
+    Ok(None)
+}
+
+/// RFC 6265 cookie-name（RFC 2616 token）允许的字符：可见 ASCII，不含分隔符
+fn is_token_char(b: u8) -> bool {
+    b.is_ascii_graphic() && !b"()<>@,;:\\\"/[]?={}".contains(&b)
+}
+
+/// RFC 6265 av-value 允许的字符：除控制字符和 `;` 以外的 ASCII
+fn is_attribute_char(b: u8) -> bool {
+    (b' '..=b'~').contains(&b) && b != b';'
+}
+
+fn check_size(actual: usize, max: usize) -> Result<()> {
+    if actual > max {
+        return Err(DxError::TokenTooLarge { max, actual });
+    }
+    Ok(())
+}
+
+/// 格式化为 HTTP 日期（IMF-fixdate），如 `Sun, 06 Nov 1994 08:49:37 GMT`
+fn http_date(timestamp: u64) -> String {
+    httpdate::fmt_http_date(UNIX_EPOCH + Duration::from_secs(timestamp))
+}
@@ -0,0 +1,385 @@
+//! 异步流式编解码（需要启用 `tokio` feature）
+//!
+//! DX 帧的校验和位于数据之前，压缩数据也只能记录 65535 字节以内的原始大小，
+//! 因此流式编码把数据切分为多个独立的分块（dx-chunk），每个分块编码为一行
+//! 完整的 DX 字符串并以 `\n` 结尾：
+//!
+//! ```text
+//! dx...(第 1 块)\n
+//! dx...(第 2 块)\n
+//! ```
+//!
+//! 每行都可以用 [`decode`](crate::decode) 单独解码。编码时增量计算 CRC16 并增量
+//! 压缩，单次处理的数据量不超过一个分块，不会长时间阻塞执行器。
+//!
+//! # 示例
+//!
+//! ```
+//! use dxcode::stream::{DxReader, DxWriter};
+//! use tokio::io::{AsyncReadExt, AsyncWriteExt};
+//!
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() -> std::io::Result<()> {
+//! let mut writer = DxWriter::new(Vec::new());
+//! writer.write_all(b"Hello, Dogxi!").await?;
+//! writer.shutdown().await?;
+//! let encoded = writer.into_inner();
+//!
+//! let mut decoded = Vec::new();
+//! DxReader::new(&encoded[..]).read_to_end(&mut decoded).await?;
+//! assert_eq!(decoded, b"Hello, Dogxi!");
+//! # Ok(())
+//! # }
+//! ```
+
+use std::io::{self, Write};
+use std::mem;
+use std::pin::Pin;
+use std::task::{ready, Context, Poll};
+
+use bytes::Bytes;
+use flate2::write::DeflateEncoder;
+use flate2::Compression;
+use futures_core::Stream;
+use tokio::io::{AsyncBufRead, AsyncRead, AsyncWrite, ReadBuf};
+
+use crate::frame::{self, COMPRESSION_THRESHOLD};
+use crate::{Crc16, Engine, LineEnding, STANDARD};
+
+/// 默认分块大小（原始字节数）
+pub const DEFAULT_CHUNK_SIZE: usize = 32 * 1024;
+
+/// 最大分块大小（压缩数据只能记录 16 位原始大小）
+pub const MAX_CHUNK_SIZE: usize = 65535;
+
+/// 解码时允许的最大行长度（字符数），防止无换行的输入耗尽内存
+const MAX_LINE_LEN: usize = 1024 * 1024;
+
+/// 增量分块编码器
+#[derive(Debug)]
+struct ChunkEncoder {
+    engine: Engine,
+    chunk_size: usize,
+    crc: Crc16,
+    raw: Vec<u8>,
+    deflate: DeflateEncoder<Vec<u8>>,
+}
+
+impl ChunkEncoder {
+    fn new(engine: Engine, chunk_size: usize) -> ChunkEncoder {
+        ChunkEncoder {
+            // 分块以换行分隔，不能在块内换行
+            engine: engine.with_line_wrap(0, LineEnding::Lf),
+            chunk_size: chunk_size.clamp(1, MAX_CHUNK_SIZE),
+            crc: Crc16::new(),
+            raw: Vec::new(),
+            deflate: DeflateEncoder::new(Vec::new(), Compression::default()),
+        }
+    }
+
+    /// 追加数据，已满的分块编码后追加到 `out`
+    fn push(&mut self, mut data: &[u8], out: &mut Vec<u8>) {
+        while !data.is_empty() {
+            let take = data.len().min(self.chunk_size - self.raw.len());
+            let (head, rest) = data.split_at(take);
+            self.crc.update(head);
+            self.raw.extend_from_slice(head);
+            // 写入 Vec 不会失败
+            let _ = self.deflate.write_all(head);
+            data = rest;
+
+            if self.raw.len() == self.chunk_size {
+                self.emit(out);
+            }
+        }
+    }
+
+    /// 编码剩余数据
+    fn finish(&mut self, out: &mut Vec<u8>) {
+        if !self.raw.is_empty() {
+            self.emit(out);
+        }
+    }
+
+    fn emit(&mut self, out: &mut Vec<u8>) {
+        let deflate = mem::replace(
+            &mut self.deflate,
+            DeflateEncoder::new(Vec::new(), Compression::default()),
+        );
+        let compressed = if self.raw.len() >= COMPRESSION_THRESHOLD {
+            deflate.finish().ok()
+        } else {
+            None
+        };
+
+        let frame = frame::assemble(
+            &self.raw,
+            self.crc.finish(),
+            compressed.as_deref(),
+            None,
+            None,
+        );
+        out.extend_from_slice(self.engine.encode_frame(&frame).as_bytes());
+        out.push(b'\n');
+
+        self.crc = Crc16::new();
+        self.raw.clear();
+    }
+}
+
+/// 将写入的数据编码为 dx-chunk 并写入内部的 `AsyncWrite`
+///
+/// 只有写满的分块才会输出；`flush` 不会截断当前分块，
+/// 必须调用 `shutdown` 才会输出最后一个分块。
+#[derive(Debug)]
+pub struct DxWriter<W> {
+    inner: W,
+    encoder: ChunkEncoder,
+    pending: Vec<u8>,
+    written: usize,
+    finished: bool,
+}
+
+impl<W> DxWriter<W> {
+    /// 使用标准引擎和默认分块大小
+    pub fn new(inner: W) -> DxWriter<W> {
+        DxWriter::with_engine(inner, STANDARD)
+    }
+
+    /// 使用指定引擎（引擎的换行设置会被忽略）
+    pub fn with_engine(inner: W, engine: Engine) -> DxWriter<W> {
+        DxWriter {
+            inner,
+            encoder: ChunkEncoder::new(engine, DEFAULT_CHUNK_SIZE),
+            pending: Vec::new(),
+            written: 0,
+            finished: false,
+        }
+    }
+
+    /// 设置分块大小（原始字节数，最大 [`MAX_CHUNK_SIZE`]）
+    pub fn with_chunk_size(mut self, chunk_size: usize) -> DxWriter<W> {
+        self.encoder.chunk_size = chunk_size.clamp(1, MAX_CHUNK_SIZE);
+        self
+    }
+
+    /// 内部的写入器
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// 取出内部的写入器（未 `shutdown` 时最后一个分块会丢失）
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: AsyncWrite + Unpin> DxWriter<W> {
+    /// 将已编码的数据写入内部写入器
+    fn poll_drain(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        while self.written < self.pending.len() {
+            let n =
+                ready!(Pin::new(&mut self.inner).poll_write(cx, &self.pending[self.written..]))?;
+            if n == 0 {
+                return Poll::Ready(Err(io::ErrorKind::WriteZero.into()));
+            }
+            self.written += n;
+        }
+        self.pending.clear();
+        self.written = 0;
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl<W: AsyncWrite + Unpin> AsyncWrite for DxWriter<W> {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        ready!(this.poll_drain(cx))?;
+
+        // 每次最多接收一个分块，避免缓冲过多数据
+        let n = buf.len().min(this.encoder.chunk_size);
+        this.encoder.push(&buf[..n], &mut this.pending);
+        Poll::Ready(Ok(n))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        ready!(this.poll_drain(cx))?;
+        Pin::new(&mut this.inner).poll_flush(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        if !this.finished {
+            this.encoder.finish(&mut this.pending);
+            this.finished = true;
+        }
+        ready!(this.poll_drain(cx))?;
+        Pin::new(&mut this.inner).poll_shutdown(cx)
+    }
+}
+
+/// 从内部的 `AsyncBufRead` 读取 dx-chunk 并输出解码后的数据
+///
+/// 每行独立验证校验和和 TTL，错误以 [`io::ErrorKind::InvalidData`] 返回，
+/// 原始的 [`DxError`](crate::DxError) 可以通过 `io::Error::get_ref()` 取回。空行会被忽略。
+#[derive(Debug)]
+pub struct DxReader<R> {
+    inner: R,
+    engine: Engine,
+    line: Vec<u8>,
+    decoded: Vec<u8>,
+    pos: usize,
+    eof: bool,
+}
+
+impl<R> DxReader<R> {
+    /// 使用标准引擎
+    pub fn new(inner: R) -> DxReader<R> {
+        DxReader::with_engine(inner, STANDARD)
+    }
+
+    /// 使用指定引擎
+    pub fn with_engine(inner: R, engine: Engine) -> DxReader<R> {
+        DxReader {
+            inner,
+            engine,
+            line: Vec::new(),
+            decoded: Vec::new(),
+            pos: 0,
+            eof: false,
+        }
+    }
+
+    /// 取出内部的读取器
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// 解码一行，空行返回空数据
+    fn decode_line(&mut self) -> io::Result<()> {
+        let line = mem::take(&mut self.line);
+        let line = std::str::from_utf8(&line)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?
+            .trim();
+        self.decoded = if line.is_empty() {
+            Vec::new()
+        } else {
+            self.engine
+                .decode(line)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?
+        };
+        self.pos = 0;
+        Ok(())
+    }
+}
+
+impl<R: AsyncBufRead + Unpin> AsyncRead for DxReader<R> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        loop {
+            if this.pos < this.decoded.len() {
+                let n = buf.remaining().min(this.decoded.len() - this.pos);
+                buf.put_slice(&this.decoded[this.pos..this.pos + n]);
+                this.pos += n;
+                return Poll::Ready(Ok(()));
+            }
+            if this.eof {
+                return Poll::Ready(Ok(()));
+            }
+
+            let available = ready!(Pin::new(&mut this.inner).poll_fill_buf(cx))?;
+            if available.is_empty() {
+                this.eof = true;
+                this.decode_line()?;
+                continue;
+            }
+
+            let (consumed, complete) = match available.iter().position(|&b| b == b'\n') {
+                Some(i) => {
+                    this.line.extend_from_slice(&available[..i]);
+                    (i + 1, true)
+                }
+                None => {
+                    this.line.extend_from_slice(available);
+                    (available.len(), false)
+                }
+            };
+            Pin::new(&mut this.inner).consume(consumed);
+
+            if this.line.len() > MAX_LINE_LEN {
+                return Poll::Ready(Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "dx chunk line too long",
+                )));
+            }
+            if complete {
+                this.decode_line()?;
+            }
+        }
+    }
+}
+
+/// 将 `Stream<Item = Bytes>` 编码为 dx-chunk 流
+///
+/// 输出的每一项都由一个或多个完整的行组成，适合直接作为 HTTP 响应体。
+#[derive(Debug)]
+pub struct DxChunks<S> {
+    inner: S,
+    encoder: ChunkEncoder,
+    done: bool,
+}
+
+impl<S> DxChunks<S> {
+    /// 使用标准引擎和默认分块大小
+    pub fn new(inner: S) -> DxChunks<S> {
+        DxChunks::with_engine(inner, STANDARD)
+    }
+
+    /// 使用指定引擎（引擎的换行设置会被忽略）
+    pub fn with_engine(inner: S, engine: Engine) -> DxChunks<S> {
+        DxChunks {
+            inner,
+            encoder: ChunkEncoder::new(engine, DEFAULT_CHUNK_SIZE),
+            done: false,
+        }
+    }
+
+    /// 设置分块大小（原始字节数，最大 [`MAX_CHUNK_SIZE`]）
+    pub fn with_chunk_size(mut self, chunk_size: usize) -> DxChunks<S> {
+        self.encoder.chunk_size = chunk_size.clamp(1, MAX_CHUNK_SIZE);
+        self
+    }
+}
+
+impl<S: Stream<Item = Bytes> + Unpin> Stream for DxChunks<S> {
+    type Item = Bytes;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Bytes>> {
+        let this = self.get_mut();
+        let mut out = Vec::new();
+        while out.is_empty() && !this.done {
+            match ready!(Pin::new(&mut this.inner).poll_next(cx)) {
+                Some(bytes) => this.encoder.push(&bytes, &mut out),
+                None => {
+                    this.encoder.finish(&mut out);
+                    this.done = true;
+                }
+            }
+        }
+
+        if out.is_empty() {
+            Poll::Ready(None)
+        } else {
+            Poll::Ready(Some(Bytes::from(out)))
+        }
+    }
+}
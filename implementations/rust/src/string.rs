@@ -0,0 +1,321 @@
+//! 经过验证的 DX 字符串类型
+//!
+//! [`DxString`] 和借用形式的 [`DxStr`] 只能由通过 [`validate`](crate::validate)
+//! 的输入构造（标准引擎，不检查 TTL），之后无需在每一层重复验证。
+
+use std::borrow::Borrow;
+use std::fmt;
+use std::ops::Deref;
+use std::str::FromStr;
+
+use crate::{DxError, Header, Result, TtlInfo, STANDARD};
+
+/// 经过验证的 DX 字符串切片（借用形式）
+///
+/// # 示例
+///
+/// ```
+/// use dxcode::{encode, DxStr};
+///
+/// let encoded = encode(b"Hello");
+/// let dx = DxStr::new(&encoded).unwrap();
+/// assert_eq!(dx.decode().unwrap(), b"Hello");
+/// assert!(DxStr::new("hello").is_err());
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct DxStr(str);
+
+/// 经过验证的 DX 字符串
+///
+/// # 示例
+///
+/// ```
+/// use dxcode::{encode_with_ttl, DxString};
+///
+/// let token: DxString = encode_with_ttl(b"session", 3600).parse().unwrap();
+/// assert!(token.starts_with("dx"));
+/// assert_eq!(token.ttl().unwrap().ttl_seconds, 3600);
+/// assert!("dxDX".parse::<DxString>().is_err());
+/// ```
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DxString(String);
+
+impl DxStr {
+    /// 验证并借用字符串
+    pub fn new(s: &str) -> Result<&DxStr> {
+        STANDARD.validate(s)?;
+        Ok(DxStr::from_str_unchecked(s))
+    }
+
+    fn from_str_unchecked(s: &str) -> &DxStr {
+        // SAFETY: DxStr 是 str 的 #[repr(transparent)] 包装
+        unsafe { &*(s as *const str as *const DxStr) }
+    }
+
+    /// 字符串内容
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// 解码（验证校验和，检查 TTL）
+    pub fn decode(&self) -> Result<Vec<u8>> {
+        STANDARD.decode(&self.0)
+    }
+
+    /// 头部信息
+    pub fn header(&self) -> Header {
+        STANDARD
+            .get_header(&self.0)
+            .expect("DxStr is validated on construction")
+    }
+
+    /// TTL 信息，不带 TTL 时返回 `None`
+    pub fn ttl(&self) -> Option<TtlInfo> {
+        STANDARD
+            .get_ttl_info(&self.0)
+            .expect("DxStr is validated on construction")
+    }
+
+    /// 复制为 [`DxString`]
+    pub fn to_dx_string(&self) -> DxString {
+        DxString(self.0.to_string())
+    }
+}
+
+impl DxString {
+    /// 验证并获取字符串的所有权
+    pub fn new(s: impl Into<String>) -> Result<DxString> {
+        let s = s.into();
+        STANDARD.validate(&s)?;
+        Ok(DxString(s))
+    }
+
+    /// 编码字节数据，结果一定有效
+    pub fn encode(data: &[u8]) -> DxString {
+        DxString(STANDARD.encode(data))
+    }
+
+    /// 借用为 [`DxStr`]
+    pub fn as_dx_str(&self) -> &DxStr {
+        DxStr::from_str_unchecked(&self.0)
+    }
+
+    /// 取出内部的 `String`
+    pub fn into_string(self) -> String {
+        self.0
+    }
+
+    /// 字符串内容
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// 解码（验证校验和，检查 TTL）
+    pub fn decode(&self) -> Result<Vec<u8>> {
+        self.as_dx_str().decode()
+    }
+
+    /// 头部信息
+    pub fn header(&self) -> Header {
+        self.as_dx_str().header()
+    }
+
+    /// TTL 信息，不带 TTL 时返回 `None`
+    pub fn ttl(&self) -> Option<TtlInfo> {
+        self.as_dx_str().ttl()
+    }
+}
+
+impl Deref for DxStr {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Deref for DxString {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for DxStr {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for DxString {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<DxStr> for DxStr {
+    fn as_ref(&self) -> &DxStr {
+        self
+    }
+}
+
+impl AsRef<DxStr> for DxString {
+    fn as_ref(&self) -> &DxStr {
+        self.as_dx_str()
+    }
+}
+
+impl Borrow<DxStr> for DxString {
+    fn borrow(&self) -> &DxStr {
+        self.as_dx_str()
+    }
+}
+
+impl ToOwned for DxStr {
+    type Owned = DxString;
+
+    fn to_owned(&self) -> DxString {
+        self.to_dx_string()
+    }
+}
+
+impl fmt::Debug for DxStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, f)
+    }
+}
+
+impl fmt::Debug for DxString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, f)
+    }
+}
+
+impl fmt::Display for DxStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl fmt::Display for DxString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl FromStr for DxString {
+    type Err = DxError;
+
+    fn from_str(s: &str) -> Result<DxString> {
+        DxString::new(s)
+    }
+}
+
+impl<'a> TryFrom<&'a str> for &'a DxStr {
+    type Error = DxError;
+
+    fn try_from(s: &'a str) -> Result<&'a DxStr> {
+        DxStr::new(s)
+    }
+}
+
+impl TryFrom<&str> for DxString {
+    type Error = DxError;
+
+    fn try_from(s: &str) -> Result<DxString> {
+        DxString::new(s)
+    }
+}
+
+impl TryFrom<String> for DxString {
+    type Error = DxError;
+
+    fn try_from(s: String) -> Result<DxString> {
+        DxString::new(s)
+    }
+}
+
+impl From<&DxStr> for DxString {
+    fn from(s: &DxStr) -> DxString {
+        s.to_dx_string()
+    }
+}
+
+impl From<DxString> for String {
+    fn from(s: DxString) -> String {
+        s.0
+    }
+}
+
+impl PartialEq<str> for DxStr {
+    fn eq(&self, other: &str) -> bool {
+        &self.0 == other
+    }
+}
+
+impl PartialEq<str> for DxString {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&str> for DxString {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_impls {
+    use std::fmt;
+
+    use ::serde::de::{self, Deserialize, Deserializer, Visitor};
+    use ::serde::{Serialize, Serializer};
+
+    use super::{DxStr, DxString};
+
+    impl Serialize for DxStr {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_str(&self.0)
+        }
+    }
+
+    impl Serialize for DxString {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_str(&self.0)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for DxString {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<DxString, D::Error> {
+            struct DxStringVisitor;
+
+            impl Visitor<'_> for DxStringVisitor {
+                type Value = DxString;
+
+                fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    f.write_str("a valid DX string")
+                }
+
+                fn visit_str<E: de::Error>(self, v: &str) -> Result<DxString, E> {
+                    DxString::new(v).map_err(E::custom)
+                }
+
+                fn visit_string<E: de::Error>(self, v: String) -> Result<DxString, E> {
+                    DxString::new(v).map_err(E::custom)
+                }
+            }
+
+            deserializer.deserialize_string(DxStringVisitor)
+        }
+    }
+
+    impl<'de: 'a, 'a> Deserialize<'de> for &'a DxStr {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<&'a DxStr, D::Error> {
+            let s = <&str>::deserialize(deserializer)?;
+            DxStr::new(s).map_err(de::Error::custom)
+        }
+    }
+}
@@ -0,0 +1,352 @@
+//! 文档字段的编解码（需要启用 `json` feature）
+//!
+//! [`transform_json`] 按选择器找到 JSON 文档中的字段，就地编码或解码，
+//! 其余部分保持不变。选择器是 JSONPath 的子集：
+//!
+//! - `$` 根节点
+//! - `.key` 或 `['key']` 对象的字段
+//! - `[0]` 数组的元素
+//! - `.*` 或 `[*]` 所有字段或元素
+//!
+//! # 示例
+//!
+//! ```
+//! use dxcode::{transform_json, FieldOp};
+//! use serde_json::json;
+//!
+//! let mut doc = json!({ "users": [{ "name": "a", "token": "secret" }] });
+//! let op = FieldOp::Encode { allow_compression: true };
+//! assert_eq!(transform_json(&mut doc, "$.users[*].token", op).unwrap(), 1);
+//! assert!(doc["users"][0]["token"].as_str().unwrap().starts_with("dx"));
+//!
+//! let op = FieldOp::Decode { check_ttl: true };
+//! transform_json(&mut doc, "$.users[*].token", op).unwrap();
+//! assert_eq!(doc, json!({ "users": [{ "name": "a", "token": "secret" }] }));
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde_json::Value;
+
+use crate::locale::{Locale, Localized};
+use crate::{DxError, Engine, Result, STANDARD};
+
+/// 选择器的一级
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Segment {
+    /// 对象的字段
+    Key(String),
+    /// 数组的元素
+    Index(usize),
+    /// 所有字段或元素
+    Wildcard,
+}
+
+impl fmt::Display for Segment {
+    /// 字段名只含字母、数字、`_` 和 `-` 时输出 `.key`，否则输出 `['key']`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Segment::Key(key)
+                if !key.is_empty()
+                    && key
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-') =>
+            {
+                write!(f, ".{}", key)
+            }
+            Segment::Key(key) => {
+                let escaped = key.replace('\\', "\\\\").replace('\'', "\\'");
+                write!(f, "['{}']", escaped)
+            }
+            Segment::Index(i) => write!(f, "[{}]", i),
+            Segment::Wildcard => write!(f, "[*]"),
+        }
+    }
+}
+
+/// 字段选择器（JSONPath 子集）
+///
+/// # 示例
+///
+/// ```
+/// use dxcode::{Segment, Selector};
+///
+/// let selector: Selector = "$.users[*]['api key']".parse().unwrap();
+/// assert_eq!(selector.segments()[1], Segment::Wildcard);
+/// assert_eq!(selector.to_string(), "$.users[*]['api key']");
+/// assert!("users.token".parse::<Selector>().is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Selector {
+    segments: Vec<Segment>,
+}
+
+impl Selector {
+    /// 解析选择器
+    pub fn parse(selector: &str) -> std::result::Result<Selector, TransformError> {
+        let invalid = |position: usize| TransformError::InvalidSelector {
+            selector: selector.to_string(),
+            position,
+        };
+        let bytes = selector.as_bytes();
+        if bytes.first() != Some(&b'$') {
+            return Err(invalid(0));
+        }
+
+        let mut segments = Vec::new();
+        let mut pos = 1;
+        while pos < bytes.len() {
+            match bytes[pos] {
+                b'.' if bytes.get(pos + 1) == Some(&b'*') => {
+                    segments.push(Segment::Wildcard);
+                    pos += 2;
+                }
+                b'.' => {
+                    let start = pos + 1;
+                    let end = selector[start..]
+                        .find(['.', '['])
+                        .map_or(selector.len(), |i| start + i);
+                    if end == start {
+                        return Err(invalid(start));
+                    }
+                    segments.push(Segment::Key(selector[start..end].to_string()));
+                    pos = end;
+                }
+                b'[' => {
+                    let (segment, end) =
+                        parse_bracket(selector, pos + 1).ok_or_else(|| invalid(pos))?;
+                    segments.push(segment);
+                    pos = end;
+                }
+                _ => return Err(invalid(pos)),
+            }
+        }
+        Ok(Selector { segments })
+    }
+
+    /// 各级选择器（不含根节点 `$`）
+    pub fn segments(&self) -> &[Segment] {
+        &self.segments
+    }
+}
+
+/// 解析 `[` 之后的内容，返回选择器和 `]` 之后的位置
+fn parse_bracket(selector: &str, start: usize) -> Option<(Segment, usize)> {
+    let rest = &selector[start..];
+    if let Some(rest) = rest.strip_prefix("*]") {
+        return Some((Segment::Wildcard, selector.len() - rest.len()));
+    }
+
+    let quote = rest.chars().next()?;
+    if quote == '\'' || quote == '"' {
+        let mut key = String::new();
+        let mut chars = rest.char_indices().skip(1);
+        while let Some((i, c)) = chars.next() {
+            match c {
+                '\\' => key.push(chars.next()?.1),
+                c if c == quote => {
+                    let end = start + i + c.len_utf8();
+                    return (selector.as_bytes().get(end) == Some(&b']'))
+                        .then_some((Segment::Key(key), end + 1));
+                }
+                c => key.push(c),
+            }
+        }
+        return None;
+    }
+
+    let close = rest.find(']')?;
+    let index = &rest[..close];
+    if index.is_empty() || !index.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    Some((Segment::Index(index.parse().ok()?), start + close + 1))
+}
+
+impl FromStr for Selector {
+    type Err = TransformError;
+
+    fn from_str(s: &str) -> std::result::Result<Selector, TransformError> {
+        Selector::parse(s)
+    }
+}
+
+impl fmt::Display for Selector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("$")?;
+        self.segments
+            .iter()
+            .try_for_each(|s| fmt::Display::fmt(s, f))
+    }
+}
+
+/// 对选中字段执行的操作
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldOp {
+    /// 把字段的文本编码为 DX 字符串
+    Encode { allow_compression: bool },
+    /// 把 DX 字符串字段解码为文本，解码结果必须是 UTF-8
+    Decode { check_ttl: bool },
+}
+
+impl FieldOp {
+    /// 对单个字段的值执行操作
+    pub fn apply(&self, engine: &Engine, value: &str) -> Result<String> {
+        match *self {
+            FieldOp::Encode { allow_compression } => {
+                Ok(engine.encode_with_options(value.as_bytes(), allow_compression))
+            }
+            FieldOp::Decode { check_ttl } => {
+                let decoded = engine.decode_with_options(value, check_ttl)?;
+                String::from_utf8(decoded).map_err(|e| DxError::Utf8Error(e.utf8_error()))
+            }
+        }
+    }
+}
+
+/// 字段编解码错误
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum TransformError {
+    /// 选择器语法错误，`position` 为出错的字节位置
+    InvalidSelector { selector: String, position: usize },
+    /// 选中的字段不是字符串，`path` 为字段的具体路径
+    NotAString { path: String },
+    /// 字段编解码失败
+    Field { path: String, error: DxError },
+}
+
+impl TransformError {
+    /// 按指定语言格式化错误信息，`Display` 始终输出英文
+    pub fn localized(&self, locale: Locale) -> Localized<'_, TransformError> {
+        Localized {
+            error: self,
+            locale,
+        }
+    }
+}
+
+impl fmt::Display for TransformError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.localized(Locale::En), f)
+    }
+}
+
+impl fmt::Display for Localized<'_, TransformError> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.error, self.locale) {
+            (TransformError::InvalidSelector { selector, position }, Locale::En) => write!(
+                f,
+                "invalid selector '{}' at position {}",
+                selector, position
+            ),
+            (TransformError::InvalidSelector { selector, position }, Locale::ZhCn) => {
+                write!(f, "选择器 '{}' 在位置 {} 处无效", selector, position)
+            }
+            (TransformError::NotAString { path }, Locale::En) => {
+                write!(f, "{}: not a string", path)
+            }
+            (TransformError::NotAString { path }, Locale::ZhCn) => {
+                write!(f, "{}: 不是字符串", path)
+            }
+            (TransformError::Field { path, error }, locale) => {
+                write!(f, "{}: {}", path, error.localized(locale))
+            }
+        }
+    }
+}
+
+impl std::error::Error for TransformError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            TransformError::Field { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// 编码或解码 JSON 文档中选中的字段，返回处理的字段数
+///
+/// 选中的值必须都是字符串；任一字段失败时返回错误且文档保持不变。
+/// 选择器没有匹配到字段时返回 `Ok(0)`。
+///
+/// # 参数
+///
+/// * `value` - JSON 文档
+/// * `selector` - 字段选择器，如 `$.users[*].token`
+/// * `op` - 对字段执行的操作
+pub fn transform_json(
+    value: &mut Value,
+    selector: &str,
+    op: FieldOp,
+) -> std::result::Result<usize, TransformError> {
+    transform_json_with(value, selector, op, &STANDARD)
+}
+
+/// 使用指定引擎编码或解码 JSON 文档中选中的字段
+pub fn transform_json_with(
+    value: &mut Value,
+    selector: &str,
+    op: FieldOp,
+    engine: &Engine,
+) -> std::result::Result<usize, TransformError> {
+    let selector = Selector::parse(selector)?;
+    let mut targets = Vec::new();
+    select(value, selector.segments(), "$".to_string(), &mut targets);
+
+    // 先计算所有新值，全部成功后再写回，保证失败时文档不变
+    let replaced = targets
+        .iter()
+        .map(|(path, target)| match target {
+            Value::String(s) => op.apply(engine, s).map_err(|error| TransformError::Field {
+                path: path.clone(),
+                error,
+            }),
+            _ => Err(TransformError::NotAString { path: path.clone() }),
+        })
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+
+    let count = replaced.len();
+    for ((_, target), new) in targets.into_iter().zip(replaced) {
+        *target = Value::String(new);
+    }
+    Ok(count)
+}
+
+/// 收集选中的值及其具体路径
+fn select<'a>(
+    value: &'a mut Value,
+    segments: &[Segment],
+    path: String,
+    out: &mut Vec<(String, &'a mut Value)>,
+) {
+    let Some((segment, rest)) = segments.split_first() else {
+        out.push((path, value));
+        return;
+    };
+    match (segment, value) {
+        (Segment::Key(key), Value::Object(map)) => {
+            if let Some(v) = map.get_mut(key) {
+                select(v, rest, format!("{}{}", path, segment), out);
+            }
+        }
+        (Segment::Index(i), Value::Array(items)) => {
+            if let Some(v) = items.get_mut(*i) {
+                select(v, rest, format!("{}{}", path, segment), out);
+            }
+        }
+        (Segment::Wildcard, Value::Object(map)) => {
+            for (k, v) in map.iter_mut() {
+                let path = format!("{}{}", path, Segment::Key(k.clone()));
+                select(v, rest, path, out);
+            }
+        }
+        (Segment::Wildcard, Value::Array(items)) => {
+            for (i, v) in items.iter_mut().enumerate() {
+                select(v, rest, format!("{}{}", path, Segment::Index(i)), out);
+            }
+        }
+        _ => {}
+    }
+}
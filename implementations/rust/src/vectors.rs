@@ -0,0 +1,249 @@
+//! 跨实现一致性测试向量
+//!
+//! `tests/vectors.json` 由 `dxc vectors` 生成，供 Go/JS/Python/C 等实现核对编解码结果：
+//!
+//! - `vectors`：合法输入（`input` 为十六进制）及其编码结果。TTL 向量的创建时间为 `now`；
+//!   `exact` 为 `false` 的向量包含 DEFLATE 压缩数据，不同的压缩库输出可能不同，
+//!   其他实现只需要能够解码，不要求逐字节一致
+//! - `errors`：非法编码及解码时（当前时间为 `now`）应当返回的错误类别；
+//!   `as_string` 为 `true` 时按 UTF-8 字符串解码
+//!
+//! ```
+//! let json = dxcode::vectors::to_json();
+//! assert!(json.contains("\"name\": \"empty\""));
+//! ```
+
+use std::fmt::Write;
+
+use crate::frame::{self, FLAG_ALGO_DEFLATE, FLAG_COMPRESSED, FLAG_HAS_TTL};
+use crate::{crc16, Clock, Engine, ErrorKind, STANDARD};
+
+/// 生成和检查 TTL 向量时使用的当前时间（Unix 时间戳，秒）
+pub const NOW: u64 = 1_700_000_000;
+
+/// 合法输入的测试向量
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Vector {
+    /// 名称
+    pub name: &'static str,
+    /// 原始数据
+    pub input: Vec<u8>,
+    /// 是否允许压缩
+    pub compress: bool,
+    /// 有效期（秒），`None` 表示不带 TTL
+    pub ttl_seconds: Option<u32>,
+    /// 编码结果
+    pub encoded: String,
+    /// 编码结果是否与压缩库无关（未压缩时为 `true`）
+    pub exact: bool,
+}
+
+/// 非法编码的测试向量
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ErrorVector {
+    /// 名称
+    pub name: &'static str,
+    /// 非法编码
+    pub encoded: String,
+    /// 是否按 UTF-8 字符串解码
+    pub as_string: bool,
+    /// 应当返回的错误类别
+    pub error: ErrorKind,
+}
+
+/// 使用固定时钟 [`NOW`] 的标准引擎
+pub fn engine() -> Engine {
+    STANDARD.with_clock(Clock::Fixed(NOW))
+}
+
+/// 合法输入的测试向量
+pub fn vectors() -> Vec<Vector> {
+    let repeated = b"Hello, Dogxi! ".repeat(8);
+    let cases: [(&'static str, Vec<u8>, bool, Option<u32>); 13] = [
+        ("empty", vec![], true, None),
+        ("one_byte", b"a".to_vec(), true, None),
+        ("two_bytes", b"ab".to_vec(), true, None),
+        ("three_bytes", b"abc".to_vec(), true, None),
+        ("hello", b"Hello".to_vec(), true, None),
+        ("utf8", "你好，Dogxi！".as_bytes().to_vec(), true, None),
+        (
+            "binary",
+            vec![0x00, 0x01, 0x02, 0x7F, 0x80, 0xFE, 0xFF],
+            true,
+            None,
+        ),
+        ("all_bytes", (0..=255).collect(), true, None),
+        ("compressed", repeated.clone(), true, None),
+        ("compression_disabled", repeated.clone(), false, None),
+        ("ttl", b"session".to_vec(), true, Some(3600)),
+        ("ttl_never_expires", b"session".to_vec(), true, Some(0)),
+        ("ttl_compressed", repeated, true, Some(60)),
+    ];
+
+    let engine = engine();
+    cases
+        .into_iter()
+        .map(|(name, input, compress, ttl_seconds)| {
+            let encoded = match ttl_seconds {
+                Some(ttl) => engine.encode_with_ttl_and_options(&input, ttl, compress),
+                None => engine.encode_with_options(&input, compress),
+            };
+            let exact = !engine.is_compressed(&encoded).unwrap_or(false);
+            Vector {
+                name,
+                input,
+                compress,
+                ttl_seconds,
+                encoded,
+                exact,
+            }
+        })
+        .collect()
+}
+
+/// 非法编码的测试向量，覆盖解码时可能出现的每一类错误
+pub fn error_vectors() -> Vec<ErrorVector> {
+    let hello = frame::build(b"Hello", false, None, None);
+
+    // 校验和被篡改
+    let mut tampered = hello.clone();
+    tampered[1] ^= 0xFF;
+
+    // 标记为压缩，但负载不是有效的 DEFLATE 数据
+    let garbage = [0x00, 0x05, 0xFF, 0xFF, 0xFF, 0xFF];
+    let mut bad_deflate = vec![FLAG_COMPRESSED | FLAG_ALGO_DEFLATE];
+    bad_deflate.extend_from_slice(&crc16(b"Hello").to_be_bytes());
+    bad_deflate.extend_from_slice(&garbage);
+
+    // TTL 头部不完整
+    let mut short_ttl = vec![FLAG_HAS_TTL];
+    short_ttl.extend_from_slice(&crc16(b"").to_be_bytes());
+    short_ttl.extend_from_slice(&(NOW as u32).to_be_bytes());
+
+    let expired = frame::build(b"session", false, Some(((NOW - 3600) as u32, 60)), None);
+
+    let cases: [(&'static str, String, bool, ErrorKind); 10] = [
+        (
+            "missing_prefix",
+            "hello".into(),
+            false,
+            ErrorKind::InvalidPrefix,
+        ),
+        (
+            "invalid_character",
+            "dx!!!!".into(),
+            false,
+            ErrorKind::InvalidCharacter,
+        ),
+        (
+            "invalid_length",
+            "dxA".into(),
+            false,
+            ErrorKind::InvalidLength,
+        ),
+        (
+            "header_too_short",
+            encode_frame(&[0x00, 0xFF]),
+            false,
+            ErrorKind::InvalidHeader,
+        ),
+        (
+            "ttl_header_too_short",
+            encode_frame(&short_ttl),
+            false,
+            ErrorKind::InvalidHeader,
+        ),
+        (
+            "invalid_flags",
+            encode_frame(&[0x80, 0xFF, 0xFF]),
+            false,
+            ErrorKind::InvalidFlags,
+        ),
+        (
+            "checksum_mismatch",
+            encode_frame(&tampered),
+            false,
+            ErrorKind::ChecksumMismatch,
+        ),
+        (
+            "corrupt_deflate",
+            encode_frame(&bad_deflate),
+            false,
+            ErrorKind::Compression,
+        ),
+        (
+            "ttl_expired",
+            encode_frame(&expired),
+            false,
+            ErrorKind::TtlExpired,
+        ),
+        (
+            "invalid_utf8",
+            encode_frame(&frame::build(&[0xFF, 0xFE], false, None, None)),
+            true,
+            ErrorKind::InvalidUtf8,
+        ),
+    ];
+
+    cases
+        .into_iter()
+        .map(|(name, encoded, as_string, error)| ErrorVector {
+            name,
+            encoded,
+            as_string,
+            error,
+        })
+        .collect()
+}
+
+fn encode_frame(frame: &[u8]) -> String {
+    STANDARD.encode_frame(frame)
+}
+
+/// 生成 `tests/vectors.json` 的内容
+pub fn to_json() -> String {
+    let mut out = String::new();
+    out.push_str("{\n");
+    let _ = writeln!(out, "  \"version\": \"{}\",", crate::get_info().version);
+    let _ = writeln!(out, "  \"now\": {},", NOW);
+
+    out.push_str("  \"vectors\": [\n");
+    let vectors = vectors();
+    for (i, v) in vectors.iter().enumerate() {
+        let ttl = v
+            .ttl_seconds
+            .map_or_else(|| "null".to_string(), |t| t.to_string());
+        let _ = write!(
+            out,
+            "    {{\n      \"name\": \"{}\",\n      \"input\": \"{}\",\n      \"compress\": {},\n      \"ttl_seconds\": {},\n      \"encoded\": \"{}\",\n      \"exact\": {}\n    }}",
+            v.name,
+            hex(&v.input),
+            v.compress,
+            ttl,
+            v.encoded,
+            v.exact
+        );
+        out.push_str(if i + 1 < vectors.len() { ",\n" } else { "\n" });
+    }
+    out.push_str("  ],\n");
+
+    out.push_str("  \"errors\": [\n");
+    let errors = error_vectors();
+    for (i, v) in errors.iter().enumerate() {
+        let _ = write!(
+            out,
+            "    {{\n      \"name\": \"{}\",\n      \"encoded\": \"{}\",\n      \"as_string\": {},\n      \"error\": \"{:?}\"\n    }}",
+            v.name, v.encoded, v.as_string, v.error
+        );
+        out.push_str(if i + 1 < errors.len() { ",\n" } else { "\n" });
+    }
+    out.push_str("  ]\n}\n");
+    out
+}
+
+fn hex(data: &[u8]) -> String {
+    data.iter().fold(String::new(), |mut s, b| {
+        let _ = write!(s, "{:02x}", b);
+        s
+    })
+}
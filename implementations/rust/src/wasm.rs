@@ -0,0 +1,222 @@
+//! WebAssembly 绑定（需要启用 `wasm` feature）
+//!
+//! 通过 `wasm-bindgen` 导出编解码、检查和 TTL 函数，供浏览器和 Node.js 使用：
+//!
+//! ```sh
+//! cargo rustc --lib --release --target wasm32-unknown-unknown --features wasm --crate-type cdylib
+//! wasm-bindgen --target web --out-dir pkg target/wasm32-unknown-unknown/release/dxcode.wasm
+//! ```
+//!
+//! ```js
+//! import init, { encode, decode, setClock } from './pkg/dxcode.js'
+//!
+//! await init()
+//! const encoded = encode('你好，Dogxi！')
+//! const bytes = decode(encoded) // Uint8Array
+//! setClock(() => serverTime()) // 使用服务器时间检查 TTL
+//! ```
+//!
+//! 输入可以是字符串（按 UTF-8 编码）或 `Uint8Array`。解码失败时抛出
+//! `name` 为 `DxEncodingError` 的 `Error`，`kind` 属性为错误类别（如 `"TtlExpired"`）。
+
+use std::cell::RefCell;
+
+use js_sys::{Function, Uint8Array};
+use wasm_bindgen::prelude::*;
+
+use crate::{clock, Clock, DxError, Engine, STANDARD};
+
+thread_local! {
+    static CLOCK: RefCell<Option<Function>> = const { RefCell::new(None) };
+}
+
+/// 使用 JS 时钟的标准引擎
+fn engine() -> Engine {
+    STANDARD.with_clock(Clock::Custom(js_now))
+}
+
+/// 当前时间：优先使用 [`set_clock`] 设置的函数，否则使用 `Date.now()`
+fn js_now() -> u64 {
+    CLOCK
+        .with(|clock| {
+            let f = clock.borrow();
+            f.as_ref()?.call0(&JsValue::NULL).ok()?.as_f64()
+        })
+        .filter(|now| now.is_finite() && *now >= 0.0)
+        .map_or_else(clock::system_now, |now| now as u64)
+}
+
+fn to_bytes(input: &JsValue) -> Result<Vec<u8>, JsError> {
+    if let Some(s) = input.as_string() {
+        Ok(s.into_bytes())
+    } else if let Some(array) = input.dyn_ref::<Uint8Array>() {
+        Ok(array.to_vec())
+    } else {
+        Err(JsError::new("input must be a string or Uint8Array"))
+    }
+}
+
+fn to_js_error(e: DxError) -> JsValue {
+    let error = js_sys::Error::new(&e.to_string());
+    error.set_name("DxEncodingError");
+    let _ = js_sys::Reflect::set(
+        &error,
+        &JsValue::from_str("kind"),
+        &JsValue::from_str(&format!("{:?}", e.kind())),
+    );
+    error.into()
+}
+
+/// 设置检查 TTL 时使用的时钟
+///
+/// `clock` 返回 Unix 时间戳（秒）；传入 `null` 恢复为 `Date.now()`。
+#[wasm_bindgen(js_name = setClock)]
+pub fn set_clock(
+    #[wasm_bindgen(unchecked_param_type = "(() => number) | null")] clock: Option<Function>,
+) {
+    CLOCK.with(|c| *c.borrow_mut() = clock);
+}
+
+/// 编码字符串或字节数据
+#[wasm_bindgen]
+pub fn encode(
+    #[wasm_bindgen(unchecked_param_type = "string | Uint8Array")] input: JsValue,
+    compress: Option<bool>,
+) -> Result<String, JsError> {
+    let data = to_bytes(&input)?;
+    Ok(engine().encode_with_options(&data, compress.unwrap_or(true)))
+}
+
+/// 使用 TTL 编码字符串或字节数据
+#[wasm_bindgen(js_name = encodeWithTtl)]
+pub fn encode_with_ttl(
+    #[wasm_bindgen(unchecked_param_type = "string | Uint8Array")] input: JsValue,
+    #[wasm_bindgen(js_name = ttlSeconds)] ttl_seconds: u32,
+    compress: Option<bool>,
+) -> Result<String, JsError> {
+    let data = to_bytes(&input)?;
+    Ok(engine().encode_with_ttl_and_options(&data, ttl_seconds, compress.unwrap_or(true)))
+}
+
+/// 解码为 `Uint8Array`（`checkTtl` 默认为 `true`）
+#[wasm_bindgen]
+pub fn decode(
+    encoded: &str,
+    #[wasm_bindgen(js_name = checkTtl)] check_ttl: Option<bool>,
+) -> Result<Vec<u8>, JsValue> {
+    engine()
+        .decode_with_options(encoded, check_ttl.unwrap_or(true))
+        .map_err(to_js_error)
+}
+
+/// 解码为字符串（`checkTtl` 默认为 `true`）
+#[wasm_bindgen(js_name = decodeStr)]
+pub fn decode_str(
+    encoded: &str,
+    #[wasm_bindgen(js_name = checkTtl)] check_ttl: Option<bool>,
+) -> Result<String, JsValue> {
+    engine()
+        .decode_with_options(encoded, check_ttl.unwrap_or(true))
+        .and_then(|bytes| Ok(String::from_utf8(bytes)?))
+        .map_err(to_js_error)
+}
+
+/// 检查字符串是否为 DX 编码（仅检查前缀、长度和字符）
+#[wasm_bindgen(js_name = isEncoded)]
+pub fn is_encoded(s: &str) -> bool {
+    STANDARD.is_encoded(s)
+}
+
+/// 验证校验和，不匹配时返回 `false`，格式错误时抛出异常
+#[wasm_bindgen]
+pub fn verify(encoded: &str) -> Result<bool, JsValue> {
+    engine().verify(encoded).map_err(to_js_error)
+}
+
+/// 头部信息
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy)]
+pub struct Inspection {
+    /// flags 字节
+    #[wasm_bindgen(readonly)]
+    pub flags: u8,
+    /// 存储的 CRC16 校验和
+    #[wasm_bindgen(readonly)]
+    pub checksum: u16,
+    /// 是否压缩
+    #[wasm_bindgen(readonly)]
+    pub compressed: bool,
+    /// 压缩前的大小，未压缩时为 `undefined`
+    #[wasm_bindgen(readonly, js_name = originalSize)]
+    pub original_size: Option<u16>,
+    /// 创建时间（Unix 时间戳，秒），不带 TTL 时为 `undefined`
+    #[wasm_bindgen(readonly, js_name = createdAt)]
+    pub created_at: Option<f64>,
+    /// 有效期（秒），不带 TTL 时为 `undefined`
+    #[wasm_bindgen(readonly, js_name = ttlSeconds)]
+    pub ttl_seconds: Option<u32>,
+    /// 内容类型，未标记时为 `undefined`
+    #[wasm_bindgen(readonly, js_name = contentType)]
+    pub content_type: Option<u8>,
+    /// 负载字节数
+    #[wasm_bindgen(readonly, js_name = payloadLength)]
+    pub payload_length: u32,
+}
+
+/// 解析头部信息（不验证校验和）
+#[wasm_bindgen]
+pub fn inspect(encoded: &str) -> Result<Inspection, JsValue> {
+    let header = engine().get_header(encoded).map_err(to_js_error)?;
+    Ok(Inspection {
+        flags: header.flags,
+        checksum: header.checksum,
+        compressed: header.compressed,
+        original_size: header.original_size,
+        created_at: header.created_at.map(|t| t as f64),
+        ttl_seconds: header.ttl_seconds,
+        content_type: header.content_type,
+        payload_length: header.payload_len as u32,
+    })
+}
+
+/// TTL 信息
+#[wasm_bindgen(js_name = TtlInfo)]
+#[derive(Debug, Clone, Copy)]
+pub struct WasmTtlInfo {
+    /// 创建时间（Unix 时间戳，秒）
+    #[wasm_bindgen(readonly, js_name = createdAt)]
+    pub created_at: f64,
+    /// 有效期（秒），0 表示永不过期
+    #[wasm_bindgen(readonly, js_name = ttlSeconds)]
+    pub ttl_seconds: u32,
+    /// 过期时间（Unix 时间戳，秒），永不过期时为 `undefined`
+    #[wasm_bindgen(readonly, js_name = expiresAt)]
+    pub expires_at: Option<f64>,
+    /// 是否已过期
+    #[wasm_bindgen(readonly, js_name = isExpired)]
+    pub is_expired: bool,
+}
+
+/// 检查是否包含 TTL
+#[wasm_bindgen(js_name = hasTtl)]
+pub fn has_ttl(encoded: &str) -> Result<bool, JsValue> {
+    engine().has_ttl(encoded).map_err(to_js_error)
+}
+
+/// 获取 TTL 信息，不带 TTL 时返回 `undefined`
+#[wasm_bindgen(js_name = getTtlInfo)]
+pub fn get_ttl_info(encoded: &str) -> Result<Option<WasmTtlInfo>, JsValue> {
+    let info = engine().get_ttl_info(encoded).map_err(to_js_error)?;
+    Ok(info.map(|info| WasmTtlInfo {
+        created_at: info.created_at as f64,
+        ttl_seconds: info.ttl_seconds,
+        expires_at: info.expires_at.map(|t| t as f64),
+        is_expired: info.is_expired,
+    }))
+}
+
+/// 检查是否已过期（没有 TTL 的数据永不过期）
+#[wasm_bindgen(js_name = isExpired)]
+pub fn is_expired(encoded: &str) -> Result<bool, JsValue> {
+    engine().is_expired(encoded).map_err(to_js_error)
+}
@@ -0,0 +1,136 @@
+/**
+ * C 接口测试，由 tests/ffi.rs 编译并运行
+ */
+
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+
+#include "dxcode.h"
+
+static int failures = 0;
+
+#define CHECK(cond)                                                    \
+    do {                                                               \
+        if (!(cond)) {                                                 \
+            fprintf(stderr, "%s:%d: check failed: %s\n", __FILE__,     \
+                    __LINE__, #cond);                                  \
+            failures++;                                                \
+        }                                                              \
+    } while (0)
+
+static void test_roundtrip(void) {
+    const char *text = "你好，Dogxi！";
+    char *encoded = NULL;
+    uint8_t *decoded = NULL;
+    size_t decoded_len = 0;
+
+    CHECK(dx_encode((const uint8_t *)text, strlen(text), &encoded) == DX_OK);
+    CHECK(strncmp(encoded, "dx", 2) == 0);
+    CHECK(dx_is_encoded(encoded));
+
+    CHECK(dx_decode(encoded, &decoded, &decoded_len) == DX_OK);
+    CHECK(decoded_len == strlen(text));
+    CHECK(memcmp(decoded, text, decoded_len) == 0);
+
+    bool valid = false;
+    CHECK(dx_verify(encoded, &valid) == DX_OK && valid);
+
+    dx_free(decoded);
+    dx_free(encoded);
+    dx_free(NULL);
+
+    /* 空输入 */
+    CHECK(dx_encode(NULL, 0, &encoded) == DX_OK);
+    CHECK(dx_decode(encoded, &decoded, &decoded_len) == DX_OK);
+    CHECK(decoded_len == 0);
+    dx_free(decoded);
+    dx_free(encoded);
+}
+
+static void test_caller_buffers(void) {
+    const uint8_t data[] = {0x00, 0x01, 0x02, 0xFE, 0xFF};
+    char small[4];
+    char encoded[64];
+    uint8_t decoded[64];
+    size_t len = 0;
+
+    CHECK(dx_encode_into(data, sizeof(data), small, sizeof(small), &len) ==
+          DX_ERROR_BUFFER_TOO_SMALL);
+    CHECK(len > sizeof(small));
+    CHECK(dx_encode_into(data, sizeof(data), encoded, len, &len) ==
+          DX_ERROR_BUFFER_TOO_SMALL);
+    CHECK(dx_encode_into(data, sizeof(data), encoded, len + 1, &len) == DX_OK);
+    CHECK(strlen(encoded) == len);
+
+    CHECK(dx_decode_into(encoded, decoded, 2, &len) == DX_ERROR_BUFFER_TOO_SMALL);
+    CHECK(len == sizeof(data));
+    CHECK(dx_decode_into(encoded, decoded, sizeof(decoded), &len) == DX_OK);
+    CHECK(len == sizeof(data));
+    CHECK(memcmp(decoded, data, len) == 0);
+}
+
+static void test_inspect(void) {
+    uint8_t data[200];
+    char *encoded = NULL;
+    dx_header_t header;
+
+    memset(data, 'a', sizeof(data));
+    CHECK(dx_encode(data, sizeof(data), &encoded) == DX_OK);
+    CHECK(dx_inspect(encoded, &header) == DX_OK);
+    CHECK(header.compressed);
+    CHECK(header.original_size == sizeof(data));
+    CHECK(header.checksum == dx_crc16(data, sizeof(data)));
+    CHECK(!header.has_ttl);
+    dx_free(encoded);
+
+    dx_ttl_info_t info;
+    CHECK(dx_encode_with_ttl(data, 10, 3600, &encoded) == DX_OK);
+    CHECK(dx_inspect(encoded, &header) == DX_OK);
+    CHECK(header.has_ttl && header.ttl_seconds == 3600);
+    CHECK(dx_get_ttl_info(encoded, &info) == DX_OK);
+    CHECK(info.has_ttl && !info.is_expired);
+    CHECK(info.expires_at == info.created_at + 3600);
+    dx_free(encoded);
+}
+
+static void test_errors(void) {
+    uint8_t *decoded = NULL;
+    size_t len = 0;
+    bool valid = true;
+
+    CHECK(dx_decode("hello", &decoded, &len) == DX_ERROR_INVALID_PREFIX);
+    CHECK(dx_decode("dx!!!!", &decoded, &len) == DX_ERROR_INVALID_CHARACTER);
+    CHECK(dx_decode(NULL, &decoded, &len) == DX_ERROR_INVALID_INPUT);
+    CHECK(!dx_is_encoded("hello"));
+    CHECK(!dx_is_encoded(NULL));
+
+    /* 篡改校验和 */
+    char *encoded = NULL;
+    CHECK(dx_encode((const uint8_t *)"Hello World", 11, &encoded) == DX_OK);
+    size_t i = strlen(encoded) - 3;
+    encoded[i] = encoded[i] == 'A' ? 'B' : 'A';
+    CHECK(dx_decode(encoded, &decoded, &len) == DX_ERROR_CHECKSUM_MISMATCH);
+    CHECK(dx_verify(encoded, &valid) == DX_OK && !valid);
+    dx_free(encoded);
+
+    CHECK(strcmp(dx_error_string(DX_ERROR_TTL_EXPIRED), "TTL expired") == 0);
+    CHECK(strcmp(dx_error_string(DX_ERROR_INVALID_SOURCE), "invalid source encoding") == 0);
+    CHECK(strcmp(dx_error_string(DX_ERROR_INVALID_EXPIRY), "invalid expiry") == 0);
+    CHECK(strcmp(dx_error_string(DX_ERROR_INVALID_COOKIE), "invalid cookie") == 0);
+    CHECK(strcmp(dx_error_string(12345), "unknown error") == 0);
+}
+
+int main(void) {
+    test_roundtrip();
+    test_caller_buffers();
+    test_inspect();
+    test_errors();
+
+    if (failures > 0) {
+        fprintf(stderr, "%d check(s) failed\n", failures);
+        return 1;
+    }
+    printf("all C tests passed\n");
+    return 0;
+}
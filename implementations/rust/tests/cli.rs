@@ -0,0 +1,684 @@
+//! 命令行工具测试：参数解析、退出码和二进制安全的输入输出
+
+#![cfg(feature = "cli")]
+
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command, Output, Stdio};
+
+use dxcode::vectors::error_vectors;
+
+fn dxc(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_dxc"))
+        .args(["--lang", "en"])
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+fn dxc_stdin(args: &[&str], input: &[u8]) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_dxc"))
+        .args(["--lang", "en"])
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    // 参数错误时 dxc 不读取标准输入就退出，写入可能遇到 BrokenPipe
+    if let Err(e) = child.stdin.take().unwrap().write_all(input) {
+        assert_eq!(e.kind(), std::io::ErrorKind::BrokenPipe);
+    }
+    child.wait_with_output().unwrap()
+}
+
+fn error_vector(name: &str) -> String {
+    error_vectors()
+        .into_iter()
+        .find(|v| v.name == name)
+        .unwrap()
+        .encoded
+}
+
+#[test]
+fn test_options_after_input_and_double_dash() {
+    let out = dxc(&["encode", "Hello", "--no-compress"]);
+    assert!(out.status.success());
+    let encoded = String::from_utf8(out.stdout).unwrap();
+    assert_eq!(encoded.trim(), dxcode::encode_str("Hello"));
+
+    let out = dxc(&["encode", "--", "--no-compress"]);
+    let encoded = String::from_utf8(out.stdout).unwrap();
+    assert_eq!(dxcode::decode_str(encoded.trim()).unwrap(), "--no-compress");
+
+    let out = dxc(&["decode", &dxcode::encode_str("Hello"), "--ignore-ttl"]);
+    assert_eq!(out.stdout, b"Hello");
+
+    // `--` 之后的 --lang 也是普通文本
+    for args in [&["--lang"][..], &["--lang", "zh"], &["--lang=zh"]] {
+        let out = dxc(&[&["encode", "--"], args].concat());
+        assert!(out.status.success(), "dxc encode -- {:?}", args);
+        let encoded = String::from_utf8(out.stdout).unwrap();
+        assert_eq!(dxcode::decode_str(encoded.trim()).unwrap(), args.join(" "));
+    }
+    let out = dxc(&["encode", "--lang", "--", "x"]);
+    assert_eq!(out.status.code(), Some(64));
+}
+
+#[test]
+fn test_exit_codes() {
+    let valid = dxcode::encode_str("Hello");
+    assert_eq!(dxc(&["decode", &valid]).status.code(), Some(0));
+    assert_eq!(dxc(&["decode", "hello"]).status.code(), Some(1));
+    assert_eq!(dxc(&["check", "hello"]).status.code(), Some(1));
+
+    let tampered = error_vector("checksum_mismatch");
+    assert_eq!(dxc(&["decode", &tampered]).status.code(), Some(2));
+    assert_eq!(dxc(&["verify", &tampered]).status.code(), Some(2));
+
+    let expired = error_vector("ttl_expired");
+    assert_eq!(dxc(&["decode", &expired]).status.code(), Some(3));
+    assert_eq!(dxc(&["expired", &expired]).status.code(), Some(3));
+    assert_eq!(
+        dxc(&["decode", "--ignore-ttl", &expired]).status.code(),
+        Some(0)
+    );
+}
+
+#[test]
+fn test_usage_errors() {
+    for args in [
+        &["frobnicate"][..],
+        &["encode", "--bogus", "x"],
+        &["encode", "--ttl"],
+        &["encode", "--ttl", "soon", "x"],
+        &["encode", "--ttl", "1h30", "x"],
+        &["encode", "--ttl", "2h2h", "x"],
+        &["encode", "--ttl", "30m1h", "x"],
+        &["encode", "--ttl", "200000d", "x"],
+        &["encode", "--expires", "2020-01-01T00:00:00Z", "x"],
+        &["encode", "--expires", "tomorrow", "x"],
+        &[
+            "encode",
+            "--ttl",
+            "1h",
+            "--expires",
+            "2999-01-01T00:00:00Z",
+            "x",
+        ],
+        &["decode", "dxA", "dxB"],
+        &["info", "extra"],
+    ] {
+        let out = dxc(args);
+        assert_eq!(out.status.code(), Some(64), "dxc {:?}", args);
+        assert!(out.stdout.is_empty(), "dxc {:?}", args);
+    }
+
+    let out = dxc(&["frobnicate"]);
+    let stderr = String::from_utf8(out.stderr).unwrap();
+    assert!(stderr.starts_with("error: unknown command 'frobnicate'"));
+}
+
+#[test]
+fn test_subcommand_help() {
+    let out = dxc(&["decode", "--help"]);
+    assert!(out.status.success());
+    let help = String::from_utf8(out.stdout).unwrap();
+    assert!(help.starts_with("Usage: dxc decode"));
+    assert!(help.contains("--ignore-ttl"));
+
+    assert_eq!(
+        dxc(&["help", "encode"]).stdout,
+        dxc(&["encode", "-h"]).stdout
+    );
+}
+
+#[test]
+fn test_binary_files_roundtrip() {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("cli_binary");
+    fs::create_dir_all(&dir).unwrap();
+    let (input, encoded, decoded) = (dir.join("in.bin"), dir.join("in.dx"), dir.join("out.bin"));
+    let data: Vec<u8> = (0..=255).chain([b'\n', b' ', 0]).collect();
+    fs::write(&input, &data).unwrap();
+
+    let path = |p: &Path| p.to_str().unwrap().to_string();
+    let out = dxc(&["encode", "-i", &path(&input), "-o", &path(&encoded)]);
+    assert!(out.status.success());
+    assert!(out.stdout.is_empty());
+    let token = fs::read_to_string(&encoded).unwrap();
+    assert_eq!(dxcode::decode(token.trim()).unwrap(), data);
+
+    let out = dxc(&[
+        "decode",
+        "--input",
+        &path(&encoded),
+        "--output",
+        &path(&decoded),
+    ]);
+    assert!(out.status.success());
+    assert_eq!(fs::read(&decoded).unwrap(), data);
+
+    // 管道输出按原始字节写出
+    assert_eq!(dxc(&["decode", token.trim()]).stdout, data);
+
+    let out = dxc(&["encode", "-i", &path(&input), "extra"]);
+    assert_eq!(out.status.code(), Some(64));
+    let out = dxc(&["decode", "-i", &path(&dir.join("missing"))]);
+    assert_eq!(out.status.code(), Some(1));
+}
+
+#[test]
+fn test_stdin_is_raw_bytes() {
+    let out = dxc_stdin(&["encode"], b"  Hello\n\n");
+    let token = String::from_utf8(out.stdout).unwrap();
+    assert_eq!(dxcode::decode(token.trim()).unwrap(), b"  Hello\n\n");
+
+    let out = dxc_stdin(&["encode"], &[0xFF, 0x00, 0xFE]);
+    let token = String::from_utf8(out.stdout).unwrap();
+    assert_eq!(dxcode::decode(token.trim()).unwrap(), [0xFF, 0x00, 0xFE]);
+
+    // 解码输入中的空白会被去除
+    let out = dxc_stdin(&["decode"], format!("{}\n", token).as_bytes());
+    assert_eq!(out.stdout, [0xFF, 0x00, 0xFE]);
+}
+
+#[test]
+fn test_text_requires_utf8() {
+    let out = dxc_stdin(&["encode", "--text"], &[0xFF, 0xFE]);
+    assert_eq!(out.status.code(), Some(1));
+    assert!(out.stdout.is_empty());
+    assert_eq!(
+        dxc_stdin(&["encode", "--text"], b"Hi").status.code(),
+        Some(0)
+    );
+
+    let binary = dxcode::encode(&[0xFF, 0xFE]);
+    assert_eq!(dxc(&["decode", "--text", &binary]).status.code(), Some(1));
+    assert_eq!(dxc(&["decode", &binary]).status.code(), Some(0));
+}
+
+fn json(out: &[u8]) -> serde_json::Value {
+    serde_json::from_slice(out).unwrap()
+}
+
+#[test]
+fn test_json_output() {
+    let token = dxcode::encode_with_ttl(&b"Hello, Dogxi! ".repeat(8), 3600);
+
+    let out = dxc(&["check", &token, "--json"]);
+    assert!(out.status.success());
+    let value = json(&out.stdout);
+    assert_eq!(value["schema"], 1);
+    assert_eq!(value["valid"], true);
+    assert_eq!(value["compressed"], true);
+    assert_eq!(value["header"]["original_size"], 112);
+    assert_eq!(value["ttl"]["ttl_seconds"], 3600);
+    let (stored, _) = dxcode::get_checksum(&token).unwrap();
+    assert_eq!(value["crc16"], format!("0x{:04X}", stored));
+
+    let out = dxc(&[
+        "verify",
+        "--format=json",
+        &error_vector("checksum_mismatch"),
+    ]);
+    assert_eq!(out.status.code(), Some(2));
+    let value = json(&out.stdout);
+    assert_eq!(value["valid"], false);
+    assert_ne!(value["crc16"], value["computed_crc16"]);
+
+    let value = json(&dxc(&["ttl", "--json", &dxcode::encode_str("x")]).stdout);
+    assert_eq!(value["has_ttl"], false);
+    assert_eq!(value["ttl"], serde_json::Value::Null);
+
+    let value = json(&dxc(&["info", "--json"]).stdout);
+    assert_eq!(value["prefix"], "dx");
+
+    let value = json(&dxc(&["decode", "--json", &dxcode::encode(&[0xFF, 0x00])]).stdout);
+    assert_eq!(value["encoding"], "hex");
+    assert_eq!(value["data"], "ff00");
+
+    let out = dxc(&["expired", "--json", &error_vector("ttl_expired")]);
+    assert_eq!(out.status.code(), Some(3));
+    assert_eq!(json(&out.stdout)["expired"], true);
+}
+
+#[test]
+fn test_json_errors_on_stderr() {
+    let out = dxc(&["decode", "--json", &error_vector("checksum_mismatch")]);
+    assert_eq!(out.status.code(), Some(2));
+    assert!(out.stdout.is_empty());
+    let error = &json(&out.stderr)["error"];
+    assert_eq!(error["kind"], "ChecksumMismatch");
+    assert_eq!(error["exit_code"], 2);
+
+    for args in [
+        &["decode", "--json", "--bogus"][..],
+        &["frobnicate", "--json"],
+    ] {
+        let out = dxc(args);
+        assert_eq!(out.status.code(), Some(64));
+        assert_eq!(json(&out.stderr)["error"]["kind"], "Usage");
+    }
+    assert_eq!(dxc(&["info", "--format", "xml"]).status.code(), Some(64));
+}
+
+#[test]
+fn test_tsv_output() {
+    let out = dxc(&["verify", "--format", "tsv", &dxcode::encode_str("Hello")]);
+    assert!(out.status.success());
+    let tsv = String::from_utf8(out.stdout).unwrap();
+    assert!(tsv.lines().any(|l| l == "valid\ttrue"));
+    assert!(tsv.lines().any(|l| l == "ttl\t"));
+    assert!(tsv.lines().any(|l| l == "schema\t1"));
+}
+
+#[test]
+fn test_inspect() {
+    let token = dxcode::vectors::vectors()
+        .into_iter()
+        .find(|v| v.name == "ttl_compressed")
+        .unwrap()
+        .encoded;
+    let out = dxc(&["inspect", "--utc", &token]);
+    assert!(out.status.success());
+    let text = String::from_utf8(out.stdout).unwrap();
+    assert!(text.contains("Flags:      0x07 (compressed, deflate, ttl)"));
+    assert!(text.contains("Created:    2023-11-14T22:13:20Z (1700000000)"));
+    assert!(text.contains("Expires:    2023-11-14T22:14:20Z (1700000060)"));
+    assert!(text.contains("Size:       112 bytes declared, 112 bytes actual"));
+    assert!(text.contains("\n00000000  "));
+
+    // 校验和或 flags 无效时需要 --raw
+    for (name, code) in [("checksum_mismatch", 2), ("invalid_flags", 1)] {
+        let bad = error_vector(name);
+        assert_eq!(dxc(&["inspect", &bad]).status.code(), Some(code));
+        let out = dxc(&["inspect", "--raw", "--json", &bad]);
+        assert!(out.status.success());
+        let value = json(&out.stdout);
+        assert!(value["error"]["kind"].is_string());
+        assert!(value["payload_hex"].is_string());
+    }
+    let value = json(&dxc(&["inspect", "--raw", "--json", &error_vector("invalid_flags")]).stdout);
+    assert_eq!(value["unknown_flags"], 0x80);
+    let value = json(
+        &dxc(&[
+            "inspect",
+            "--raw",
+            "--json",
+            &error_vector("checksum_mismatch"),
+        ])
+        .stdout,
+    );
+    assert_eq!(value["checksum_ok"], false);
+}
+
+#[test]
+fn test_batch_lines() {
+    let tokens: Vec<String> = (0..2000)
+        .map(|i| dxcode::encode_str(&format!("record {}", i)))
+        .collect();
+    let mut input = tokens.join("\r\n").into_bytes();
+    input.push(b'\n');
+
+    let out = dxc_stdin(&["decode", "--lines", "-j", "4"], &input);
+    assert!(out.status.success());
+    let expected: String = (0..2000).map(|i| format!("record {}\n", i)).collect();
+    assert_eq!(String::from_utf8(out.stdout).unwrap(), expected);
+
+    let out = dxc_stdin(&["encode", "-0"], b"a\0\0b\xff");
+    let encoded: Vec<&[u8]> = out.stdout.split(|&b| b == 0).collect();
+    assert_eq!(encoded.len(), 4);
+    assert_eq!(
+        dxcode::decode(std::str::from_utf8(encoded[1]).unwrap()).unwrap(),
+        b""
+    );
+    assert_eq!(
+        dxcode::decode(std::str::from_utf8(encoded[2]).unwrap()).unwrap(),
+        b"b\xff"
+    );
+}
+
+#[test]
+fn test_batch_rejects_wrap() {
+    let input = format!("{}\nbbb\n", "a".repeat(100));
+    for args in [&["--wrap", "20"][..], &["--crlf"], &["-0", "--wrap=20"]] {
+        let out = dxc_stdin(&[&["encode", "--lines"], args].concat(), input.as_bytes());
+        assert_eq!(out.status.code(), Some(64), "dxc encode --lines {:?}", args);
+        assert!(out.stdout.is_empty());
+    }
+}
+
+#[test]
+fn test_batch_error_policies() {
+    let good = dxcode::encode_str("ok");
+    let input = format!(
+        "{}\nbad\n{}\n{}\n",
+        good,
+        error_vector("checksum_mismatch"),
+        good
+    );
+
+    // 默认：报告错误，失败的记录输出空行占位
+    let out = dxc_stdin(&["decode", "--lines"], input.as_bytes());
+    assert_eq!(out.status.code(), Some(1));
+    assert_eq!(out.stdout, b"ok\n\n\nok\n");
+    let stderr = String::from_utf8(out.stderr).unwrap();
+    assert!(stderr.contains("error: line 2: "));
+    assert!(stderr.contains("error: line 3: checksum mismatch"));
+    assert!(stderr.contains("4 records processed, 2 failed"));
+
+    let out = dxc_stdin(&["decode", "--lines", "--skip-errors"], input.as_bytes());
+    assert_eq!(out.status.code(), Some(0));
+    assert_eq!(out.stdout, b"ok\nok\n");
+
+    let out = dxc_stdin(&["decode", "--lines", "--fail-fast"], input.as_bytes());
+    assert_eq!(out.status.code(), Some(1));
+    assert_eq!(out.stdout, b"ok\n");
+    assert!(!String::from_utf8(out.stderr).unwrap().contains("line 3"));
+
+    let out = dxc_stdin(&["decode", "--lines", "--json"], input.as_bytes());
+    let lines: Vec<serde_json::Value> = out
+        .stdout
+        .split(|&b| b == b'\n')
+        .filter(|l| !l.is_empty())
+        .map(json)
+        .collect();
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[1]["line"], 4);
+    let errors: Vec<serde_json::Value> = out
+        .stderr
+        .split(|&b| b == b'\n')
+        .filter(|l| !l.is_empty())
+        .map(json)
+        .collect();
+    assert_eq!(errors[1]["line"], 3);
+    assert_eq!(errors[1]["error"]["kind"], "ChecksumMismatch");
+
+    let out = dxc(&["decode", "--lines", "--skip-errors", "--fail-fast", "x"]);
+    assert_eq!(out.status.code(), Some(64));
+}
+
+#[test]
+fn test_document_fields() {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("cli_document");
+    fs::create_dir_all(&dir).unwrap();
+    let file = dir.join("users.json");
+    let doc = "{\"users\":[{\"name\":\"a\",\"token\":\"s1\"},{\"name\":\"b\",\"token\":\"s2\"}]}";
+    fs::write(&file, doc).unwrap();
+
+    let out = dxc(&[
+        "json",
+        "encode",
+        "-p",
+        "$.users[*].token",
+        file.to_str().unwrap(),
+    ]);
+    assert!(out.status.success());
+    let encoded = json(&out.stdout);
+    assert_eq!(encoded["users"][1]["name"], "b");
+    let token = encoded["users"][1]["token"].as_str().unwrap();
+    assert_eq!(dxcode::decode_str(token).unwrap(), "s2");
+
+    let out = dxc_stdin(
+        &["json", "decode", "--path", "$.users[*].token"],
+        &out.stdout,
+    );
+    assert_eq!(String::from_utf8(out.stdout).unwrap(), doc);
+
+    let csv = "id,secret\n1,hunter2\n";
+    let out = dxc_stdin(&["csv", "encode", "--column", "secret"], csv.as_bytes());
+    let encoded = String::from_utf8(out.stdout).unwrap();
+    assert!(encoded.starts_with("id,secret\n1,dx"));
+    let out = dxc_stdin(&["csv", "decode", "-c", "2"], encoded.as_bytes());
+    assert_eq!(out.stdout, csv.as_bytes());
+
+    let out = dxc_stdin(&["csv", "decode", "--json", "-c", "2"], encoded.as_bytes());
+    assert!(out.status.success());
+    assert_eq!(json(&out.stdout)["document"], csv);
+    let out = dxc_stdin(&["csv", "decode", "--json", "-c", "secret"], csv.as_bytes());
+    assert_eq!(out.status.code(), Some(1));
+    assert_eq!(json(&out.stderr)["error"]["kind"], "InvalidPrefix");
+    let out = dxc_stdin(
+        &["csv", "encode", "--json", "-c", "missing"],
+        csv.as_bytes(),
+    );
+    assert_eq!(out.status.code(), Some(64));
+    assert_eq!(json(&out.stderr)["error"]["kind"], "Usage");
+
+    let toml = "[db]\npassword = \"pw\" # secret\n";
+    let out = dxc_stdin(&["toml", "encode", "-p", "$.db.password"], toml.as_bytes());
+    let encoded = String::from_utf8(out.stdout).unwrap();
+    assert!(encoded.starts_with("[db]\npassword = \"dx") && encoded.ends_with("\" # secret\n"));
+
+    // 用法错误和字段错误
+    assert_eq!(
+        dxc_stdin(&["json", "encode"], b"{}").status.code(),
+        Some(64)
+    );
+    assert_eq!(
+        dxc_stdin(&["json", "-p", "$"], b"{}").status.code(),
+        Some(64)
+    );
+    let out = dxc_stdin(&["json", "encode", "-p", "users"], b"{}");
+    assert_eq!(out.status.code(), Some(64));
+    let out = dxc_stdin(&["csv", "encode", "-c", "missing"], csv.as_bytes());
+    assert_eq!(out.status.code(), Some(64));
+    let out = dxc_stdin(&["json", "decode", "-p", "$.a"], b"{\"a\":\"nope\"}");
+    assert_eq!(out.status.code(), Some(1));
+    assert!(String::from_utf8(out.stderr)
+        .unwrap()
+        .contains("$.a: invalid DX encoding"));
+    let out = dxc_stdin(&["csv", "decode", "-c", "secret"], csv.as_bytes());
+    assert!(String::from_utf8(out.stderr)
+        .unwrap()
+        .contains("line 2, column 'secret'"));
+    assert_eq!(
+        dxc_stdin(&["json", "encode", "-p", "$"], b"{")
+            .status
+            .code(),
+        Some(1)
+    );
+}
+
+#[test]
+fn test_scan() {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("cli_scan");
+    fs::create_dir_all(dir.join("nested")).unwrap();
+    let token = dxcode::encode_str("hello");
+    let engine = dxcode::STANDARD.with_clock(dxcode::Clock::Fixed(1_000));
+    let expired = engine.encode_with_ttl(b"old", 60);
+    fs::write(dir.join("a.log"), format!("run dxdiag\nid={} ok\n", token)).unwrap();
+    fs::write(dir.join("nested/b.txt"), format!("  {}", expired)).unwrap();
+
+    let out = dxc(&["scan", "--utc", dir.to_str().unwrap()]);
+    assert!(out.status.success());
+    let stdout = String::from_utf8(out.stdout).unwrap();
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(lines.len(), 2);
+    assert!(lines[0].ends_with(&format!("a.log:2:4: {} (5 bytes, no TTL)", token)));
+    assert!(lines[1].contains("b.txt:1:3: ") && lines[1].contains("expired 1970-01-01T00:17:40Z"));
+
+    let out = dxc(&[
+        "scan",
+        "--expired-only",
+        "--decode",
+        "--json",
+        dir.to_str().unwrap(),
+    ]);
+    let hits: Vec<serde_json::Value> = out
+        .stdout
+        .split(|&b| b == b'\n')
+        .filter(|l| !l.is_empty())
+        .map(json)
+        .collect();
+    assert_eq!(hits.len(), 1);
+    assert_eq!(hits[0]["token"], expired);
+    assert_eq!(hits[0]["expired"], true);
+    assert_eq!(hits[0]["data"], "old");
+    assert_eq!(hits[0]["ttl"]["expires_at"], 1_060);
+
+    let out = dxc_stdin(&["scan"], format!("x {}", token).as_bytes());
+    assert!(String::from_utf8(out.stdout)
+        .unwrap()
+        .starts_with("-:1:3: "));
+    let out = dxc(&["scan", dir.join("missing").to_str().unwrap()]);
+    assert_eq!(out.status.code(), Some(1));
+}
+
+#[test]
+fn test_convert() {
+    let stdout = |out: Output| String::from_utf8(out.stdout).unwrap();
+    let out = dxc(&["convert", "--from", "base64", "--to", "dx", "SGVsbG8="]);
+    assert!(out.status.success());
+    assert_eq!(stdout(out).trim(), dxcode::encode_str("Hello"));
+
+    // 任意两种编码之间转换，经过 DX 时数据不变
+    let encodings = ["dx", "base64", "base64url", "hex", "base32"];
+    for from in encodings {
+        let source = dxcode::Encoding::from_name(from)
+            .unwrap()
+            .encode(b"\xfb\xffdata");
+        for to in encodings {
+            let out = dxc(&["convert", "-f", from, "-t", to, "--", &source]);
+            assert!(out.status.success(), "{} -> {}", from, to);
+            let target = dxcode::Encoding::from_name(to).unwrap();
+            assert_eq!(target.decode(&stdout(out)).unwrap(), b"\xfb\xffdata");
+        }
+    }
+
+    // 默认丢弃 TTL，--keep-header 时原样保留
+    let engine = dxcode::STANDARD.with_clock(dxcode::Clock::Fixed(1_000));
+    let token = engine.encode_with_ttl(b"old", 60);
+    let out = dxc(&["convert", "-f", "dx", "-t", "hex", &token]);
+    assert_eq!(out.status.code(), Some(3));
+    let out = dxc(&["convert", "-f", "dx", "-t", "hex", "--ignore-ttl", &token]);
+    assert_eq!(stdout(out).trim(), "6f6c64");
+    let out = dxc(&[
+        "convert",
+        "-f",
+        "dx",
+        "-t",
+        "hex",
+        "-it",
+        "--keep-header",
+        &token,
+    ]);
+    let frame = stdout(out);
+    let out = dxc(&[
+        "convert",
+        "-f",
+        "hex",
+        "-t",
+        "dx",
+        "--keep-header",
+        frame.trim(),
+    ]);
+    assert_eq!(stdout(out).trim(), token);
+    let out = dxc(&["convert", "-f", "dx", "-t", "hex", "--keep-header", &token]);
+    assert_eq!(out.status.code(), Some(3));
+    let unpadded = dxcode::STANDARD.with_encode_padding(false);
+    let out = dxc(&[
+        "convert",
+        "-f",
+        "hex",
+        "-t",
+        "dx",
+        "--keep-header",
+        "--no-padding",
+        frame.trim(),
+    ]);
+    assert_eq!(
+        stdout(out).trim(),
+        unpadded
+            .from_frame(&engine.to_frame(&token).unwrap())
+            .unwrap()
+    );
+    let out = dxc(&["convert", "-f", "base64", "-t", "dx", "-np", "SGVsbG8="]);
+    assert_eq!(stdout(out).trim(), unpadded.encode(b"Hello"));
+
+    let out = dxc(&["convert", "--json", "-f", "hex", "-t", "base64", "zz"]);
+    assert_eq!(out.status.code(), Some(1));
+    assert_eq!(json(&out.stderr)["error"]["kind"], "InvalidSource");
+    assert_eq!(dxc(&["convert", "-t", "dx", "ab"]).status.code(), Some(64));
+    let out = dxc(&[
+        "convert",
+        "-f",
+        "hex",
+        "-t",
+        "base64",
+        "--keep-header",
+        "ab",
+    ]);
+    assert_eq!(out.status.code(), Some(64));
+}
+
+#[test]
+fn test_ttl_durations() {
+    let ttl = |args: &[&str]| {
+        let out = dxc(&[&["encode", "--json"], args, &["x"]].concat());
+        assert!(out.status.success(), "dxc encode {:?}", args);
+        json(&out.stdout)["ttl"]["ttl_seconds"].as_u64().unwrap()
+    };
+    assert_eq!(ttl(&["--ttl", "90m"]), 5_400);
+    assert_eq!(ttl(&["--ttl", "1h30m"]), 5_400);
+    assert_eq!(ttl(&["--ttl", "7d"]), 604_800);
+    assert_eq!(ttl(&["--ttl", "3600"]), 3_600);
+    let expires = ttl(&["--expires", "2099-01-01T00:00:00+08:00"]);
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    assert!((now + expires).abs_diff(4_070_880_000) <= 2);
+
+    let engine = dxcode::STANDARD.with_clock(dxcode::Clock::Fixed(now));
+    let token = engine.encode_with_ttl(b"x", 8_100);
+    let out = dxc(&["ttl", "--utc", &token]);
+    let text = String::from_utf8(out.stdout).unwrap();
+    assert!(text.contains("Lifetime:   2h 15m (8100 seconds)"));
+    assert!(text.contains("Remaining:  2h 1"));
+    assert!(text.contains(&format!("({})", now)) && text.contains("Z ("));
+}
+
+#[test]
+fn test_ttl_reissue() {
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let engine = dxcode::STANDARD.with_clock(dxcode::Clock::Fixed(now));
+    let token = engine.encode_with_ttl(b"session", 60);
+
+    let out = dxc(&["ttl", "--extend", "1h", "--json", &token]);
+    assert!(out.status.success());
+    let value = json(&out.stdout);
+    assert_eq!(value["ttl"]["created_at"], now);
+    assert_eq!(value["ttl"]["ttl_seconds"], 3_660);
+    let reissued = value["encoded"].as_str().unwrap();
+    assert_eq!(dxcode::decode(reissued).unwrap(), b"session");
+
+    let out = dxc(&["ttl", "--remove-ttl", reissued]);
+    let removed = String::from_utf8(out.stdout).unwrap();
+    assert_eq!(removed.trim(), dxcode::encode(b"session"));
+
+    // 已过期的编码需要 --ignore-ttl
+    let expired = dxcode::STANDARD
+        .with_clock(dxcode::Clock::Fixed(now - 120))
+        .encode_with_ttl(b"session", 60);
+    assert_eq!(
+        dxc(&["ttl", "--extend", "1h", &expired]).status.code(),
+        Some(3)
+    );
+    let out = dxc(&["ttl", "--extend", "1h", "--ignore-ttl", &expired]);
+    assert!(out.status.success());
+    assert!(!dxcode::is_expired(String::from_utf8(out.stdout).unwrap().trim()).unwrap());
+
+    for args in [
+        &["--extend", "1h", "--remove-ttl"][..],
+        &["--extend", "1x"],
+        &["--expires", "2000-01-01T00:00:00Z"],
+    ] {
+        let out = dxc(&[&["ttl"], args, &[&token]].concat());
+        assert_eq!(out.status.code(), Some(64), "dxc ttl {:?}", args);
+    }
+}
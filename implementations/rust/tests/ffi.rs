@@ -0,0 +1,74 @@
+//! C 接口测试：用系统 C 编译器编译 `tests/c/test_ffi.c`，链接本库的 staticlib 并运行
+
+#![cfg(all(feature = "ffi", unix))]
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// 用 `cargo rustc --crate-type staticlib` 单独构建 staticlib
+///
+/// 使用独立的 target 目录，避免与正在运行的 `cargo test` 争用构建锁；
+/// 依赖已由外层构建下载，因此可以离线构建。
+fn static_lib() -> PathBuf {
+    let target_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("ffi");
+    let cargo = env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let status = Command::new(&cargo)
+        .args(["rustc", "-q", "--lib", "--offline", "--features", "ffi"])
+        .args(["--crate-type", "staticlib"])
+        .arg("--manifest-path")
+        .arg(Path::new(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml"))
+        .arg("--target-dir")
+        .arg(&target_dir)
+        .status()
+        .unwrap_or_else(|e| panic!("failed to run {}: {}", cargo, e));
+    assert!(status.success(), "failed to build the staticlib");
+    target_dir.join("debug/libdxcode.a")
+}
+
+#[test]
+fn test_header_is_up_to_date() {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let committed = fs::read_to_string(manifest_dir.join("include/dxcode.h")).unwrap();
+    let generated = include_str!(concat!(env!("OUT_DIR"), "/dxcode.h"));
+    assert!(
+        committed == generated,
+        "include/dxcode.h is out of date, run `cbindgen --config cbindgen.toml --output include/dxcode.h`"
+    );
+}
+
+#[test]
+fn test_c_program() {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let out = Path::new(env!("CARGO_TARGET_TMPDIR")).join("test_ffi");
+    let cc = env::var("CC").unwrap_or_else(|_| "cc".to_string());
+
+    let status = Command::new(&cc)
+        .arg("-std=c99")
+        .arg("-Wall")
+        .arg("-Werror")
+        .arg("-I")
+        .arg(manifest_dir.join("include"))
+        .arg(manifest_dir.join("tests/c/test_ffi.c"))
+        .arg(static_lib())
+        .args(["-lpthread", "-ldl", "-lm"])
+        // 丢弃未引用的代码，启用 python 等 feature 时无需链接对应的运行库
+        .arg(if cfg!(target_os = "macos") {
+            "-Wl,-dead_strip"
+        } else {
+            "-Wl,--gc-sections"
+        })
+        .arg("-o")
+        .arg(&out)
+        .status()
+        .unwrap_or_else(|e| panic!("failed to run {}: {}", cc, e));
+    assert!(status.success(), "failed to compile test_ffi.c");
+
+    let output = Command::new(&out).output().unwrap();
+    assert!(
+        output.status.success(),
+        "test_ffi failed:\n{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
@@ -0,0 +1,55 @@
+//! Python 绑定测试：将 `cargo test` 构建的扩展模块作为 `dxcode` 导入，
+//! 运行纯 Python 实现的测试 `implementations/python/test_dxcode.py`
+
+#![cfg(all(feature = "python", target_os = "linux"))]
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// 用 `cargo rustc --crate-type cdylib` 单独构建扩展模块（与 maturin 的做法相同）
+///
+/// 使用独立的 target 目录，避免与正在运行的 `cargo test` 争用构建锁；
+/// 依赖已由外层构建下载，因此可以离线构建。
+fn extension_module() -> PathBuf {
+    let target_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("python-ext");
+    let cargo = env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let status = Command::new(&cargo)
+        .args(["rustc", "-q", "--lib", "--offline", "--features", "python"])
+        .args(["--crate-type", "cdylib"])
+        .arg("--manifest-path")
+        .arg(Path::new(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml"))
+        .arg("--target-dir")
+        .arg(&target_dir)
+        .status()
+        .unwrap_or_else(|e| panic!("failed to run {}: {}", cargo, e));
+    assert!(status.success(), "failed to build the extension module");
+    target_dir.join("debug/libdxcode.so")
+}
+
+#[test]
+fn test_python_suite() {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("python");
+    fs::create_dir_all(&dir).unwrap();
+
+    fs::copy(extension_module(), dir.join("dxcode.so")).unwrap();
+    fs::copy(
+        manifest_dir.join("../python/test_dxcode.py"),
+        dir.join("test_dxcode.py"),
+    )
+    .unwrap();
+
+    let python = env::var("PYTHON").unwrap_or_else(|_| "python3".to_string());
+    let output = Command::new(&python)
+        .args(["-m", "unittest", "test_dxcode"])
+        .current_dir(&dir)
+        .output()
+        .unwrap_or_else(|e| panic!("failed to run {}: {}", python, e));
+    assert!(
+        output.status.success(),
+        "test_dxcode.py failed:\n{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
@@ -0,0 +1,78 @@
+//! 一致性测试向量：`tests/vectors.json` 必须与 `dxc vectors` 的输出一致，
+//! 并且每个向量都能由公开 API 逐字节复现
+//!
+//! 更新向量：`cargo run --bin dxc -- vectors > tests/vectors.json`
+
+#[cfg(feature = "cli")]
+use std::process::Command;
+
+use dxcode::{Clock, STANDARD};
+use serde_json::Value;
+
+const VECTORS: &str = include_str!("vectors.json");
+
+fn unhex(s: &str) -> Vec<u8> {
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+#[cfg(feature = "cli")]
+#[test]
+fn test_vectors_file_is_up_to_date() {
+    assert_eq!(dxcode::vectors::to_json(), VECTORS);
+
+    let output = Command::new(env!("CARGO_BIN_EXE_dxc"))
+        .arg("vectors")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), VECTORS);
+}
+
+#[test]
+fn test_encode_vectors() {
+    let json: Value = serde_json::from_str(VECTORS).unwrap();
+    let engine = STANDARD.with_clock(Clock::Fixed(json["now"].as_u64().unwrap()));
+
+    for v in json["vectors"].as_array().unwrap() {
+        let name = v["name"].as_str().unwrap();
+        let input = unhex(v["input"].as_str().unwrap());
+        let compress = v["compress"].as_bool().unwrap();
+        let expected = v["encoded"].as_str().unwrap();
+
+        let encoded = match v["ttl_seconds"].as_u64() {
+            Some(ttl) => engine.encode_with_ttl_and_options(&input, ttl as u32, compress),
+            None => engine.encode_with_options(&input, compress),
+        };
+        assert_eq!(encoded, expected, "vector {}", name);
+        assert_eq!(engine.decode(expected).unwrap(), input, "vector {}", name);
+        assert_eq!(
+            v["exact"].as_bool().unwrap(),
+            !engine.is_compressed(expected).unwrap(),
+            "vector {}",
+            name
+        );
+    }
+}
+
+#[test]
+fn test_error_vectors() {
+    let json: Value = serde_json::from_str(VECTORS).unwrap();
+    let engine = STANDARD.with_clock(Clock::Fixed(json["now"].as_u64().unwrap()));
+
+    for v in json["errors"].as_array().unwrap() {
+        let name = v["name"].as_str().unwrap();
+        let encoded = v["encoded"].as_str().unwrap();
+        let result =
+            engine
+                .decode(encoded)
+                .and_then(|bytes| match v["as_string"].as_bool().unwrap() {
+                    true => Ok(String::from_utf8(bytes)?.into_bytes()),
+                    false => Ok(bytes),
+                });
+        let kind = format!("{:?}", result.unwrap_err().kind());
+        assert_eq!(kind, v["error"].as_str().unwrap(), "vector {}", name);
+    }
+}
@@ -0,0 +1,73 @@
+//! WebAssembly 绑定测试（Node.js 无头运行）
+//!
+//! ```sh
+//! wasm-pack test --node --features wasm --test wasm
+//! ```
+
+#![cfg(all(feature = "wasm", target_arch = "wasm32"))]
+
+use dxcode::wasm;
+use js_sys::{Function, Reflect, Uint8Array};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+fn test_string_and_bytes_roundtrip() {
+    let encoded = wasm::encode(JsValue::from_str("你好，Dogxi！"), None).unwrap();
+    assert_eq!(encoded, dxcode::encode_str("你好，Dogxi！"));
+    assert_eq!(wasm::decode_str(&encoded, None).unwrap(), "你好，Dogxi！");
+
+    let bytes = Uint8Array::from(&[0u8, 1, 2, 255][..]);
+    let encoded = wasm::encode(bytes.into(), Some(false)).unwrap();
+    assert_eq!(wasm::decode(&encoded, None).unwrap(), [0, 1, 2, 255]);
+    assert!(wasm::is_encoded(&encoded));
+    assert!(wasm::verify(&encoded).unwrap());
+
+    assert!(wasm::encode(JsValue::from_f64(1.0), None).is_err());
+}
+
+#[wasm_bindgen_test]
+fn test_error_name_and_kind() {
+    let err = wasm::decode("hello", None).unwrap_err();
+    let err: js_sys::Error = err.dyn_into().unwrap();
+    assert_eq!(err.name(), "DxEncodingError");
+    let kind = Reflect::get(&err, &JsValue::from_str("kind")).unwrap();
+    assert_eq!(kind.as_string().unwrap(), "InvalidPrefix");
+}
+
+#[wasm_bindgen_test]
+fn test_inspect() {
+    let encoded = wasm::encode(JsValue::from_str(&"a".repeat(100)), None).unwrap();
+    let header = wasm::inspect(&encoded).unwrap();
+    assert!(header.compressed);
+    assert_eq!(header.original_size, Some(100));
+    assert_eq!(header.checksum, dxcode::crc16("a".repeat(100).as_bytes()));
+    assert_eq!(header.created_at, None);
+}
+
+#[wasm_bindgen_test]
+fn test_ttl_with_clock_hook() {
+    let clock = Function::new_no_args("return 1700000000");
+    wasm::set_clock(Some(clock));
+    let encoded = wasm::encode_with_ttl(JsValue::from_str("session"), 60, None).unwrap();
+    let info = wasm::get_ttl_info(&encoded).unwrap().unwrap();
+    assert_eq!(info.created_at, 1_700_000_000.0);
+    assert_eq!(info.expires_at, Some(1_700_000_060.0));
+    assert!(!wasm::is_expired(&encoded).unwrap());
+
+    wasm::set_clock(Some(Function::new_no_args("return 1700000061")));
+    assert!(wasm::is_expired(&encoded).unwrap());
+    let err: js_sys::Error = wasm::decode(&encoded, None)
+        .unwrap_err()
+        .dyn_into()
+        .unwrap();
+    let kind = Reflect::get(&err, &JsValue::from_str("kind")).unwrap();
+    assert_eq!(kind.as_string().unwrap(), "TtlExpired");
+    assert_eq!(wasm::decode_str(&encoded, Some(false)).unwrap(), "session");
+
+    // 恢复为 Date.now()
+    wasm::set_clock(None);
+    assert!(wasm::is_expired(&encoded).unwrap());
+    assert!(wasm::has_ttl(&encoded).unwrap());
+    assert!(wasm::get_ttl_info(&dxcode::encode(b"x")).unwrap().is_none());
+}
@@ -116,7 +116,7 @@ download() {
 install_via_cargo() {
     info "通过 Cargo 安装 dxcode..."
 
-    if cargo install dxcode; then
+    if cargo install dxcode --features cli; then
         success "dxcode 安装成功!"
         return 0
     else
@@ -358,7 +358,7 @@ main() {
         echo "    brew install dogxii/tap/dxcode"
         echo ""
         echo "  ${BOLD}Cargo (需要 Rust):${NC}"
-        echo "    cargo install dxcode"
+        echo "    cargo install dxcode --features cli"
         echo ""
         echo "  ${BOLD}手动下载:${NC}"
         echo "    https://github.com/${REPO}/releases"
@@ -0,0 +1,208 @@
+/**
+ * DX Encoding - Rust 实现的 WebAssembly 绑定
+ *
+ * `./wasm` 由 `bun run build:wasm` 从 implementations/rust 生成，是网站唯一的
+ * 编解码器。调用其他函数前需要先等待 `loadDxcode()` 加载完成，否则抛出
+ * `DxEncodingError`。
+ *
+ * @author Dogxi
+ * @license MIT
+ */
+
+import init, * as wasm from "./wasm/dxcode.js";
+
+/**
+ * DX 编码错误类
+ */
+export class DxEncodingError extends Error {
+	constructor(message: string) {
+		super(message);
+		this.name = "DxEncodingError";
+	}
+}
+
+/**
+ * 编码选项
+ */
+export interface DxEncodeOptions {
+	/** 是否允许压缩（默认 true） */
+	compress?: boolean;
+}
+
+/**
+ * TTL 编码选项
+ */
+export interface DxEncodeWithTtlOptions {
+	compress?: boolean;
+}
+
+/**
+ * DX 解码选项
+ */
+export interface DxDecodeOptions {
+	/** 是否返回字符串（默认 true） */
+	asString?: boolean;
+	/** 是否检查 TTL 过期（默认 true） */
+	checkTtl?: boolean;
+}
+
+/**
+ * TTL 信息接口
+ */
+export interface TtlInfo {
+	createdAt: number;
+	ttlSeconds: number;
+	expiresAt: number | null;
+	isExpired: boolean;
+}
+
+/**
+ * DX 编码信息
+ */
+export interface DxInfo {
+	name: string;
+	version: string;
+	author: string;
+	prefix: string;
+	checksum: string;
+	compression: string;
+}
+
+/**
+ * 获取 DX 编码信息
+ */
+export function getDxInfo(): DxInfo {
+	return {
+		name: "DX Encoding",
+		version: "2.3.0",
+		author: "Dogxi",
+		prefix: "dx",
+		checksum: "CRC16-CCITT",
+		compression: "DEFLATE",
+	};
+}
+
+let loaded = false;
+let loading: Promise<boolean> | null = null;
+
+/**
+ * 加载 WebAssembly 模块，返回是否加载成功（重复调用返回同一结果）
+ */
+export function loadDxcode(): Promise<boolean> {
+	loading ??= init()
+		.then(() => (loaded = true))
+		.catch(() => false);
+	return loading;
+}
+
+/**
+ * WebAssembly 模块是否已加载
+ */
+export function isWasmLoaded(): boolean {
+	return loaded;
+}
+
+/**
+ * 设置检查 TTL 时使用的时钟（Unix 时间戳，秒），传入 `null` 恢复为 `Date.now()`
+ */
+export function setClock(clock: (() => number) | null): void {
+	call(() => wasm.setClock(clock));
+}
+
+/**
+ * 调用 Rust 编解码器，将抛出的错误转换为 `DxEncodingError`
+ */
+function call<T>(f: () => T): T {
+	if (!loaded) {
+		throw new DxEncodingError("WebAssembly 编解码器尚未加载");
+	}
+	try {
+		return f();
+	} catch (e) {
+		throw new DxEncodingError(e instanceof Error ? e.message : String(e));
+	}
+}
+
+/**
+ * DX 编码
+ */
+export function dxEncode(
+	input: string | Uint8Array,
+	options: DxEncodeOptions = { compress: true },
+): string {
+	return call(() => wasm.encode(input, options.compress !== false));
+}
+
+/**
+ * 使用 TTL 编码
+ */
+export function dxEncodeWithTtl(
+	input: string | Uint8Array,
+	ttlSeconds: number,
+	options: DxEncodeWithTtlOptions = { compress: true },
+): string {
+	return call(() =>
+		wasm.encodeWithTtl(input, ttlSeconds, options.compress !== false),
+	);
+}
+
+/**
+ * DX 解码
+ */
+export function dxDecode(
+	encoded: string,
+	options: DxDecodeOptions = { asString: true },
+): string | Uint8Array {
+	const checkTtl = options.checkTtl !== false;
+	return call(() =>
+		options.asString === false
+			? wasm.decode(encoded, checkTtl)
+			: wasm.decodeStr(encoded, checkTtl),
+	);
+}
+
+/**
+ * 检查是否为 DX 编码
+ */
+export function isDxEncoded(str: string): boolean {
+	return call(() => wasm.isEncoded(str));
+}
+
+/**
+ * 验证校验和
+ */
+export function dxVerify(encoded: string): boolean {
+	return call(() => wasm.verify(encoded));
+}
+
+/**
+ * 检查是否包含 TTL
+ */
+export function hasTtl(encoded: string): boolean {
+	return call(() => wasm.hasTtl(encoded));
+}
+
+/**
+ * 获取 TTL 信息，没有 TTL 返回 null
+ */
+export function getTtlInfo(encoded: string): TtlInfo | null {
+	const info = call(() => wasm.getTtlInfo(encoded));
+	if (!info) {
+		return null;
+	}
+	const result = {
+		createdAt: info.createdAt,
+		ttlSeconds: info.ttlSeconds,
+		expiresAt: info.expiresAt ?? null,
+		isExpired: info.isExpired,
+	};
+	info.free();
+	return result;
+}
+
+/**
+ * 检查是否已过期（没有 TTL 的数据返回 false）
+ */
+export function isExpired(encoded: string): boolean {
+	return call(() => wasm.isExpired(encoded));
+}
@@ -0,0 +1,186 @@
+/* tslint:disable */
+/* eslint-disable */
+
+/**
+ * 头部信息
+ */
+export class Inspection {
+    private constructor();
+    free(): void;
+    [Symbol.dispose](): void;
+    /**
+     * 存储的 CRC16 校验和
+     */
+    readonly checksum: number;
+    /**
+     * 是否压缩
+     */
+    readonly compressed: boolean;
+    /**
+     * 内容类型，未标记时为 `undefined`
+     */
+    readonly contentType: number | undefined;
+    /**
+     * 创建时间（Unix 时间戳，秒），不带 TTL 时为 `undefined`
+     */
+    readonly createdAt: number | undefined;
+    /**
+     * flags 字节
+     */
+    readonly flags: number;
+    /**
+     * 压缩前的大小，未压缩时为 `undefined`
+     */
+    readonly originalSize: number | undefined;
+    /**
+     * 负载字节数
+     */
+    readonly payloadLength: number;
+    /**
+     * 有效期（秒），不带 TTL 时为 `undefined`
+     */
+    readonly ttlSeconds: number | undefined;
+}
+
+/**
+ * TTL 信息
+ */
+export class TtlInfo {
+    private constructor();
+    free(): void;
+    [Symbol.dispose](): void;
+    /**
+     * 创建时间（Unix 时间戳，秒）
+     */
+    readonly createdAt: number;
+    /**
+     * 过期时间（Unix 时间戳，秒），永不过期时为 `undefined`
+     */
+    readonly expiresAt: number | undefined;
+    /**
+     * 是否已过期
+     */
+    readonly isExpired: boolean;
+    /**
+     * 有效期（秒），0 表示永不过期
+     */
+    readonly ttlSeconds: number;
+}
+
+/**
+ * 解码为 `Uint8Array`（`checkTtl` 默认为 `true`）
+ */
+export function decode(encoded: string, checkTtl?: boolean | null): Uint8Array;
+
+/**
+ * 解码为字符串（`checkTtl` 默认为 `true`）
+ */
+export function decodeStr(encoded: string, checkTtl?: boolean | null): string;
+
+/**
+ * 编码字符串或字节数据
+ */
+export function encode(input: string | Uint8Array, compress?: boolean | null): string;
+
+/**
+ * 使用 TTL 编码字符串或字节数据
+ */
+export function encodeWithTtl(input: string | Uint8Array, ttlSeconds: number, compress?: boolean | null): string;
+
+/**
+ * 获取 TTL 信息，不带 TTL 时返回 `undefined`
+ */
+export function getTtlInfo(encoded: string): TtlInfo | undefined;
+
+/**
+ * 检查是否包含 TTL
+ */
+export function hasTtl(encoded: string): boolean;
+
+/**
+ * 解析头部信息（不验证校验和）
+ */
+export function inspect(encoded: string): Inspection;
+
+/**
+ * 检查字符串是否为 DX 编码（仅检查前缀、长度和字符）
+ */
+export function isEncoded(s: string): boolean;
+
+/**
+ * 检查是否已过期（没有 TTL 的数据永不过期）
+ */
+export function isExpired(encoded: string): boolean;
+
+/**
+ * 设置检查 TTL 时使用的时钟
+ *
+ * `clock` 返回 Unix 时间戳（秒）；传入 `null` 恢复为 `Date.now()`。
+ */
+export function setClock(clock: (() => number) | null): void;
+
+/**
+ * 验证校验和，不匹配时返回 `false`，格式错误时抛出异常
+ */
+export function verify(encoded: string): boolean;
+
+export type InitInput = RequestInfo | URL | Response | BufferSource | WebAssembly.Module;
+
+export interface InitOutput {
+    readonly memory: WebAssembly.Memory;
+    readonly __wbg_get_inspection_checksum: (a: number) => number;
+    readonly __wbg_get_inspection_compressed: (a: number) => number;
+    readonly __wbg_get_inspection_contentType: (a: number) => number;
+    readonly __wbg_get_inspection_createdAt: (a: number) => [number, number];
+    readonly __wbg_get_inspection_flags: (a: number) => number;
+    readonly __wbg_get_inspection_originalSize: (a: number) => number;
+    readonly __wbg_get_inspection_payloadLength: (a: number) => number;
+    readonly __wbg_get_inspection_ttlSeconds: (a: number) => number;
+    readonly __wbg_get_ttlinfo_createdAt: (a: number) => number;
+    readonly __wbg_get_ttlinfo_expiresAt: (a: number) => [number, number];
+    readonly __wbg_get_ttlinfo_isExpired: (a: number) => number;
+    readonly __wbg_get_ttlinfo_ttlSeconds: (a: number) => number;
+    readonly __wbg_inspection_free: (a: number, b: number) => void;
+    readonly __wbg_ttlinfo_free: (a: number, b: number) => void;
+    readonly decode: (a: number, b: number, c: number) => [number, number, number, number];
+    readonly decodeStr: (a: number, b: number, c: number) => [number, number, number, number];
+    readonly encode: (a: any, b: number) => [number, number, number, number];
+    readonly encodeWithTtl: (a: any, b: number, c: number) => [number, number, number, number];
+    readonly getTtlInfo: (a: number, b: number) => [number, number, number];
+    readonly hasTtl: (a: number, b: number) => [number, number, number];
+    readonly inspect: (a: number, b: number) => [number, number, number];
+    readonly isEncoded: (a: number, b: number) => number;
+    readonly isExpired: (a: number, b: number) => [number, number, number];
+    readonly setClock: (a: number) => void;
+    readonly verify: (a: number, b: number) => [number, number, number];
+    readonly __wbindgen_malloc: (a: number, b: number) => number;
+    readonly __wbindgen_realloc: (a: number, b: number, c: number, d: number) => number;
+    readonly __wbindgen_exn_store: (a: number) => void;
+    readonly __externref_table_alloc: () => number;
+    readonly __wbindgen_externrefs: WebAssembly.Table;
+    readonly __externref_table_dealloc: (a: number) => void;
+    readonly __wbindgen_free: (a: number, b: number, c: number) => void;
+    readonly __wbindgen_start: () => void;
+}
+
+export type SyncInitInput = BufferSource | WebAssembly.Module;
+
+/**
+ * Instantiates the given `module`, which can either be bytes or
+ * a precompiled `WebAssembly.Module`.
+ *
+ * @param {{ module: SyncInitInput }} module - Passing `SyncInitInput` directly is deprecated.
+ *
+ * @returns {InitOutput}
+ */
+export function initSync(module: { module: SyncInitInput } | SyncInitInput): InitOutput;
+
+/**
+ * If `module_or_path` is {RequestInfo} or {URL}, makes a request and
+ * for everything else, calls `WebAssembly.instantiate` directly.
+ *
+ * @param {{ module_or_path: InitInput | Promise<InitInput> }} module_or_path - Passing `InitInput` directly is deprecated.
+ *
+ * @returns {Promise<InitOutput>}
+ */
+export default function __wbg_init (module_or_path?: { module_or_path: InitInput | Promise<InitInput> } | InitInput | Promise<InitInput>): Promise<InitOutput>;
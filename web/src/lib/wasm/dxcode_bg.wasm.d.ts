@@ -0,0 +1,36 @@
+/* tslint:disable */
+/* eslint-disable */
+export const memory: WebAssembly.Memory;
+export const __wbg_get_inspection_checksum: (a: number) => number;
+export const __wbg_get_inspection_compressed: (a: number) => number;
+export const __wbg_get_inspection_contentType: (a: number) => number;
+export const __wbg_get_inspection_createdAt: (a: number) => [number, number];
+export const __wbg_get_inspection_flags: (a: number) => number;
+export const __wbg_get_inspection_originalSize: (a: number) => number;
+export const __wbg_get_inspection_payloadLength: (a: number) => number;
+export const __wbg_get_inspection_ttlSeconds: (a: number) => number;
+export const __wbg_get_ttlinfo_createdAt: (a: number) => number;
+export const __wbg_get_ttlinfo_expiresAt: (a: number) => [number, number];
+export const __wbg_get_ttlinfo_isExpired: (a: number) => number;
+export const __wbg_get_ttlinfo_ttlSeconds: (a: number) => number;
+export const __wbg_inspection_free: (a: number, b: number) => void;
+export const __wbg_ttlinfo_free: (a: number, b: number) => void;
+export const decode: (a: number, b: number, c: number) => [number, number, number, number];
+export const decodeStr: (a: number, b: number, c: number) => [number, number, number, number];
+export const encode: (a: any, b: number) => [number, number, number, number];
+export const encodeWithTtl: (a: any, b: number, c: number) => [number, number, number, number];
+export const getTtlInfo: (a: number, b: number) => [number, number, number];
+export const hasTtl: (a: number, b: number) => [number, number, number];
+export const inspect: (a: number, b: number) => [number, number, number];
+export const isEncoded: (a: number, b: number) => number;
+export const isExpired: (a: number, b: number) => [number, number, number];
+export const setClock: (a: number) => void;
+export const verify: (a: number, b: number) => [number, number, number];
+export const __wbindgen_malloc: (a: number, b: number) => number;
+export const __wbindgen_realloc: (a: number, b: number, c: number, d: number) => number;
+export const __wbindgen_exn_store: (a: number) => void;
+export const __externref_table_alloc: () => number;
+export const __wbindgen_externrefs: WebAssembly.Table;
+export const __externref_table_dealloc: (a: number) => void;
+export const __wbindgen_free: (a: number, b: number, c: number) => void;
+export const __wbindgen_start: () => void;